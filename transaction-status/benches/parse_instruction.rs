@@ -0,0 +1,245 @@
+#![feature(test)]
+
+extern crate test;
+
+use {
+    solana_sdk::{
+        bpf_loader, bpf_loader_upgradeable,
+        compute_budget::ComputeBudgetInstruction,
+        hash::Hash,
+        instruction::{AccountMeta, Instruction},
+        loader_instruction,
+        message::{AccountKeys, Message},
+        pubkey::Pubkey,
+        stake, system_instruction,
+    },
+    solana_transaction_status::{
+        extract_memos::spl_memo_id_v3, parse_anchor::anchor_discriminator, parse_feature_proposal,
+        parse_governance, parse_instruction::parse, parse_jito, parse_name_service, parse_noop,
+        parse_secp256r1, parse_stake_pool, parse_token::spl_token_instruction, parse_token_lending,
+        parse_token_metadata, parse_token_swap, parse_token_upgrade,
+    },
+    solana_vote_program::{vote_instruction, vote_state::Vote},
+    test::Bencher,
+};
+
+fn bench_parse(b: &mut Bencher, instruction: Instruction) {
+    let message = Message::new(&[instruction], None);
+    let account_keys = AccountKeys::new(&message.account_keys, None);
+    let instruction = &message.instructions[0];
+    let program_id = &message.account_keys[instruction.program_id_index as usize];
+    parse(program_id, instruction, &account_keys).unwrap();
+    b.iter(|| parse(program_id, instruction, &account_keys).unwrap());
+}
+
+fn spl_pubkey() -> spl_token::solana_program::pubkey::Pubkey {
+    spl_token::solana_program::pubkey::Pubkey::new_unique()
+}
+
+#[bench]
+fn bench_parse_address_lookup_table(b: &mut Bencher) {
+    let (instruction, _) = solana_address_lookup_table_program::instruction::create_lookup_table(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        42,
+    );
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_associated_token(b: &mut Bencher) {
+    let instruction = spl_associated_token_account::instruction::create_associated_token_account(
+        &spl_pubkey(),
+        &spl_pubkey(),
+        &spl_pubkey(),
+    );
+    bench_parse(b, spl_token_instruction(instruction));
+}
+
+#[bench]
+fn bench_parse_bpf_loader(b: &mut Bencher) {
+    let instruction =
+        loader_instruction::write(&Pubkey::new_unique(), &bpf_loader::id(), 0, vec![0; 64]);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_bpf_upgradeable_loader(b: &mut Bencher) {
+    let instruction = bpf_loader_upgradeable::upgrade(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+    );
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_compute_budget(b: &mut Bencher) {
+    bench_parse(b, ComputeBudgetInstruction::set_compute_unit_price(1_000));
+}
+
+#[bench]
+fn bench_parse_feature_proposal(b: &mut Bencher) {
+    // Tally
+    let accounts = (0..5)
+        .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+        .collect();
+    let instruction = Instruction::new_with_bytes(parse_feature_proposal::id(), &[1], accounts);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_governance(b: &mut Bencher) {
+    // CastVote(Deny)
+    let accounts = (0..10)
+        .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+        .collect();
+    let instruction = Instruction::new_with_bytes(parse_governance::id(), &[13, 1], accounts);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_jito_tip_payment(b: &mut Bencher) {
+    // ClaimTips
+    let accounts = (0..12)
+        .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+        .collect();
+    let data = anchor_discriminator("claim_tips");
+    let instruction = Instruction::new_with_bytes(parse_jito::tip_payment::id(), &data, accounts);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_memo(b: &mut Bencher) {
+    let instruction = Instruction::new_with_bytes(spl_memo_id_v3(), b"Test memo", vec![]);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_name_service(b: &mut Bencher) {
+    // Delete
+    let accounts = (0..3)
+        .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+        .collect();
+    let instruction = Instruction::new_with_bytes(parse_name_service::id(), &[3], accounts);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_noop(b: &mut Bencher) {
+    let accounts = vec![AccountMeta::new(Pubkey::new_unique(), false)];
+    let instruction = Instruction::new_with_bytes(parse_noop::id(), b"anchored", accounts);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_secp256r1(b: &mut Bencher) {
+    let mut data = vec![1, 0];
+    for offset in [16u16, u16::MAX, 80, u16::MAX, 113, 32, u16::MAX] {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    let instruction = Instruction::new_with_bytes(parse_secp256r1::id(), &data, vec![]);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_stake(b: &mut Bencher) {
+    let instruction = stake::instruction::delegate_stake(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+    );
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_stake_pool(b: &mut Bencher) {
+    // DepositSol
+    let mut data = vec![14];
+    data.extend_from_slice(&42u64.to_le_bytes());
+    let accounts = (0..10)
+        .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+        .collect();
+    let instruction = Instruction::new_with_bytes(parse_stake_pool::id(), &data, accounts);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_system(b: &mut Bencher) {
+    let instruction =
+        system_instruction::transfer(&Pubkey::new_unique(), &Pubkey::new_unique(), 42);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_token(b: &mut Bencher) {
+    let instruction = spl_token::instruction::transfer_checked(
+        &spl_token::id(),
+        &spl_pubkey(),
+        &spl_pubkey(),
+        &spl_pubkey(),
+        &spl_pubkey(),
+        &[],
+        42,
+        2,
+    )
+    .unwrap();
+    bench_parse(b, spl_token_instruction(instruction));
+}
+
+#[bench]
+fn bench_parse_token_metadata(b: &mut Bencher) {
+    // UpdateMetadataAccountV2 with no changes
+    let accounts = (0..2)
+        .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+        .collect();
+    let instruction =
+        Instruction::new_with_bytes(parse_token_metadata::id(), &[15, 0, 0, 0, 0], accounts);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_token_lending(b: &mut Bencher) {
+    // DepositReserveLiquidity
+    let accounts = (0..10)
+        .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+        .collect();
+    let mut data = vec![4];
+    data.extend_from_slice(&[0; 8]);
+    let instruction = Instruction::new_with_bytes(parse_token_lending::id(), &data, accounts);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_token_swap(b: &mut Bencher) {
+    // Swap
+    let accounts = (0..10)
+        .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+        .collect();
+    let mut data = vec![1];
+    data.extend_from_slice(&[0; 16]);
+    let instruction = Instruction::new_with_bytes(parse_token_swap::id(), &data, accounts);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_token_upgrade(b: &mut Bencher) {
+    // Exchange
+    let accounts = (0..9)
+        .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+        .collect();
+    let instruction = Instruction::new_with_bytes(parse_token_upgrade::id(), &[0], accounts);
+    bench_parse(b, instruction);
+}
+
+#[bench]
+fn bench_parse_vote(b: &mut Bencher) {
+    let vote = Vote {
+        slots: vec![1, 2, 4],
+        hash: Hash::new_unique(),
+        timestamp: Some(1_234_567_890),
+    };
+    let instruction = vote_instruction::vote(&Pubkey::new_unique(), &Pubkey::new_unique(), vote);
+    bench_parse(b, instruction);
+}
@@ -0,0 +1,447 @@
+//! Names emitted as `ParsedInstructionEnum::instruction_type` by the built-in parsers
+
+pub mod address_lookup_table {
+    pub const CREATE_LOOKUP_TABLE: &str = "createLookupTable";
+    pub const FREEZE_LOOKUP_TABLE: &str = "freezeLookupTable";
+    pub const EXTEND_LOOKUP_TABLE: &str = "extendLookupTable";
+    pub const DEACTIVATE_LOOKUP_TABLE: &str = "deactivateLookupTable";
+    pub const CLOSE_LOOKUP_TABLE: &str = "closeLookupTable";
+
+    pub const ALL: &[&str] = &[
+        CREATE_LOOKUP_TABLE,
+        FREEZE_LOOKUP_TABLE,
+        EXTEND_LOOKUP_TABLE,
+        DEACTIVATE_LOOKUP_TABLE,
+        CLOSE_LOOKUP_TABLE,
+    ];
+}
+
+pub mod associated_token {
+    pub const CREATE: &str = "create";
+
+    pub const ALL: &[&str] = &[CREATE];
+}
+
+pub mod bpf_loader {
+    pub const WRITE: &str = "write";
+    pub const FINALIZE: &str = "finalize";
+
+    pub const ALL: &[&str] = &[WRITE, FINALIZE];
+}
+
+pub mod bpf_upgradeable_loader {
+    pub const INITIALIZE_BUFFER: &str = "initializeBuffer";
+    pub const WRITE: &str = "write";
+    pub const DEPLOY_WITH_MAX_DATA_LEN: &str = "deployWithMaxDataLen";
+    pub const UPGRADE: &str = "upgrade";
+    pub const SET_AUTHORITY: &str = "setAuthority";
+    pub const CLOSE: &str = "close";
+    pub const EXTEND_PROGRAM_DATA: &str = "extendProgramData";
+
+    pub const ALL: &[&str] = &[
+        INITIALIZE_BUFFER,
+        WRITE,
+        DEPLOY_WITH_MAX_DATA_LEN,
+        UPGRADE,
+        SET_AUTHORITY,
+        CLOSE,
+        EXTEND_PROGRAM_DATA,
+    ];
+}
+
+pub mod budget {
+    pub const INITIALIZE_ACCOUNT: &str = "initializeAccount";
+    pub const APPLY_TIMESTAMP: &str = "applyTimestamp";
+    pub const APPLY_SIGNATURE: &str = "applySignature";
+    pub const APPLY_ACCOUNT_DATA: &str = "applyAccountData";
+
+    pub const ALL: &[&str] = &[
+        INITIALIZE_ACCOUNT,
+        APPLY_TIMESTAMP,
+        APPLY_SIGNATURE,
+        APPLY_ACCOUNT_DATA,
+    ];
+}
+
+pub mod compute_budget {
+    pub const REQUEST_UNITS: &str = "requestUnits";
+    pub const REQUEST_HEAP_FRAME: &str = "requestHeapFrame";
+    pub const SET_COMPUTE_UNIT_LIMIT: &str = "setComputeUnitLimit";
+    pub const SET_COMPUTE_UNIT_PRICE: &str = "setComputeUnitPrice";
+
+    pub const ALL: &[&str] = &[
+        REQUEST_UNITS,
+        REQUEST_HEAP_FRAME,
+        SET_COMPUTE_UNIT_LIMIT,
+        SET_COMPUTE_UNIT_PRICE,
+    ];
+}
+
+pub mod exchange {
+    pub const ACCOUNT_REQUEST: &str = "accountRequest";
+    pub const TRANSFER_REQUEST: &str = "transferRequest";
+    pub const ORDER_REQUEST: &str = "orderRequest";
+    pub const ORDER_CANCELLATION: &str = "orderCancellation";
+    pub const SWAP_REQUEST: &str = "swapRequest";
+
+    pub const ALL: &[&str] = &[
+        ACCOUNT_REQUEST,
+        TRANSFER_REQUEST,
+        ORDER_REQUEST,
+        ORDER_CANCELLATION,
+        SWAP_REQUEST,
+    ];
+}
+
+pub mod feature_proposal {
+    pub const PROPOSE: &str = "propose";
+    pub const TALLY: &str = "tally";
+
+    pub const ALL: &[&str] = &[PROPOSE, TALLY];
+}
+
+pub mod governance {
+    pub const DEPOSIT_GOVERNING_TOKENS: &str = "depositGoverningTokens";
+    pub const WITHDRAW_GOVERNING_TOKENS: &str = "withdrawGoverningTokens";
+    pub const CREATE_PROPOSAL: &str = "createProposal";
+    pub const CAST_VOTE: &str = "castVote";
+    pub const EXECUTE_TRANSACTION: &str = "executeTransaction";
+
+    pub const ALL: &[&str] = &[
+        DEPOSIT_GOVERNING_TOKENS,
+        WITHDRAW_GOVERNING_TOKENS,
+        CREATE_PROPOSAL,
+        CAST_VOTE,
+        EXECUTE_TRANSACTION,
+    ];
+}
+
+pub mod jito_tip_distribution {
+    pub const INIT_TIP_DISTRIBUTION_ACCOUNT: &str = "initTipDistributionAccount";
+    pub const UPLOAD_MERKLE_ROOT: &str = "uploadMerkleRoot";
+    pub const CLAIM: &str = "claim";
+
+    pub const ALL: &[&str] = &[INIT_TIP_DISTRIBUTION_ACCOUNT, UPLOAD_MERKLE_ROOT, CLAIM];
+}
+
+pub mod jito_tip_payment {
+    pub const CLAIM_TIPS: &str = "claimTips";
+    pub const CHANGE_TIP_RECEIVER: &str = "changeTipReceiver";
+
+    pub const ALL: &[&str] = &[CLAIM_TIPS, CHANGE_TIP_RECEIVER];
+}
+
+pub mod name_service {
+    pub const CREATE: &str = "create";
+    pub const UPDATE: &str = "update";
+    pub const TRANSFER: &str = "transfer";
+    pub const DELETE: &str = "delete";
+
+    pub const ALL: &[&str] = &[CREATE, UPDATE, TRANSFER, DELETE];
+}
+
+pub mod noop {
+    pub const NOOP: &str = "noop";
+
+    pub const ALL: &[&str] = &[NOOP];
+}
+
+pub mod secp256r1 {
+    pub const VERIFY: &str = "verify";
+
+    pub const ALL: &[&str] = &[VERIFY];
+}
+
+pub mod stake {
+    pub const INITIALIZE: &str = "initialize";
+    pub const AUTHORIZE: &str = "authorize";
+    pub const DELEGATE: &str = "delegate";
+    pub const SPLIT: &str = "split";
+    pub const WITHDRAW: &str = "withdraw";
+    pub const DEACTIVATE: &str = "deactivate";
+    pub const SET_LOCKUP: &str = "setLockup";
+    pub const MERGE: &str = "merge";
+    pub const AUTHORIZE_WITH_SEED: &str = "authorizeWithSeed";
+    pub const INITIALIZE_CHECKED: &str = "initializeChecked";
+    pub const AUTHORIZE_CHECKED: &str = "authorizeChecked";
+    pub const AUTHORIZE_CHECKED_WITH_SEED: &str = "authorizeCheckedWithSeed";
+    pub const SET_LOCKUP_CHECKED: &str = "setLockupChecked";
+    pub const GET_MINIMUM_DELEGATION: &str = "getMinimumDelegation";
+    pub const DEACTIVATE_DELINQUENT: &str = "deactivateDeactive";
+
+    pub const ALL: &[&str] = &[
+        INITIALIZE,
+        AUTHORIZE,
+        DELEGATE,
+        SPLIT,
+        WITHDRAW,
+        DEACTIVATE,
+        SET_LOCKUP,
+        MERGE,
+        AUTHORIZE_WITH_SEED,
+        INITIALIZE_CHECKED,
+        AUTHORIZE_CHECKED,
+        AUTHORIZE_CHECKED_WITH_SEED,
+        SET_LOCKUP_CHECKED,
+        GET_MINIMUM_DELEGATION,
+        DEACTIVATE_DELINQUENT,
+    ];
+}
+
+pub mod stake_pool {
+    pub const DEPOSIT_SOL: &str = "depositSol";
+    pub const WITHDRAW_SOL: &str = "withdrawSol";
+    pub const DEPOSIT_STAKE: &str = "depositStake";
+    pub const WITHDRAW_STAKE: &str = "withdrawStake";
+    pub const UPDATE_VALIDATOR_LIST_BALANCE: &str = "updateValidatorListBalance";
+
+    pub const ALL: &[&str] = &[
+        DEPOSIT_SOL,
+        WITHDRAW_SOL,
+        DEPOSIT_STAKE,
+        WITHDRAW_STAKE,
+        UPDATE_VALIDATOR_LIST_BALANCE,
+    ];
+}
+
+pub mod system {
+    pub const CREATE_ACCOUNT: &str = "createAccount";
+    pub const ASSIGN: &str = "assign";
+    pub const TRANSFER: &str = "transfer";
+    pub const CREATE_ACCOUNT_WITH_SEED: &str = "createAccountWithSeed";
+    pub const ADVANCE_NONCE: &str = "advanceNonce";
+    pub const WITHDRAW_FROM_NONCE: &str = "withdrawFromNonce";
+    pub const INITIALIZE_NONCE: &str = "initializeNonce";
+    pub const AUTHORIZE_NONCE: &str = "authorizeNonce";
+    pub const UPGRADE_NONCE: &str = "upgradeNonce";
+    pub const ALLOCATE: &str = "allocate";
+    pub const ALLOCATE_WITH_SEED: &str = "allocateWithSeed";
+    pub const ASSIGN_WITH_SEED: &str = "assignWithSeed";
+    pub const TRANSFER_WITH_SEED: &str = "transferWithSeed";
+
+    pub const ALL: &[&str] = &[
+        CREATE_ACCOUNT,
+        ASSIGN,
+        TRANSFER,
+        CREATE_ACCOUNT_WITH_SEED,
+        ADVANCE_NONCE,
+        WITHDRAW_FROM_NONCE,
+        INITIALIZE_NONCE,
+        AUTHORIZE_NONCE,
+        UPGRADE_NONCE,
+        ALLOCATE,
+        ALLOCATE_WITH_SEED,
+        ASSIGN_WITH_SEED,
+        TRANSFER_WITH_SEED,
+    ];
+}
+
+pub mod token {
+    pub const INITIALIZE_MINT: &str = "initializeMint";
+    pub const INITIALIZE_MINT2: &str = "initializeMint2";
+    pub const INITIALIZE_ACCOUNT: &str = "initializeAccount";
+    pub const INITIALIZE_ACCOUNT2: &str = "initializeAccount2";
+    pub const INITIALIZE_ACCOUNT3: &str = "initializeAccount3";
+    pub const INITIALIZE_MULTISIG: &str = "initializeMultisig";
+    pub const INITIALIZE_MULTISIG2: &str = "initializeMultisig2";
+    pub const TRANSFER: &str = "transfer";
+    pub const APPROVE: &str = "approve";
+    pub const REVOKE: &str = "revoke";
+    pub const SET_AUTHORITY: &str = "setAuthority";
+    pub const MINT_TO: &str = "mintTo";
+    pub const BURN: &str = "burn";
+    pub const CLOSE_ACCOUNT: &str = "closeAccount";
+    pub const FREEZE_ACCOUNT: &str = "freezeAccount";
+    pub const THAW_ACCOUNT: &str = "thawAccount";
+    pub const TRANSFER_CHECKED: &str = "transferChecked";
+    pub const APPROVE_CHECKED: &str = "approveChecked";
+    pub const MINT_TO_CHECKED: &str = "mintToChecked";
+    pub const BURN_CHECKED: &str = "burnChecked";
+    pub const SYNC_NATIVE: &str = "syncNative";
+    pub const GET_ACCOUNT_DATA_SIZE: &str = "getAccountDataSize";
+    pub const INITIALIZE_IMMUTABLE_OWNER: &str = "initializeImmutableOwner";
+    pub const AMOUNT_TO_UI_AMOUNT: &str = "amountToUiAmount";
+    pub const UI_AMOUNT_TO_AMOUNT: &str = "uiAmountToAmount";
+    pub const CREATE_NATIVE_MINT: &str = "createNativeMint";
+    pub const INITIALIZE_DEFAULT_ACCOUNT_STATE: &str = "initializeDefaultAccountState";
+    pub const UPDATE_DEFAULT_ACCOUNT_STATE: &str = "updateDefaultAccountState";
+    pub const ENABLE_REQUIRED_MEMO_TRANSFERS: &str = "enableRequiredMemoTransfers";
+    pub const DISABLE_REQUIRED_MEMO_TRANSFERS: &str = "disableRequiredMemoTransfers";
+    pub const INITIALIZE_MINT_CLOSE_AUTHORITY: &str = "initializeMintCloseAuthority";
+    pub const REALLOCATE: &str = "reallocate";
+    pub const INITIALIZE_TRANSFER_FEE_CONFIG: &str = "initializeTransferFeeConfig";
+    pub const TRANSFER_CHECKED_WITH_FEE: &str = "transferCheckedWithFee";
+    pub const WITHDRAW_WITHHELD_TOKENS_FROM_MINT: &str = "withdrawWithheldTokensFromMint";
+    pub const WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS: &str = "withdrawWithheldTokensFromAccounts";
+    pub const HARVEST_WITHHELD_TOKENS_TO_MINT: &str = "harvestWithheldTokensToMint";
+    pub const SET_TRANSFER_FEE: &str = "setTransferFee";
+
+    pub const ALL: &[&str] = &[
+        INITIALIZE_MINT,
+        INITIALIZE_MINT2,
+        INITIALIZE_ACCOUNT,
+        INITIALIZE_ACCOUNT2,
+        INITIALIZE_ACCOUNT3,
+        INITIALIZE_MULTISIG,
+        INITIALIZE_MULTISIG2,
+        TRANSFER,
+        APPROVE,
+        REVOKE,
+        SET_AUTHORITY,
+        MINT_TO,
+        BURN,
+        CLOSE_ACCOUNT,
+        FREEZE_ACCOUNT,
+        THAW_ACCOUNT,
+        TRANSFER_CHECKED,
+        APPROVE_CHECKED,
+        MINT_TO_CHECKED,
+        BURN_CHECKED,
+        SYNC_NATIVE,
+        GET_ACCOUNT_DATA_SIZE,
+        INITIALIZE_IMMUTABLE_OWNER,
+        AMOUNT_TO_UI_AMOUNT,
+        UI_AMOUNT_TO_AMOUNT,
+        CREATE_NATIVE_MINT,
+        INITIALIZE_DEFAULT_ACCOUNT_STATE,
+        UPDATE_DEFAULT_ACCOUNT_STATE,
+        ENABLE_REQUIRED_MEMO_TRANSFERS,
+        DISABLE_REQUIRED_MEMO_TRANSFERS,
+        INITIALIZE_MINT_CLOSE_AUTHORITY,
+        REALLOCATE,
+        INITIALIZE_TRANSFER_FEE_CONFIG,
+        TRANSFER_CHECKED_WITH_FEE,
+        WITHDRAW_WITHHELD_TOKENS_FROM_MINT,
+        WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS,
+        HARVEST_WITHHELD_TOKENS_TO_MINT,
+        SET_TRANSFER_FEE,
+    ];
+}
+
+pub mod token_metadata {
+    pub const CREATE_METADATA_ACCOUNT_V3: &str = "createMetadataAccountV3";
+    pub const UPDATE_METADATA_ACCOUNT_V2: &str = "updateMetadataAccountV2";
+    pub const CREATE_MASTER_EDITION_V3: &str = "createMasterEditionV3";
+    pub const MINT: &str = "mint";
+    pub const BURN: &str = "burn";
+    pub const TRANSFER: &str = "transfer";
+
+    pub const ALL: &[&str] = &[
+        CREATE_METADATA_ACCOUNT_V3,
+        UPDATE_METADATA_ACCOUNT_V2,
+        CREATE_MASTER_EDITION_V3,
+        MINT,
+        BURN,
+        TRANSFER,
+    ];
+}
+
+pub mod token_lending {
+    pub const DEPOSIT_RESERVE_LIQUIDITY: &str = "depositReserveLiquidity";
+    pub const REDEEM_RESERVE_COLLATERAL: &str = "redeemReserveCollateral";
+    pub const BORROW_OBLIGATION_LIQUIDITY: &str = "borrowObligationLiquidity";
+    pub const REPAY_OBLIGATION_LIQUIDITY: &str = "repayObligationLiquidity";
+    pub const LIQUIDATE_OBLIGATION: &str = "liquidateObligation";
+
+    pub const ALL: &[&str] = &[
+        DEPOSIT_RESERVE_LIQUIDITY,
+        REDEEM_RESERVE_COLLATERAL,
+        BORROW_OBLIGATION_LIQUIDITY,
+        REPAY_OBLIGATION_LIQUIDITY,
+        LIQUIDATE_OBLIGATION,
+    ];
+}
+
+pub mod token_swap {
+    pub const INITIALIZE: &str = "initialize";
+    pub const SWAP: &str = "swap";
+    pub const DEPOSIT_ALL_TOKEN_TYPES: &str = "depositAllTokenTypes";
+    pub const WITHDRAW_ALL_TOKEN_TYPES: &str = "withdrawAllTokenTypes";
+
+    pub const ALL: &[&str] = &[
+        INITIALIZE,
+        SWAP,
+        DEPOSIT_ALL_TOKEN_TYPES,
+        WITHDRAW_ALL_TOKEN_TYPES,
+    ];
+}
+
+pub mod token_upgrade {
+    pub const EXCHANGE: &str = "exchange";
+
+    pub const ALL: &[&str] = &[EXCHANGE];
+}
+
+pub mod vote {
+    pub const INITIALIZE: &str = "initialize";
+    pub const AUTHORIZE: &str = "authorize";
+    pub const AUTHORIZE_WITH_SEED: &str = "authorizeWithSeed";
+    pub const AUTHORIZE_CHECKED_WITH_SEED: &str = "authorizeCheckedWithSeed";
+    pub const VOTE: &str = "vote";
+    pub const UPDATE_VOTE_STATE: &str = "updatevotestate";
+    pub const UPDATE_VOTE_STATE_SWITCH: &str = "updatevotestateswitch";
+    pub const WITHDRAW: &str = "withdraw";
+    pub const UPDATE_VALIDATOR_IDENTITY: &str = "updateValidatorIdentity";
+    pub const UPDATE_COMMISSION: &str = "updateCommission";
+    pub const VOTE_SWITCH: &str = "voteSwitch";
+    pub const AUTHORIZE_CHECKED: &str = "authorizeChecked";
+
+    pub const ALL: &[&str] = &[
+        INITIALIZE,
+        AUTHORIZE,
+        AUTHORIZE_WITH_SEED,
+        AUTHORIZE_CHECKED_WITH_SEED,
+        VOTE,
+        UPDATE_VOTE_STATE,
+        UPDATE_VOTE_STATE_SWITCH,
+        WITHDRAW,
+        UPDATE_VALIDATOR_IDENTITY,
+        UPDATE_COMMISSION,
+        VOTE_SWITCH,
+        AUTHORIZE_CHECKED,
+    ];
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, inflector::Inflector, std::collections::HashSet};
+
+    #[test]
+    fn test_instruction_types() {
+        for all in [
+            address_lookup_table::ALL,
+            associated_token::ALL,
+            bpf_loader::ALL,
+            bpf_upgradeable_loader::ALL,
+            budget::ALL,
+            compute_budget::ALL,
+            exchange::ALL,
+            feature_proposal::ALL,
+            governance::ALL,
+            jito_tip_distribution::ALL,
+            jito_tip_payment::ALL,
+            name_service::ALL,
+            noop::ALL,
+            secp256r1::ALL,
+            stake::ALL,
+            stake_pool::ALL,
+            system::ALL,
+            token::ALL,
+            token_lending::ALL,
+            token_metadata::ALL,
+            token_swap::ALL,
+            token_upgrade::ALL,
+            vote::ALL,
+        ] {
+            assert!(!all.is_empty());
+            let unique: HashSet<_> = all.iter().collect();
+            assert_eq!(unique.len(), all.len());
+            for instruction_type in all {
+                assert_eq!(&instruction_type.to_camel_case(), instruction_type);
+            }
+        }
+        assert!(system::ALL.contains(&"transfer"));
+        assert!(token::ALL.contains(&"transferCheckedWithFee"));
+        assert_eq!(vote::UPDATE_VOTE_STATE, "updatevotestate");
+    }
+}
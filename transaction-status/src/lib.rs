@@ -32,15 +32,44 @@ extern crate lazy_static;
 #[macro_use]
 extern crate serde_derive;
 
+// The crates of most third-party programs parsed here, such as the SPL stake pool, governance
+// and token swap programs, Metaplex or Jito, are not dependencies. Their parsers mirror the
+// program ids and the instruction layouts they decode.
 pub mod extract_memos;
+pub mod instruction_types;
 pub mod parse_accounts;
+pub mod parse_address_lookup_table;
+pub mod parse_anchor;
 pub mod parse_associated_token;
 pub mod parse_bpf_loader;
+pub mod parse_clockwork;
+pub mod parse_compute_budget;
+#[cfg(feature = "dex-names")]
+pub mod parse_dex;
+pub mod parse_encode;
+pub mod parse_export;
+pub mod parse_feature_proposal;
+pub mod parse_governance;
 pub mod parse_instruction;
+pub mod parse_jito;
+pub mod parse_jupiter;
+#[cfg(feature = "legacy-programs")]
+pub mod parse_legacy;
+pub mod parse_message;
+pub mod parse_name_service;
+pub mod parse_noop;
+pub mod parse_registry;
+pub mod parse_secp256r1;
 pub mod parse_stake;
+pub mod parse_stake_pool;
 pub mod parse_system;
 pub mod parse_token;
+pub mod parse_token_lending;
+pub mod parse_token_metadata;
+pub mod parse_token_swap;
+pub mod parse_token_upgrade;
 pub mod parse_vote;
+pub mod parse_warnings;
 pub mod token_balances;
 
 pub struct BlockEncodingOptions {
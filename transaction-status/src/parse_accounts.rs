@@ -1,4 +1,11 @@
-use solana_sdk::message::{v0::LoadedMessage, Message};
+use {
+    solana_sdk::{
+        instruction::CompiledInstruction,
+        message::{v0::LoadedMessage, Message},
+        pubkey::Pubkey,
+    },
+    std::collections::HashMap,
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -32,13 +39,70 @@ pub fn parse_static_accounts(message: &LoadedMessage) -> Vec<ParsedAccount> {
     accounts
 }
 
+/// The account that pays the transaction fee, always the first account key. Sanitized messages
+/// always have one; panics if `message` has no account keys.
+pub fn fee_payer(message: &Message) -> Pubkey {
+    message.account_keys[0]
+}
+
+/// Indexes of the instructions in `message` that reference the fee payer as an account
+pub fn fee_payer_instruction_indexes(message: &Message) -> Vec<usize> {
+    message
+        .instructions
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| instruction.accounts.contains(&0))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// The distinct accounts `instruction` may write, in the order it lists them, as permitted by
+/// the message header
+pub fn writable_accounts(message: &Message, instruction: &CompiledInstruction) -> Vec<Pubkey> {
+    let mut writable: Vec<Pubkey> = vec![];
+    for index in instruction.accounts.iter().map(|i| *i as usize) {
+        if message.is_writable(index) && !writable.contains(&message.account_keys[index]) {
+            writable.push(message.account_keys[index]);
+        }
+    }
+    writable
+}
+
+/// The distinct accounts that must sign for `instruction`, in the order it lists them, as
+/// required by the message header
+pub fn required_signers(message: &Message, instruction: &CompiledInstruction) -> Vec<Pubkey> {
+    let mut signers: Vec<Pubkey> = vec![];
+    for index in instruction.accounts.iter().map(|i| *i as usize) {
+        if message.is_signer(index) && !signers.contains(&message.account_keys[index]) {
+            signers.push(message.account_keys[index]);
+        }
+    }
+    signers
+}
+
+/// Indexes of the instructions in `message` invoking each program, keyed by program id.
+/// Instructions whose program index is out of range are left out.
+pub fn group_by_program(message: &Message) -> HashMap<Pubkey, Vec<usize>> {
+    let mut groups: HashMap<Pubkey, Vec<usize>> = HashMap::new();
+    for (i, instruction) in message.instructions.iter().enumerate() {
+        if let Some(program_id) = message
+            .account_keys
+            .get(instruction.program_id_index as usize)
+        {
+            groups.entry(*program_id).or_default().push(i);
+        }
+    }
+    groups
+}
+
 #[cfg(test)]
 mod test {
     use {
         super::*,
         solana_sdk::{
+            instruction::Instruction,
             message::{v0, v0::LoadedAddresses, MessageHeader},
-            pubkey::Pubkey,
+            system_instruction, system_program,
         },
     };
 
@@ -133,4 +197,88 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_fee_payer() {
+        let payer = Pubkey::new_unique();
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(
+            &[
+                system_instruction::transfer(&from, &to, 1),
+                system_instruction::transfer(&payer, &to, 1),
+            ],
+            Some(&payer),
+        );
+        assert_eq!(fee_payer(&message), payer);
+        assert_eq!(fee_payer_instruction_indexes(&message), vec![1]);
+    }
+
+    #[test]
+    fn test_writable_accounts() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(&[system_instruction::transfer(&from, &to, 1)], None);
+        assert_eq!(
+            writable_accounts(&message, &message.instructions[0]),
+            vec![from, to]
+        );
+
+        // The sysvar and the nonce authority are read-only
+        let nonce = Pubkey::new_unique();
+        let message = Message::new(
+            &[system_instruction::advance_nonce_account(&nonce, &from)],
+            None,
+        );
+        assert_eq!(
+            writable_accounts(&message, &message.instructions[0]),
+            vec![nonce]
+        );
+    }
+
+    #[test]
+    fn test_required_signers() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(&[system_instruction::transfer(&from, &to, 1)], None);
+        assert_eq!(
+            required_signers(&message, &message.instructions[0]),
+            vec![from]
+        );
+
+        let owner = Pubkey::new_unique();
+        let message = Message::new(
+            &[system_instruction::create_account(&from, &to, 1, 0, &owner)],
+            None,
+        );
+        assert_eq!(
+            required_signers(&message, &message.instructions[0]),
+            vec![from, to]
+        );
+    }
+
+    #[test]
+    fn test_group_by_program() {
+        let from = Pubkey::new_unique();
+        let memo_program_id = Pubkey::new_unique();
+        let mut message = Message::new(
+            &[
+                system_instruction::transfer(&from, &Pubkey::new_unique(), 42),
+                Instruction::new_with_bytes(memo_program_id, b"memo", vec![]),
+                system_instruction::transfer(&from, &Pubkey::new_unique(), 43),
+            ],
+            Some(&from),
+        );
+        let groups = group_by_program(&message);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&system_program::id()], vec![0, 2]);
+        assert_eq!(groups[&memo_program_id], vec![1]);
+
+        message.instructions[1].program_id_index = message.account_keys.len() as u8;
+        let groups = group_by_program(&message);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[&system_program::id()], vec![0, 2]);
+
+        assert!(group_by_program(&Message::default()).is_empty());
+    }
 }
@@ -0,0 +1,327 @@
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+        },
+    },
+    bincode::deserialize,
+    serde_json::json,
+    solana_address_lookup_table_program::instruction::ProgramInstruction,
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys},
+};
+
+pub fn parse_address_lookup_table(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    let address_lookup_table_instruction: ProgramInstruction = deserialize(&instruction.data)
+        .map_err(|_| {
+            ParseInstructionError::InstructionNotParsable(ParsableProgram::AddressLookupTable)
+        })?;
+    match instruction.accounts.iter().max() {
+        Some(index) if (*index as usize) < account_keys.len() => {}
+        _ => {
+            // Runtime should prevent this from ever happening
+            return Err(ParseInstructionError::InstructionKeyMismatch(
+                ParsableProgram::AddressLookupTable,
+            ));
+        }
+    }
+    match address_lookup_table_instruction {
+        ProgramInstruction::CreateLookupTable {
+            recent_slot,
+            bump_seed,
+        } => {
+            check_num_address_lookup_table_accounts(&instruction.accounts, 4)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::address_lookup_table::CREATE_LOOKUP_TABLE
+                    .into(),
+                info: json!({
+                    "lookupTableAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                    "lookupTableAuthority": account_keys[instruction.accounts[1] as usize].to_string(),
+                    "payerAccount": account_keys[instruction.accounts[2] as usize].to_string(),
+                    "systemProgram": account_keys[instruction.accounts[3] as usize].to_string(),
+                    "recentSlot": recent_slot,
+                    "bumpSeed": bump_seed,
+                }),
+            })
+        }
+        ProgramInstruction::FreezeLookupTable => {
+            check_num_address_lookup_table_accounts(&instruction.accounts, 2)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::address_lookup_table::FREEZE_LOOKUP_TABLE
+                    .into(),
+                info: json!({
+                    "lookupTableAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                    "lookupTableAuthority": account_keys[instruction.accounts[1] as usize].to_string(),
+                }),
+            })
+        }
+        ProgramInstruction::ExtendLookupTable { new_addresses } => {
+            check_num_address_lookup_table_accounts(&instruction.accounts, 2)?;
+            // Funding accounts are only meaningful when the table actually grows, and the
+            // payer is never passed without the system program
+            if instruction.accounts.len() > 2 {
+                if new_addresses.is_empty() {
+                    return Err(ParseInstructionError::InstructionKeyMismatch(
+                        ParsableProgram::AddressLookupTable,
+                    ));
+                }
+                check_num_address_lookup_table_accounts(&instruction.accounts, 4)?;
+            }
+            let new_addresses: Vec<String> = new_addresses
+                .into_iter()
+                .map(|address| address.to_string())
+                .collect();
+            let mut value = json!({
+                "lookupTableAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                "lookupTableAuthority": account_keys[instruction.accounts[1] as usize].to_string(),
+                "newAddressCount": new_addresses.len(),
+                "newAddresses": new_addresses,
+            });
+            let map = value.as_object_mut().unwrap();
+            if instruction.accounts.len() > 2 {
+                map.insert(
+                    "payerAccount".to_string(),
+                    json!(account_keys[instruction.accounts[2] as usize].to_string()),
+                );
+                map.insert(
+                    "systemProgram".to_string(),
+                    json!(account_keys[instruction.accounts[3] as usize].to_string()),
+                );
+            }
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::address_lookup_table::EXTEND_LOOKUP_TABLE
+                    .into(),
+                info: value,
+            })
+        }
+        ProgramInstruction::DeactivateLookupTable => {
+            check_num_address_lookup_table_accounts(&instruction.accounts, 2)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::address_lookup_table::DEACTIVATE_LOOKUP_TABLE
+                    .into(),
+                info: json!({
+                    "lookupTableAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                    "lookupTableAuthority": account_keys[instruction.accounts[1] as usize].to_string(),
+                }),
+            })
+        }
+        ProgramInstruction::CloseLookupTable => {
+            check_num_address_lookup_table_accounts(&instruction.accounts, 3)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::address_lookup_table::CLOSE_LOOKUP_TABLE
+                    .into(),
+                info: json!({
+                    "lookupTableAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                    "lookupTableAuthority": account_keys[instruction.accounts[1] as usize].to_string(),
+                    "recipient": account_keys[instruction.accounts[2] as usize].to_string(),
+                }),
+            })
+        }
+    }
+}
+
+fn check_num_address_lookup_table_accounts(
+    accounts: &[u8],
+    num: usize,
+) -> Result<(), ParseInstructionError> {
+    check_num_accounts(accounts, num, ParsableProgram::AddressLookupTable)
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        solana_address_lookup_table_program::instruction,
+        solana_sdk::{message::Message, pubkey::Pubkey, system_program},
+    };
+
+    #[test]
+    fn test_parse_create_address_lookup_table_ix() {
+        let from_pubkey = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let slot = 42;
+
+        let (instruction, lookup_table_pubkey) =
+            instruction::create_lookup_table(authority, from_pubkey, slot);
+        let bump_seed = match bincode::deserialize(&instruction.data).unwrap() {
+            ProgramInstruction::CreateLookupTable { bump_seed, .. } => bump_seed,
+            _ => unreachable!(),
+        };
+        let mut message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_address_lookup_table(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "createLookupTable".into(),
+                info: json!({
+                    "lookupTableAccount": lookup_table_pubkey.to_string(),
+                    "lookupTableAuthority": authority.to_string(),
+                    "payerAccount": from_pubkey.to_string(),
+                    "systemProgram": system_program::id().to_string(),
+                    "recentSlot": slot,
+                    "bumpSeed": bump_seed,
+                }),
+            }
+        );
+        assert!(parse_address_lookup_table(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys[0..3], None)
+        )
+        .is_err());
+        let keys = message.account_keys.clone();
+        message.instructions[0].accounts.pop();
+        assert!(parse_address_lookup_table(
+            &message.instructions[0],
+            &AccountKeys::new(&keys, None)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_freeze_deactivate_close_address_lookup_table_ix() {
+        let lookup_table_pubkey = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+
+        let message = Message::new(
+            &[
+                instruction::freeze_lookup_table(lookup_table_pubkey, authority),
+                instruction::deactivate_lookup_table(lookup_table_pubkey, authority),
+                instruction::close_lookup_table(lookup_table_pubkey, authority, recipient),
+            ],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        assert_eq!(
+            parse_address_lookup_table(&message.instructions[0], &account_keys).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "freezeLookupTable".into(),
+                info: json!({
+                    "lookupTableAccount": lookup_table_pubkey.to_string(),
+                    "lookupTableAuthority": authority.to_string(),
+                }),
+            }
+        );
+        assert_eq!(
+            parse_address_lookup_table(&message.instructions[1], &account_keys).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "deactivateLookupTable".into(),
+                info: json!({
+                    "lookupTableAccount": lookup_table_pubkey.to_string(),
+                    "lookupTableAuthority": authority.to_string(),
+                }),
+            }
+        );
+        assert_eq!(
+            parse_address_lookup_table(&message.instructions[2], &account_keys).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "closeLookupTable".into(),
+                info: json!({
+                    "lookupTableAccount": lookup_table_pubkey.to_string(),
+                    "lookupTableAuthority": authority.to_string(),
+                    "recipient": recipient.to_string(),
+                }),
+            }
+        );
+        let mut close_instruction = message.instructions[2].clone();
+        close_instruction.accounts.pop();
+        assert!(parse_address_lookup_table(&close_instruction, &account_keys).is_err());
+    }
+
+    #[test]
+    fn test_parse_extend_address_lookup_table_ix() {
+        let lookup_table_pubkey = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let new_addresses = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+        // Extend with payer
+        let instruction = instruction::extend_lookup_table(
+            lookup_table_pubkey,
+            authority,
+            Some(payer),
+            new_addresses.clone(),
+        );
+        let mut message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_address_lookup_table(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "extendLookupTable".into(),
+                info: json!({
+                    "lookupTableAccount": lookup_table_pubkey.to_string(),
+                    "lookupTableAuthority": authority.to_string(),
+                    "payerAccount": payer.to_string(),
+                    "systemProgram": system_program::id().to_string(),
+                    "newAddressCount": 2,
+                    "newAddresses": [
+                        new_addresses[0].to_string(),
+                        new_addresses[1].to_string(),
+                    ],
+                }),
+            }
+        );
+        // A payer without the system program is rejected
+        let keys = message.account_keys.clone();
+        message.instructions[0].accounts.pop();
+        assert!(parse_address_lookup_table(
+            &message.instructions[0],
+            &AccountKeys::new(&keys, None)
+        )
+        .is_err());
+
+        // Extend without payer
+        let instruction = instruction::extend_lookup_table(
+            lookup_table_pubkey,
+            authority,
+            None,
+            new_addresses.clone(),
+        );
+        let mut message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_address_lookup_table(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "extendLookupTable".into(),
+                info: json!({
+                    "lookupTableAccount": lookup_table_pubkey.to_string(),
+                    "lookupTableAuthority": authority.to_string(),
+                    "newAddressCount": 2,
+                    "newAddresses": [
+                        new_addresses[0].to_string(),
+                        new_addresses[1].to_string(),
+                    ],
+                }),
+            }
+        );
+        let keys = message.account_keys.clone();
+        message.instructions[0].accounts.pop();
+        assert!(parse_address_lookup_table(
+            &message.instructions[0],
+            &AccountKeys::new(&keys, None)
+        )
+        .is_err());
+
+        // Funding accounts with nothing to extend are rejected
+        let instruction =
+            instruction::extend_lookup_table(lookup_table_pubkey, authority, Some(payer), vec![]);
+        let message = Message::new(&[instruction], None);
+        assert!(parse_address_lookup_table(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None)
+        )
+        .is_err());
+    }
+}
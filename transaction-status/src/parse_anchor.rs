@@ -0,0 +1,163 @@
+//! Generic parser for programs built with Anchor, whose instruction data starts with an 8-byte
+//! discriminator derived from the instruction name. Arguments are passed through as base64.
+
+use {
+    crate::parse_instruction::{
+        InstructionParser, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+    },
+    inflector::Inflector,
+    serde_json::json,
+    solana_sdk::{
+        hash::hash, instruction::CompiledInstruction, message::AccountKeys, pubkey::Pubkey,
+    },
+    std::collections::HashMap,
+};
+
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+/// Anchor prefixes instruction data with the first 8 bytes of sha256("global:<method>"), where
+/// `method` is the snake_case instruction name
+pub fn anchor_discriminator(method: &str) -> [u8; DISCRIMINATOR_LEN] {
+    let mut discriminator = [0; DISCRIMINATOR_LEN];
+    discriminator.copy_from_slice(
+        &hash(format!("global:{}", method.to_snake_case()).as_bytes()).to_bytes()
+            [..DISCRIMINATOR_LEN],
+    );
+    discriminator
+}
+
+/// Parser for a single Anchor program, registered with a
+/// [`ParserRegistry`](crate::parse_registry::ParserRegistry)
+pub struct AnchorParser {
+    program_id: Pubkey,
+    program_name: Option<String>,
+    instruction_names: HashMap<[u8; DISCRIMINATOR_LEN], String>,
+}
+
+impl AnchorParser {
+    /// Without instruction names, instructions are typed by their hex discriminator
+    pub fn new(program_id: Pubkey) -> Self {
+        Self {
+            program_id,
+            program_name: None,
+            instruction_names: HashMap::new(),
+        }
+    }
+
+    /// Names instructions from the instruction names listed in the program's IDL
+    pub fn with_idl_instructions<'a>(mut self, names: impl IntoIterator<Item = &'a str>) -> Self {
+        self.instruction_names.extend(
+            names
+                .into_iter()
+                .map(|name| (anchor_discriminator(name), name.to_camel_case())),
+        );
+        self
+    }
+
+    pub fn with_program_name(mut self, program_name: &str) -> Self {
+        self.program_name = Some(program_name.to_string());
+        self
+    }
+}
+
+impl InstructionParser for AnchorParser {
+    fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    fn program_name(&self) -> String {
+        self.program_name
+            .clone()
+            .unwrap_or_else(|| self.program_id.to_string())
+    }
+
+    fn parse(
+        &self,
+        instruction: &CompiledInstruction,
+        _account_keys: &AccountKeys,
+    ) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+        if instruction.data.len() < DISCRIMINATOR_LEN {
+            return Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::Anchor,
+            ));
+        }
+        let (discriminator, args) = instruction.data.split_at(DISCRIMINATOR_LEN);
+        let instruction_type = match self.instruction_names.get(discriminator) {
+            Some(name) => name.clone(),
+            None => discriminator
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect(),
+        };
+        Ok(ParsedInstructionEnum {
+            instruction_type: instruction_type.into(),
+            info: json!({
+                "args": base64::encode(args),
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_anchor() {
+        let program_id = Pubkey::new_unique();
+        let keys = [Pubkey::new_unique()];
+        let parser =
+            AnchorParser::new(program_id).with_idl_instructions(["initialize", "placeOrder"]);
+
+        let mut data = anchor_discriminator("place_order").to_vec();
+        data.extend_from_slice(&[1, 2, 3]);
+        let instruction = CompiledInstruction::new_from_raw_parts(0, data, vec![0]);
+        assert_eq!(
+            parser
+                .parse(&instruction, &AccountKeys::new(&keys, None))
+                .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "placeOrder".into(),
+                info: json!({
+                    "args": "AQID",
+                }),
+            }
+        );
+
+        // Unknown discriminator
+        let instruction =
+            CompiledInstruction::new_from_raw_parts(0, vec![0, 1, 2, 3, 4, 5, 6, 7], vec![0]);
+        assert_eq!(
+            parser
+                .parse(&instruction, &AccountKeys::new(&keys, None))
+                .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "0001020304050607".into(),
+                info: json!({
+                    "args": "",
+                }),
+            }
+        );
+
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![0; 7], vec![0]);
+        assert!(matches!(
+            parser.parse(&instruction, &AccountKeys::new(&keys, None)),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::Anchor
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_anchor_discriminator() {
+        // sha256("global:initialize")
+        assert_eq!(
+            anchor_discriminator("initialize"),
+            [175, 175, 109, 31, 13, 152, 155, 237]
+        );
+        assert_eq!(
+            anchor_discriminator("placeOrder"),
+            anchor_discriminator("place_order")
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use {
-    crate::parse_instruction::{
-        check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+        },
     },
     borsh::BorshDeserialize,
     serde_json::json,
@@ -30,7 +33,7 @@ pub fn parse_associated_token(
     if instruction.data.is_empty() {
         check_num_associated_token_accounts(&instruction.accounts, 7)?;
         Ok(ParsedInstructionEnum {
-            instruction_type: "create".to_string(),
+            instruction_type: instruction_types::associated_token::CREATE.into(),
             info: json!({
                 "source": account_keys[instruction.accounts[0] as usize].to_string(),
                 "account": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -50,7 +53,7 @@ pub fn parse_associated_token(
             AssociatedTokenAccountInstruction::Create => {
                 check_num_associated_token_accounts(&instruction.accounts, 6)?;
                 Ok(ParsedInstructionEnum {
-                    instruction_type: "create".to_string(),
+                    instruction_type: instruction_types::associated_token::CREATE.into(),
                     info: json!({
                         "source": account_keys[instruction.accounts[0] as usize].to_string(),
                         "account": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -133,7 +136,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "create".to_string(),
+                instruction_type: "create".into(),
                 info: json!({
                     "source": funder.to_string(),
                     "account": associated_account_address.to_string(),
@@ -174,7 +177,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "create".to_string(),
+                instruction_type: "create".into(),
                 info: json!({
                     "source": funder.to_string(),
                     "account": associated_account_address.to_string(),
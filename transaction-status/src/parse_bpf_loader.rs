@@ -1,12 +1,18 @@
 use {
-    crate::parse_instruction::{
-        check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            check_num_accounts, ParsableProgram, ParseConfig, ParseInstructionError,
+            ParsedInstructionEnum,
+        },
     },
     bincode::deserialize,
-    serde_json::json,
+    serde_json::{json, Value},
     solana_sdk::{
-        instruction::CompiledInstruction, loader_instruction::LoaderInstruction,
+        bpf_loader_upgradeable::UpgradeableLoaderState, instruction::CompiledInstruction,
+        loader_instruction::LoaderInstruction,
         loader_upgradeable_instruction::UpgradeableLoaderInstruction, message::AccountKeys,
+        pubkey::Pubkey,
     },
 };
 
@@ -25,7 +31,7 @@ pub fn parse_bpf_loader(
         LoaderInstruction::Write { offset, bytes } => {
             check_num_bpf_loader_accounts(&instruction.accounts, 1)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "write".to_string(),
+                instruction_type: instruction_types::bpf_loader::WRITE.into(),
                 info: json!({
                     "offset": offset,
                     "bytes": base64::encode(bytes),
@@ -36,7 +42,7 @@ pub fn parse_bpf_loader(
         LoaderInstruction::Finalize => {
             check_num_bpf_loader_accounts(&instruction.accounts, 2)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "finalize".to_string(),
+                instruction_type: instruction_types::bpf_loader::FINALIZE.into(),
                 info: json!({
                     "account": account_keys[instruction.accounts[0] as usize].to_string(),
                 }),
@@ -48,6 +54,14 @@ pub fn parse_bpf_loader(
 pub fn parse_bpf_upgradeable_loader(
     instruction: &CompiledInstruction,
     account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    parse_bpf_upgradeable_loader_with_config(instruction, account_keys, &ParseConfig::default())
+}
+
+pub fn parse_bpf_upgradeable_loader_with_config(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+    config: &ParseConfig,
 ) -> Result<ParsedInstructionEnum, ParseInstructionError> {
     let bpf_upgradeable_loader_instruction: UpgradeableLoaderInstruction =
         deserialize(&instruction.data).map_err(|_| {
@@ -76,14 +90,15 @@ pub fn parse_bpf_upgradeable_loader(
                 );
             }
             Ok(ParsedInstructionEnum {
-                instruction_type: "initializeBuffer".to_string(),
+                instruction_type: instruction_types::bpf_upgradeable_loader::INITIALIZE_BUFFER
+                    .into(),
                 info: value,
             })
         }
         UpgradeableLoaderInstruction::Write { offset, bytes } => {
             check_num_bpf_upgradeable_loader_accounts(&instruction.accounts, 2)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "write".to_string(),
+                instruction_type: instruction_types::bpf_upgradeable_loader::WRITE.into(),
                 info: json!({
                     "offset": offset,
                     "bytes": base64::encode(bytes),
@@ -94,40 +109,47 @@ pub fn parse_bpf_upgradeable_loader(
         }
         UpgradeableLoaderInstruction::DeployWithMaxDataLen { max_data_len } => {
             check_num_bpf_upgradeable_loader_accounts(&instruction.accounts, 8)?;
+            let mut value = json!({
+                "maxDataLen": max_data_len,
+                "payerAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                "programDataAccount": account_keys[instruction.accounts[1] as usize].to_string(),
+                "programAccount": account_keys[instruction.accounts[2] as usize].to_string(),
+                "bufferAccount": account_keys[instruction.accounts[3] as usize].to_string(),
+                "rentSysvar": account_keys[instruction.accounts[4] as usize].to_string(),
+                "clockSysvar": account_keys[instruction.accounts[5] as usize].to_string(),
+                "systemProgram": account_keys[instruction.accounts[6] as usize].to_string(),
+                "authority": account_keys[instruction.accounts[7] as usize].to_string(),
+            });
+            let program_data_account = &account_keys[instruction.accounts[1] as usize];
+            insert_program_data_version(&mut value, program_data_account, config);
             Ok(ParsedInstructionEnum {
-                instruction_type: "deployWithMaxDataLen".to_string(),
-                info: json!({
-                    "maxDataLen": max_data_len,
-                    "payerAccount": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "programDataAccount": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "programAccount": account_keys[instruction.accounts[2] as usize].to_string(),
-                    "bufferAccount": account_keys[instruction.accounts[3] as usize].to_string(),
-                    "rentSysvar": account_keys[instruction.accounts[4] as usize].to_string(),
-                    "clockSysvar": account_keys[instruction.accounts[5] as usize].to_string(),
-                    "systemProgram": account_keys[instruction.accounts[6] as usize].to_string(),
-                    "authority": account_keys[instruction.accounts[7] as usize].to_string(),
-                }),
+                instruction_type:
+                    instruction_types::bpf_upgradeable_loader::DEPLOY_WITH_MAX_DATA_LEN.into(),
+                info: value,
             })
         }
         UpgradeableLoaderInstruction::Upgrade => {
             check_num_bpf_upgradeable_loader_accounts(&instruction.accounts, 7)?;
+            let mut value = json!({
+                "programDataAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                "programAccount": account_keys[instruction.accounts[1] as usize].to_string(),
+                "bufferAccount": account_keys[instruction.accounts[2] as usize].to_string(),
+                "spillAccount": account_keys[instruction.accounts[3] as usize].to_string(),
+                "rentSysvar": account_keys[instruction.accounts[4] as usize].to_string(),
+                "clockSysvar": account_keys[instruction.accounts[5] as usize].to_string(),
+                "authority": account_keys[instruction.accounts[6] as usize].to_string(),
+            });
+            let program_data_account = &account_keys[instruction.accounts[0] as usize];
+            insert_program_data_version(&mut value, program_data_account, config);
             Ok(ParsedInstructionEnum {
-                instruction_type: "upgrade".to_string(),
-                info: json!({
-                    "programDataAccount": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "programAccount": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "bufferAccount": account_keys[instruction.accounts[2] as usize].to_string(),
-                    "spillAccount": account_keys[instruction.accounts[3] as usize].to_string(),
-                    "rentSysvar": account_keys[instruction.accounts[4] as usize].to_string(),
-                    "clockSysvar": account_keys[instruction.accounts[5] as usize].to_string(),
-                    "authority": account_keys[instruction.accounts[6] as usize].to_string(),
-                }),
+                instruction_type: instruction_types::bpf_upgradeable_loader::UPGRADE.into(),
+                info: value,
             })
         }
         UpgradeableLoaderInstruction::SetAuthority => {
             check_num_bpf_upgradeable_loader_accounts(&instruction.accounts, 2)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "setAuthority".to_string(),
+                instruction_type: instruction_types::bpf_upgradeable_loader::SET_AUTHORITY.into(),
                 info: json!({
                     "account": account_keys[instruction.accounts[0] as usize].to_string(),
                     "authority": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -142,7 +164,7 @@ pub fn parse_bpf_upgradeable_loader(
         UpgradeableLoaderInstruction::Close => {
             check_num_bpf_upgradeable_loader_accounts(&instruction.accounts, 3)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "close".to_string(),
+                instruction_type: instruction_types::bpf_upgradeable_loader::CLOSE.into(),
                 info: json!({
                     "account": account_keys[instruction.accounts[0] as usize].to_string(),
                     "recipient": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -151,24 +173,52 @@ pub fn parse_bpf_upgradeable_loader(
             })
         }
         UpgradeableLoaderInstruction::ExtendProgramData { additional_bytes } => {
-            check_num_bpf_upgradeable_loader_accounts(&instruction.accounts, 2)?;
+            check_num_bpf_upgradeable_loader_accounts(&instruction.accounts, 1)?;
+            let mut value = json!({
+                "additionalBytes": additional_bytes,
+                "programDataAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+            });
+            // The system program and payer are only passed when the extension needs funding
+            let map = value.as_object_mut().unwrap();
+            if instruction.accounts.len() > 1 {
+                map.insert(
+                    "systemProgram".to_string(),
+                    json!(account_keys[instruction.accounts[1] as usize].to_string()),
+                );
+            }
+            if instruction.accounts.len() > 2 {
+                map.insert(
+                    "payerAccount".to_string(),
+                    json!(account_keys[instruction.accounts[2] as usize].to_string()),
+                );
+            }
             Ok(ParsedInstructionEnum {
-                instruction_type: "extendProgramData".to_string(),
-                info: json!({
-                    "additionalBytes": additional_bytes,
-                    "programDataAccount": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "systemProgram": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "payerAccount": if instruction.accounts.len() > 2 {
-                        Some(account_keys[instruction.accounts[2] as usize].to_string())
-                    } else {
-                        None
-                    },
-                }),
+                instruction_type: instruction_types::bpf_upgradeable_loader::EXTEND_PROGRAM_DATA
+                    .into(),
+                info: value,
             })
         }
     }
 }
 
+// Instructions carry no version, so the slot the program data was last written, which changes
+// with every deploy and upgrade, stands in for it. Added when the program data account's data
+// was supplied and holds deployed program data.
+fn insert_program_data_version(
+    value: &mut Value,
+    program_data_account: &Pubkey,
+    config: &ParseConfig,
+) {
+    if let Some(UpgradeableLoaderState::ProgramData { slot, .. }) = config
+        .account_data
+        .get(program_data_account)
+        .and_then(|data| deserialize(data).ok())
+    {
+        let map = value.as_object_mut().unwrap();
+        map.insert("programDataVersion".to_string(), json!(slot));
+    }
+}
+
 fn check_num_bpf_loader_accounts(accounts: &[u8], num: usize) -> Result<(), ParseInstructionError> {
     check_num_accounts(accounts, num, ParsableProgram::BpfLoader)
 }
@@ -217,7 +267,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "write".to_string(),
+                instruction_type: "write".into(),
                 info: json!({
                     "offset": offset,
                     "bytes": base64::encode(&bytes),
@@ -246,7 +296,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "finalize".to_string(),
+                instruction_type: "finalize".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                 }),
@@ -310,7 +360,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeBuffer".to_string(),
+                instruction_type: "initializeBuffer".into(),
                 info: json!({
                     "account": buffer_address.to_string(),
                     "authority": authority_address.to_string(),
@@ -353,7 +403,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "write".to_string(),
+                instruction_type: "write".into(),
                 info: json!({
                     "offset": offset,
                     "bytes": base64::encode(&bytes),
@@ -406,7 +456,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "deployWithMaxDataLen".to_string(),
+                instruction_type: "deployWithMaxDataLen".into(),
                 info: json!({
                     "maxDataLen": max_data_len,
                     "payerAccount": payer_address.to_string(),
@@ -459,7 +509,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "upgrade".to_string(),
+                instruction_type: "upgrade".into(),
                 info: json!({
                     "authority": authority_address.to_string(),
                     "programDataAccount": programdata_address.to_string(),
@@ -485,6 +535,54 @@ mod test {
         .is_err());
     }
 
+    #[test]
+    fn test_parse_bpf_upgradeable_loader_program_data_version() {
+        let program_address = Pubkey::new_unique();
+        let authority_address = Pubkey::new_unique();
+        let programdata_address = Pubkey::find_program_address(
+            &[program_address.as_ref()],
+            &bpf_loader_upgradeable::id(),
+        )
+        .0;
+        let instruction = bpf_loader_upgradeable::upgrade(
+            &program_address,
+            &Pubkey::new_unique(),
+            &authority_address,
+            &Pubkey::new_unique(),
+        );
+        let message = Message::new(&[instruction], None);
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let program_data_version = |data: Vec<u8>| {
+            let config = ParseConfig::default().with_account_data(programdata_address, data);
+            parse_bpf_upgradeable_loader_with_config(
+                &message.instructions[0],
+                &account_keys,
+                &config,
+            )
+            .unwrap()
+            .info
+            .get("programDataVersion")
+            .cloned()
+        };
+
+        // Program data is followed by the program itself
+        let mut data = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+            slot: 42,
+            upgrade_authority_address: Some(authority_address),
+        })
+        .unwrap();
+        data.extend_from_slice(&[0x7f, b'E', b'L', b'F']);
+        assert_eq!(program_data_version(data), Some(json!(42)));
+
+        // Other account states carry no version
+        let data = bincode::serialize(&UpgradeableLoaderState::Buffer {
+            authority_address: Some(authority_address),
+        })
+        .unwrap();
+        assert_eq!(program_data_version(data), None);
+        assert_eq!(program_data_version(vec![]), None);
+    }
+
     #[test]
     fn test_parse_bpf_upgradeable_loader_set_buffer_authority_ix() {
         let buffer_address = Pubkey::new_unique();
@@ -503,7 +601,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "setAuthority".to_string(),
+                instruction_type: "setAuthority".into(),
                 info: json!({
                     "account": buffer_address.to_string(),
                     "authority": current_authority_address.to_string(),
@@ -548,7 +646,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "setAuthority".to_string(),
+                instruction_type: "setAuthority".into(),
                 info: json!({
                     "account": programdata_address.to_string(),
                     "authority": current_authority_address.to_string(),
@@ -583,7 +681,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "setAuthority".to_string(),
+                instruction_type: "setAuthority".into(),
                 info: json!({
                     "account": programdata_address.to_string(),
                     "authority": current_authority_address.to_string(),
@@ -620,7 +718,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "close".to_string(),
+                instruction_type: "close".into(),
                 info: json!({
                     "account": close_address.to_string(),
                     "recipient": recipient_address.to_string(),
@@ -641,4 +739,64 @@ mod test {
         )
         .is_err());
     }
+
+    #[test]
+    fn test_parse_bpf_upgradeable_loader_extend_program_data_ix() {
+        let program_data_address = Pubkey::new_unique();
+        let payer_address = Pubkey::new_unique();
+
+        // Without a payer
+        let instruction =
+            bpf_loader_upgradeable::extend_program_data(&program_data_address, None, 1024);
+        let message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_bpf_upgradeable_loader(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "extendProgramData".into(),
+                info: json!({
+                    "additionalBytes": 1024,
+                    "programDataAccount": program_data_address.to_string(),
+                }),
+            }
+        );
+
+        // With a payer funding the extension
+        let instruction = bpf_loader_upgradeable::extend_program_data(
+            &program_data_address,
+            Some(&payer_address),
+            1024,
+        );
+        let mut message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_bpf_upgradeable_loader(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "extendProgramData".into(),
+                info: json!({
+                    "additionalBytes": 1024,
+                    "programDataAccount": program_data_address.to_string(),
+                    "systemProgram": system_program::id().to_string(),
+                    "payerAccount": payer_address.to_string(),
+                }),
+            }
+        );
+        assert!(parse_bpf_upgradeable_loader(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys[0..2], None)
+        )
+        .is_err());
+        message.instructions[0].accounts.clear();
+        assert!(parse_bpf_upgradeable_loader(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None)
+        )
+        .is_err());
+    }
 }
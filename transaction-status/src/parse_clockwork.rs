@@ -0,0 +1,142 @@
+//! Stub parser for the Clockwork thread program, registered with a
+//! [`ParserRegistry`](crate::parse_registry::ParserRegistry). Threads wrap instructions for
+//! automated execution; only thread creation and execution are named, and creation lists the
+//! programs the thread will invoke.
+
+use {
+    crate::{
+        parse_anchor::{anchor_discriminator, DISCRIMINATOR_LEN},
+        parse_instruction::{InstructionParser, ParseInstructionError, ParsedInstructionEnum},
+    },
+    borsh::BorshDeserialize,
+    serde_json::json,
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys, pubkey::Pubkey},
+};
+
+solana_sdk::declare_id!("CLoCKyJ6DXBJqqu2VWx9RLbgnwwR6BMHHuyasVmfMzBh");
+
+// `SerializableInstruction`: program id, (pubkey, is_signer, is_writable) accounts and data
+type SerializableInstruction = ([u8; 32], Vec<([u8; 32], bool, bool)>, Vec<u8>);
+
+lazy_static! {
+    static ref THREAD_CREATE: [u8; DISCRIMINATOR_LEN] = anchor_discriminator("thread_create");
+    static ref THREAD_EXEC: [u8; DISCRIMINATOR_LEN] = anchor_discriminator("thread_exec");
+}
+
+pub struct ClockworkThreadParser;
+
+impl InstructionParser for ClockworkThreadParser {
+    fn program_id(&self) -> Pubkey {
+        id()
+    }
+
+    fn program_name(&self) -> String {
+        "clockwork-thread".to_string()
+    }
+
+    fn parse(
+        &self,
+        instruction: &CompiledInstruction,
+        account_keys: &AccountKeys,
+    ) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+        let unknown = || ParseInstructionError::UnknownInstruction(id());
+        if instruction.data.len() < DISCRIMINATOR_LEN {
+            return Err(unknown());
+        }
+        let (discriminator, mut args) = instruction.data.split_at(DISCRIMINATOR_LEN);
+        let thread = instruction
+            .accounts
+            .get(3)
+            .and_then(|index| account_keys.get(*index as usize))
+            .ok_or_else(unknown)?
+            .to_string();
+        if discriminator == *THREAD_CREATE {
+            let (amount, _id, instructions) =
+                <(u64, Vec<u8>, Vec<SerializableInstruction>)>::deserialize(&mut args)
+                    .map_err(|_| unknown())?;
+            let target_programs: Vec<String> = instructions
+                .into_iter()
+                .map(|(program_id, _, _)| Pubkey::new_from_array(program_id).to_string())
+                .collect();
+            Ok(ParsedInstructionEnum {
+                instruction_type: "threadCreate".into(),
+                info: json!({
+                    "thread": thread,
+                    "amount": amount,
+                    "targetPrograms": target_programs,
+                }),
+            })
+        } else if discriminator == *THREAD_EXEC {
+            Ok(ParsedInstructionEnum {
+                instruction_type: "threadExec".into(),
+                info: json!({
+                    "thread": thread,
+                }),
+            })
+        } else {
+            Err(unknown())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*, crate::parse_registry::ParserRegistry, borsh::BorshSerialize,
+        solana_sdk::system_program,
+    };
+
+    #[test]
+    fn test_parse_clockwork_thread() {
+        let mut registry = ParserRegistry::default();
+        registry.register(Box::new(ClockworkThreadParser));
+        let keys: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let account_keys = AccountKeys::new(&keys, None);
+
+        // A thread wrapping a single system transfer
+        let mut data = THREAD_CREATE.to_vec();
+        let instructions: Vec<SerializableInstruction> =
+            vec![(system_program::id().to_bytes(), vec![], vec![2, 0, 0, 0])];
+        data.extend(
+            (1_000_000u64, b"payroll".to_vec(), instructions)
+                .try_to_vec()
+                .unwrap(),
+        );
+        // Trigger::Now
+        data.push(4);
+        let instruction = CompiledInstruction::new_from_raw_parts(0, data, vec![0, 1, 2, 3]);
+        let parsed = registry.parse(&id(), &instruction, &account_keys).unwrap();
+        assert_eq!(parsed.program, "clockwork-thread");
+        assert_eq!(
+            parsed.parsed,
+            json!({
+                "type": "threadCreate",
+                "info": {
+                    "thread": keys[3].to_string(),
+                    "amount": 1_000_000,
+                    "targetPrograms": [system_program::id().to_string()],
+                },
+            })
+        );
+
+        let instruction =
+            CompiledInstruction::new_from_raw_parts(0, THREAD_EXEC.to_vec(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(
+            registry
+                .parse(&id(), &instruction, &account_keys)
+                .unwrap()
+                .parsed["type"],
+            json!("threadExec")
+        );
+
+        let instruction = CompiledInstruction::new_from_raw_parts(
+            0,
+            anchor_discriminator("thread_pause").to_vec(),
+            vec![0, 1, 2, 3],
+        );
+        assert!(matches!(
+            registry.parse(&id(), &instruction, &account_keys),
+            Err(ParseInstructionError::UnknownInstruction(_))
+        ));
+    }
+}
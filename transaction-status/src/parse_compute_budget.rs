@@ -0,0 +1,183 @@
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{ParsableProgram, ParseInstructionError, ParsedInstructionEnum},
+    },
+    borsh::BorshDeserialize,
+    serde_json::json,
+    solana_account_decoder::parse_token::real_number_string_trimmed,
+    solana_sdk::{
+        compute_budget::ComputeBudgetInstruction, instruction::CompiledInstruction,
+        message::AccountKeys,
+    },
+};
+
+// Micro-lamports per lamport (6) plus lamports per SOL (9)
+const MICRO_LAMPORTS_PER_SOL_DECIMALS: u8 = 15;
+
+pub fn parse_compute_budget(
+    instruction: &CompiledInstruction,
+    _account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    let compute_budget_instruction = ComputeBudgetInstruction::try_from_slice(&instruction.data)
+        .map_err(|_| {
+            ParseInstructionError::InstructionNotParsable(ParsableProgram::ComputeBudget)
+        })?;
+    match compute_budget_instruction {
+        ComputeBudgetInstruction::RequestUnitsDeprecated {
+            units,
+            additional_fee,
+        } => Ok(ParsedInstructionEnum {
+            instruction_type: instruction_types::compute_budget::REQUEST_UNITS.into(),
+            info: json!({
+                "units": units,
+                "additionalFee": additional_fee,
+            }),
+        }),
+        ComputeBudgetInstruction::RequestHeapFrame(bytes) => Ok(ParsedInstructionEnum {
+            instruction_type: instruction_types::compute_budget::REQUEST_HEAP_FRAME.into(),
+            info: json!({
+                "bytes": bytes,
+            }),
+        }),
+        ComputeBudgetInstruction::SetComputeUnitLimit(units) => Ok(ParsedInstructionEnum {
+            instruction_type: instruction_types::compute_budget::SET_COMPUTE_UNIT_LIMIT.into(),
+            info: json!({
+                "units": units,
+            }),
+        }),
+        ComputeBudgetInstruction::SetComputeUnitPrice(micro_lamports) => {
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::compute_budget::SET_COMPUTE_UNIT_PRICE.into(),
+                info: json!({
+                    "microLamports": micro_lamports,
+                    "computeUnitPriceSol": real_number_string_trimmed(
+                        micro_lamports,
+                        MICRO_LAMPORTS_PER_SOL_DECIMALS,
+                    ),
+                }),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, borsh::BorshSerialize, solana_sdk::message::Message};
+
+    #[test]
+    fn test_parse_compute_budget_ix() {
+        let instructions = vec![
+            ComputeBudgetInstruction::request_heap_frame(64 * 1024),
+            ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+            ComputeBudgetInstruction::set_compute_unit_price(5_000),
+        ];
+        let message = Message::new(&instructions, None);
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        assert_eq!(
+            parse_compute_budget(&message.instructions[0], &account_keys).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "requestHeapFrame".into(),
+                info: json!({
+                    "bytes": 64 * 1024,
+                }),
+            }
+        );
+        assert_eq!(
+            parse_compute_budget(&message.instructions[1], &account_keys).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "setComputeUnitLimit".into(),
+                info: json!({
+                    "units": 200_000,
+                }),
+            }
+        );
+        assert_eq!(
+            parse_compute_budget(&message.instructions[2], &account_keys).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "setComputeUnitPrice".into(),
+                info: json!({
+                    "microLamports": 5_000,
+                    "computeUnitPriceSol": "0.000000000005",
+                }),
+            }
+        );
+
+        let request_units_data = ComputeBudgetInstruction::RequestUnitsDeprecated {
+            units: 300_000,
+            additional_fee: 10,
+        }
+        .try_to_vec()
+        .unwrap();
+        let instruction = CompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![],
+            data: request_units_data,
+        };
+        assert_eq!(
+            parse_compute_budget(&instruction, &account_keys).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "requestUnits".into(),
+                info: json!({
+                    "units": 300_000,
+                    "additionalFee": 10,
+                }),
+            }
+        );
+
+        let bad_instruction = CompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![],
+            data: vec![9, 0, 0, 0, 0],
+        };
+        assert!(parse_compute_budget(&bad_instruction, &account_keys).is_err());
+    }
+
+    #[test]
+    fn test_parse_set_loaded_accounts_data_size_limit_unsupported() {
+        // `SetLoadedAccountsDataSizeLimit(u32)` is tag 4 on the wire, but this SDK predates it
+        let mut data = vec![4];
+        data.extend_from_slice(&(64 * 1024u32).to_le_bytes());
+        let instruction = CompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![],
+            data,
+        };
+        assert!(matches!(
+            parse_compute_budget(&instruction, &AccountKeys::new(&[], None)),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::ComputeBudget
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_parse_compute_unit_price_sol() {
+        let message = Message::new(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_price(0),
+                ComputeBudgetInstruction::set_compute_unit_price(1_500_000_000_000_000),
+            ],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        assert_eq!(
+            parse_compute_budget(&message.instructions[0], &account_keys)
+                .unwrap()
+                .info,
+            json!({
+                "microLamports": 0,
+                "computeUnitPriceSol": "0",
+            })
+        );
+        assert_eq!(
+            parse_compute_budget(&message.instructions[1], &account_keys)
+                .unwrap()
+                .info,
+            json!({
+                "microLamports": 1_500_000_000_000_000u64,
+                "computeUnitPriceSol": "1.5",
+            })
+        );
+    }
+}
@@ -0,0 +1,89 @@
+//! Instruction names for popular perp DEX programs, so their instructions parse through an
+//! [`AnchorParser`] without a user-supplied IDL. Only the most common instructions are named;
+//! the rest are typed by their hex discriminator.
+//!
+//! ```
+//! use solana_transaction_status::{parse_dex, parse_registry::ParserRegistry};
+//!
+//! let mut registry = ParserRegistry::default();
+//! registry.register(Box::new(parse_dex::drift_parser()));
+//! registry.register(Box::new(parse_dex::mango_parser()));
+//! ```
+
+use crate::parse_anchor::AnchorParser;
+
+pub mod drift {
+    solana_sdk::declare_id!("dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH");
+
+    pub const INSTRUCTIONS: &[&str] = &[
+        "place_perp_order",
+        "place_spot_order",
+        "cancel_order",
+        "deposit",
+        "withdraw",
+    ];
+}
+
+pub mod mango {
+    solana_sdk::declare_id!("4MangoMjqJ2firMokCjjGgoK8d4MXcrgL7XJaL3w6fvg");
+
+    pub const INSTRUCTIONS: &[&str] = &[
+        "perp_place_order",
+        "perp_cancel_order",
+        "token_deposit",
+        "token_withdraw",
+    ];
+}
+
+/// Parser for Drift v2
+pub fn drift_parser() -> AnchorParser {
+    AnchorParser::new(drift::id())
+        .with_program_name("drift")
+        .with_idl_instructions(drift::INSTRUCTIONS.iter().copied())
+}
+
+/// Parser for Mango v4
+pub fn mango_parser() -> AnchorParser {
+    AnchorParser::new(mango::id())
+        .with_program_name("mango")
+        .with_idl_instructions(mango::INSTRUCTIONS.iter().copied())
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            parse_anchor::anchor_discriminator,
+            parse_instruction::{InstructionParser, ParsedInstructionEnum},
+        },
+        serde_json::json,
+        solana_sdk::{instruction::CompiledInstruction, message::AccountKeys, pubkey::Pubkey},
+    };
+
+    #[test]
+    fn test_parse_dex_place_order() {
+        let keys = [Pubkey::new_unique()];
+        for (parser, method, instruction_type) in [
+            (drift_parser(), "place_perp_order", "placePerpOrder"),
+            (mango_parser(), "perp_place_order", "perpPlaceOrder"),
+        ] {
+            let mut data = anchor_discriminator(method).to_vec();
+            data.extend_from_slice(&[1, 2, 3]);
+            let instruction = CompiledInstruction::new_from_raw_parts(0, data, vec![0]);
+            assert_eq!(
+                parser
+                    .parse(&instruction, &AccountKeys::new(&keys, None))
+                    .unwrap(),
+                ParsedInstructionEnum {
+                    instruction_type: instruction_type.into(),
+                    info: json!({
+                        "args": "AQID",
+                    }),
+                }
+            );
+        }
+        assert_eq!(drift_parser().program_name(), "drift");
+        assert_eq!(mango_parser().program_id(), mango::id());
+    }
+}
@@ -0,0 +1,320 @@
+//! Re-encoding of parsed system and vote instructions, the inverse of
+//! [`parse`](crate::parse_instruction::parse), and the round-trip check the dispatcher runs
+//! when [`ParseConfig::verify_round_trip`] is set.
+//!
+//! [`ParseConfig::verify_round_trip`]: crate::parse_instruction::ParseConfig::verify_round_trip
+
+use {
+    crate::{
+        parse_instruction::{
+            ParsableProgram, ParseInstructionError, ParsedInstruction, PARSABLE_PROGRAM_IDS,
+        },
+        parse_system::encode_system,
+        parse_vote::encode_vote,
+    },
+    serde::{de::DeserializeOwned, Serialize},
+    serde_json::{Map, Value},
+    solana_sdk::{
+        hash::Hash, instruction::CompiledInstruction, message::AccountKeys, pubkey::Pubkey,
+        system_instruction::SystemInstruction,
+    },
+    solana_vote_program::vote_instruction::VoteInstruction,
+};
+
+/// Inverse of [`parse`](crate::parse_instruction::parse) for system and vote instructions, for
+/// tooling that edits and re-emits transactions: re-encodes the typed instruction and resolves
+/// its named accounts back to indexes into `account_keys`. Accounts the parsed output doesn't
+/// name, like the optional base signer of `createAccountWithSeed`, are not restored.
+pub fn to_compiled_instruction(
+    parsed: &ParsedInstruction,
+    account_keys: &AccountKeys,
+) -> Result<CompiledInstruction, ParseInstructionError> {
+    let program_id: Pubkey = parsed
+        .program_id
+        .parse()
+        .map_err(|_| ParseInstructionError::ProgramNotParsable)?;
+    let program = match PARSABLE_PROGRAM_IDS.get(&program_id) {
+        Some(program @ (ParsableProgram::System | ParsableProgram::Vote)) => *program,
+        _ => return Err(ParseInstructionError::ProgramNotParsable),
+    };
+    let not_parsable = || ParseInstructionError::InstructionNotParsable(program);
+    let instruction_type = parsed
+        .parsed
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(not_parsable)?;
+    let fields = ParsedFields::new(program, parsed.parsed.get("info"), account_keys)?;
+    let (data, accounts) = if program == ParsableProgram::System {
+        let (system_instruction, accounts) = encode_system(instruction_type, &fields)?;
+        (bincode::serialize(&system_instruction), accounts)
+    } else {
+        let (vote_instruction, accounts) = encode_vote(instruction_type, &fields)?;
+        (bincode::serialize(&vote_instruction), accounts)
+    };
+    Ok(CompiledInstruction {
+        program_id_index: fields.index_of(&program_id)?,
+        accounts,
+        data: data.map_err(|_| not_parsable())?,
+    })
+}
+
+/// Typed access to the `info` object of a parsed instruction, for re-encoding it. Integers may
+/// be numbers or, as emitted with `stringify_numbers`, strings.
+pub(crate) struct ParsedFields<'a> {
+    program: ParsableProgram,
+    info: &'a Map<String, Value>,
+    account_keys: &'a AccountKeys<'a>,
+}
+
+impl<'a> ParsedFields<'a> {
+    fn new(
+        program: ParsableProgram,
+        info: Option<&'a Value>,
+        account_keys: &'a AccountKeys<'a>,
+    ) -> Result<Self, ParseInstructionError> {
+        let info = info
+            .and_then(Value::as_object)
+            .ok_or(ParseInstructionError::InstructionNotParsable(program))?;
+        Ok(Self {
+            program,
+            info,
+            account_keys,
+        })
+    }
+
+    fn not_parsable(&self) -> ParseInstructionError {
+        ParseInstructionError::InstructionNotParsable(self.program)
+    }
+
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.info.contains_key(name)
+    }
+
+    pub(crate) fn value(&self, name: &str) -> Result<&'a Value, ParseInstructionError> {
+        self.info.get(name).ok_or_else(|| self.not_parsable())
+    }
+
+    /// The nested object `value`, e.g. an element of an array field
+    pub(crate) fn nested(&self, value: &'a Value) -> Result<Self, ParseInstructionError> {
+        Self::new(self.program, Some(value), self.account_keys)
+    }
+
+    pub(crate) fn array(&self, name: &str) -> Result<&'a Vec<Value>, ParseInstructionError> {
+        self.value(name)?
+            .as_array()
+            .ok_or_else(|| self.not_parsable())
+    }
+
+    pub(crate) fn string(&self, name: &str) -> Result<String, ParseInstructionError> {
+        self.value(name)?
+            .as_str()
+            .map(ToString::to_string)
+            .ok_or_else(|| self.not_parsable())
+    }
+
+    pub(crate) fn pubkey(&self, name: &str) -> Result<Pubkey, ParseInstructionError> {
+        self.string(name)?.parse().map_err(|_| self.not_parsable())
+    }
+
+    pub(crate) fn hash(&self, name: &str) -> Result<Hash, ParseInstructionError> {
+        self.string(name)?.parse().map_err(|_| self.not_parsable())
+    }
+
+    pub(crate) fn integer<T: TryFrom<i128>>(
+        &self,
+        value: &Value,
+    ) -> Result<T, ParseInstructionError> {
+        let integer = match value {
+            Value::Number(number) => number
+                .as_u64()
+                .map(i128::from)
+                .or_else(|| number.as_i64().map(i128::from)),
+            Value::String(string) => string.parse().ok(),
+            _ => None,
+        };
+        integer
+            .and_then(|integer| T::try_from(integer).ok())
+            .ok_or_else(|| self.not_parsable())
+    }
+
+    pub(crate) fn u64(&self, name: &str) -> Result<u64, ParseInstructionError> {
+        self.integer(self.value(name)?)
+    }
+
+    pub(crate) fn u8(&self, name: &str) -> Result<u8, ParseInstructionError> {
+        self.integer(self.value(name)?)
+    }
+
+    /// An integer that is null when absent, like a vote's `timestamp`
+    pub(crate) fn optional_integer<T: TryFrom<i128>>(
+        &self,
+        name: &str,
+    ) -> Result<Option<T>, ParseInstructionError> {
+        match self.value(name)? {
+            Value::Null => Ok(None),
+            value => self.integer(value).map(Some),
+        }
+    }
+
+    /// Index into the account keys of the account named by the pubkey field `name`
+    pub(crate) fn account(&self, name: &str) -> Result<u8, ParseInstructionError> {
+        self.index_of(&self.pubkey(name)?)
+    }
+
+    fn index_of(&self, pubkey: &Pubkey) -> Result<u8, ParseInstructionError> {
+        self.account_keys
+            .iter()
+            .position(|key| key == pubkey)
+            .and_then(|index| u8::try_from(index).ok())
+            .ok_or(ParseInstructionError::InstructionKeyMismatch(self.program))
+    }
+}
+
+// Undecodable data is left for the program's parser to report
+pub(crate) fn verify_round_trip(
+    program: &ParsableProgram,
+    data: &[u8],
+) -> Result<(), ParseInstructionError> {
+    fn reserialize<T: DeserializeOwned + Serialize>(data: &[u8]) -> Option<Vec<u8>> {
+        bincode::serialize(&bincode::deserialize::<T>(data).ok()?).ok()
+    }
+    let reserialized = match program {
+        ParsableProgram::System => reserialize::<SystemInstruction>(data),
+        ParsableProgram::Vote => reserialize::<VoteInstruction>(data),
+        _ => None,
+    };
+    match reserialized {
+        Some(reserialized) if reserialized != data => {
+            Err(ParseInstructionError::RoundTripMismatch(*program))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            extract_memos::spl_memo_id_v3,
+            parse_instruction::{parse, parse_with_config, ParseConfig},
+        },
+        serde_json::json,
+        solana_sdk::{
+            instruction::{AccountMeta, Instruction},
+            message::Message,
+            system_instruction, system_program, sysvar,
+        },
+        solana_vote_program::{
+            vote_instruction,
+            vote_state::{Lockout, Vote, VoteAuthorize, VoteInit, VoteStateUpdate},
+        },
+    };
+
+    #[test]
+    fn test_to_compiled_instruction() {
+        let keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let vote_init = VoteInit {
+            node_pubkey: keys[2],
+            authorized_voter: keys[1],
+            authorized_withdrawer: keys[3],
+            commission: 5,
+        };
+        let vote_state_update = VoteStateUpdate {
+            lockouts: [(40, 2), (41, 1)]
+                .into_iter()
+                .map(|(slot, confirmation_count)| Lockout {
+                    slot,
+                    confirmation_count,
+                })
+                .collect(),
+            root: Some(39),
+            hash: Hash::new_unique(),
+            timestamp: None,
+        };
+        let mut instructions = vec![
+            system_instruction::transfer(&keys[0], &keys[1], 42),
+            system_instruction::allocate_with_seed(&keys[1], &keys[0], "seed", 128, &keys[3]),
+            system_instruction::transfer_with_seed(
+                &keys[1],
+                &keys[0],
+                "seed".to_string(),
+                &keys[3],
+                &keys[2],
+                7,
+            ),
+            system_instruction::advance_nonce_account(&keys[1], &keys[0]),
+            vote_instruction::authorize(&keys[1], &keys[0], &keys[2], VoteAuthorize::Withdrawer),
+            vote_instruction::withdraw(&keys[1], &keys[0], 9, &keys[2]),
+            vote_instruction::vote(
+                &keys[1],
+                &keys[0],
+                Vote {
+                    slots: vec![1, 2, 4],
+                    hash: Hash::new_unique(),
+                    timestamp: Some(1_234_567_890),
+                },
+            ),
+            Instruction::new_with_bincode(
+                solana_vote_program::id(),
+                &VoteInstruction::UpdateVoteState(vote_state_update),
+                vec![
+                    AccountMeta::new(keys[1], false),
+                    AccountMeta::new_readonly(sysvar::slot_hashes::id(), false),
+                    AccountMeta::new_readonly(sysvar::clock::id(), false),
+                    AccountMeta::new_readonly(keys[0], true),
+                ],
+            ),
+        ];
+        instructions.extend(vote_instruction::create_account(
+            &keys[0], &keys[1], &vote_init, 100,
+        ));
+        let message = Message::new(&instructions, Some(&keys[0]));
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let stringified = ParseConfig::default().with_stringified_numbers(true);
+        for instruction in &message.instructions {
+            let program_id = instruction.program_id(&message.account_keys);
+            let parsed = parse(program_id, instruction, &account_keys).unwrap();
+            assert_eq!(
+                &to_compiled_instruction(&parsed, &account_keys).unwrap(),
+                instruction,
+                "{}",
+                parsed.summary()
+            );
+            let parsed =
+                parse_with_config(program_id, instruction, &account_keys, &stringified).unwrap();
+            assert_eq!(
+                &to_compiled_instruction(&parsed, &account_keys).unwrap(),
+                instruction
+            );
+        }
+
+        // Accounts missing from the account keys
+        let parsed = parse(
+            &system_program::id(),
+            &message.instructions[0],
+            &account_keys,
+        )
+        .unwrap();
+        assert!(matches!(
+            to_compiled_instruction(&parsed, &AccountKeys::new(&message.account_keys[..1], None)),
+            Err(ParseInstructionError::InstructionKeyMismatch(
+                ParsableProgram::System
+            ))
+        ));
+
+        // Only system and vote instructions are re-encoded
+        let memo = ParsedInstruction {
+            program: "spl-memo".to_string(),
+            program_id: spl_memo_id_v3().to_string(),
+            parsed: json!("memo"),
+            data_len: 4,
+            instruction_index: None,
+            account_keys: None,
+        };
+        assert!(matches!(
+            to_compiled_instruction(&memo, &account_keys),
+            Err(ParseInstructionError::ProgramNotParsable)
+        ));
+    }
+}
@@ -0,0 +1,326 @@
+//! Serialization of parsed instructions for storage and comparison: streamed and
+//! newline-delimited JSON, MessagePack, canonical key order, and field-level diffs.
+
+use {
+    crate::parse_instruction::{ParseInstructionError, ParsedInstructionEnum},
+    serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer},
+    serde_json::{json, Value},
+    std::{collections::BTreeSet, io},
+};
+
+/// Streams the `{type, info}` JSON of a parsed instruction to a writer without building an
+/// intermediate `Value`
+pub fn write_parsed<W: io::Write>(
+    parsed: &ParsedInstructionEnum,
+    writer: W,
+) -> Result<(), ParseInstructionError> {
+    Ok(serde_json::to_writer(writer, parsed)?)
+}
+
+/// Serializes a parsed instruction as MessagePack. Fields are encoded by name, so the output
+/// mirrors the JSON shape.
+#[cfg(feature = "msgpack")]
+pub fn to_msgpack(parsed: &ParsedInstructionEnum) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+    rmp_serde::to_vec_named(parsed)
+}
+
+/// Writes parsed instructions as newline-delimited JSON: one compact object per line, each
+/// terminated by `\n`. The writer is flushed once all items are written.
+pub fn export_ndjson<W: io::Write>(
+    items: impl Iterator<Item = ParsedInstructionEnum>,
+    mut writer: W,
+) -> Result<(), ParseInstructionError> {
+    for item in items {
+        write_parsed(&item, &mut writer)?;
+        writer.write_all(b"\n").map_err(serde_json::Error::io)?;
+    }
+    writer.flush().map_err(serde_json::Error::io)?;
+    Ok(())
+}
+
+/// Lists what differs between two parsed instructions, as `(key, a_value, b_value)`. A `type`
+/// mismatch comes first, followed by the differing `info` fields sorted by key. A field missing
+/// from one side is `None` on that side, so it can't be confused with an explicit `null`.
+pub fn diff(
+    a: &ParsedInstructionEnum,
+    b: &ParsedInstructionEnum,
+) -> Vec<(String, Option<Value>, Option<Value>)> {
+    let mut differences = vec![];
+    if a.instruction_type != b.instruction_type {
+        differences.push((
+            "type".to_string(),
+            Some(json!(a.instruction_type)),
+            Some(json!(b.instruction_type)),
+        ));
+    }
+    let empty = serde_json::Map::new();
+    let a_info = a.info.as_object().unwrap_or(&empty);
+    let b_info = b.info.as_object().unwrap_or(&empty);
+    let keys: BTreeSet<&String> = a_info.keys().chain(b_info.keys()).collect();
+    differences.extend(keys.into_iter().filter_map(|key| {
+        let a_value = a_info.get(key);
+        let b_value = b_info.get(key);
+        (a_value != b_value).then(|| (key.clone(), a_value.cloned(), b_value.cloned()))
+    }));
+    differences
+}
+
+/// Serializes a parsed value to JSON with every object's keys in sorted order, so that
+/// structurally-identical parses are byte-identical regardless of the backing map type
+pub fn to_canonical_json(value: &Value) -> Result<Vec<u8>, ParseInstructionError> {
+    Ok(serde_json::to_vec(&CanonicalValue(value))?)
+}
+
+struct CanonicalValue<'a>(&'a Value);
+
+impl<'a> Serialize for CanonicalValue<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            Value::Object(map) => {
+                let mut entries: Vec<_> = map.iter().collect();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                let mut ser_map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    ser_map.serialize_entry(key, &CanonicalValue(value))?;
+                }
+                ser_map.end()
+            }
+            Value::Array(values) => {
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    seq.serialize_element(&CanonicalValue(value))?;
+                }
+                seq.end()
+            }
+            value => value.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{parse_instruction::parse, parse_system::parse_system},
+        solana_sdk::{
+            hash::hash,
+            message::{AccountKeys, Message},
+            pubkey::Pubkey,
+            system_instruction, system_program,
+        },
+    };
+
+    #[test]
+    fn test_to_canonical_json() {
+        let from_pubkey = Pubkey::new_unique();
+        let to_pubkey = Pubkey::new_unique();
+        let instruction = system_instruction::transfer(&from_pubkey, &to_pubkey, 42);
+        let message = Message::new(&[instruction], None);
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+
+        let first = parse(
+            &system_program::id(),
+            &message.instructions[0],
+            &account_keys,
+        )
+        .unwrap();
+        let second = parse(
+            &system_program::id(),
+            &message.instructions[0],
+            &account_keys,
+        )
+        .unwrap();
+        let first_bytes = to_canonical_json(&serde_json::to_value(first).unwrap()).unwrap();
+        let second_bytes = to_canonical_json(&serde_json::to_value(second).unwrap()).unwrap();
+        assert_eq!(hash(&first_bytes), hash(&second_bytes));
+
+        // Insertion order must not leak into the output
+        let mut forward = serde_json::Map::new();
+        forward.insert("source".to_string(), json!(from_pubkey.to_string()));
+        forward.insert("lamports".to_string(), json!(42));
+        let mut reverse = serde_json::Map::new();
+        reverse.insert("lamports".to_string(), json!(42));
+        reverse.insert("source".to_string(), json!(from_pubkey.to_string()));
+        assert_eq!(
+            to_canonical_json(&json!([forward])).unwrap(),
+            to_canonical_json(&json!([reverse])).unwrap(),
+        );
+        assert_eq!(
+            to_canonical_json(&json!({"b": 1, "a": [{"d": 2, "c": 3}]})).unwrap(),
+            br#"{"a":[{"c":3,"d":2}],"b":1}"#.to_vec(),
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let from_pubkey = Pubkey::new_unique();
+        let to_pubkey = Pubkey::new_unique();
+        let message = Message::new(
+            &[
+                system_instruction::transfer(&from_pubkey, &to_pubkey, 42),
+                system_instruction::transfer(&from_pubkey, &to_pubkey, 43),
+            ],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let simulated = parse_system(&message.instructions[0], &account_keys).unwrap();
+        let executed = parse_system(&message.instructions[1], &account_keys).unwrap();
+        assert_eq!(
+            diff(&simulated, &executed),
+            vec![
+                ("lamports".to_string(), Some(json!(42)), Some(json!(43))),
+                (
+                    "sol".to_string(),
+                    Some(json!("0.000000042")),
+                    Some(json!("0.000000043"))
+                ),
+            ]
+        );
+        assert!(diff(&simulated, &simulated).is_empty());
+
+        let a = ParsedInstructionEnum {
+            instruction_type: "custom".into(),
+            info: json!({"amount": 5, "only_a": true, "same": "x"}),
+        };
+        let b = ParsedInstructionEnum {
+            instruction_type: "custom".into(),
+            info: json!({"amount": "5", "only_b": 1, "same": "x"}),
+        };
+        assert_eq!(
+            diff(&a, &b),
+            vec![
+                ("amount".to_string(), Some(json!(5)), Some(json!("5"))),
+                ("only_a".to_string(), Some(json!(true)), None),
+                ("only_b".to_string(), None, Some(json!(1))),
+            ]
+        );
+
+        // A missing key differs from an explicit null
+        let a = ParsedInstructionEnum {
+            instruction_type: "custom".into(),
+            info: json!({"authority": null}),
+        };
+        let b = ParsedInstructionEnum {
+            instruction_type: "custom".into(),
+            info: json!({}),
+        };
+        assert_eq!(
+            diff(&a, &b),
+            vec![("authority".to_string(), Some(Value::Null), None)]
+        );
+
+        let seed_message = Message::new(
+            &[system_instruction::transfer_with_seed(
+                &from_pubkey,
+                &Pubkey::new_unique(),
+                "seed".to_string(),
+                &system_program::id(),
+                &to_pubkey,
+                42,
+            )],
+            None,
+        );
+        let with_seed = parse_system(
+            &seed_message.instructions[0],
+            &AccountKeys::new(&seed_message.account_keys, None),
+        )
+        .unwrap();
+        let differences = diff(&simulated, &with_seed);
+        assert_eq!(
+            differences[0],
+            (
+                "type".to_string(),
+                Some(json!("transfer")),
+                Some(json!("transferWithSeed"))
+            )
+        );
+        assert!(differences[1..].iter().all(|(key, _, _)| key != "type"));
+    }
+
+    #[test]
+    fn test_write_parsed() {
+        let from_pubkey = Pubkey::new_unique();
+        let to_pubkey = Pubkey::new_unique();
+        let instruction = system_instruction::transfer(&from_pubkey, &to_pubkey, 42);
+        let message = Message::new(&[instruction], None);
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let parsed = parse_system(&message.instructions[0], &account_keys).unwrap();
+
+        let mut buffer = vec![];
+        write_parsed(&parsed, &mut buffer).unwrap();
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            serde_json::to_string(&parsed).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_export_ndjson() {
+        let from_pubkey = Pubkey::new_unique();
+        let to_pubkey = Pubkey::new_unique();
+        let message = Message::new(
+            &[
+                system_instruction::transfer(&from_pubkey, &to_pubkey, 1),
+                system_instruction::transfer(&from_pubkey, &to_pubkey, 2),
+                system_instruction::assign(&from_pubkey, &to_pubkey),
+            ],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let parsed: Vec<_> = message
+            .instructions
+            .iter()
+            .map(|instruction| parse_system(instruction, &account_keys).unwrap())
+            .collect();
+
+        let mut buffer = vec![];
+        export_ndjson(
+            message
+                .instructions
+                .iter()
+                .map(|instruction| parse_system(instruction, &account_keys).unwrap()),
+            &mut buffer,
+        )
+        .unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.ends_with("}\n"));
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        for (line, expected) in lines.into_iter().zip(parsed.iter()) {
+            let item: ParsedInstructionEnum = serde_json::from_str(line).unwrap();
+            assert_eq!(&item, expected);
+        }
+
+        let mut buffer = vec![];
+        export_ndjson(std::iter::empty(), &mut buffer).unwrap();
+        assert!(buffer.is_empty());
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_to_msgpack() {
+        let parsed = ParsedInstructionEnum {
+            instruction_type: "transfer".into(),
+            info: json!({
+                "source": Pubkey::new_unique().to_string(),
+                "lamports": 42,
+                "nested": {"flag": true, "list": [1, 2]},
+            }),
+        };
+        let bytes = to_msgpack(&parsed).unwrap();
+        let decoded: ParsedInstructionEnum = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(decoded, parsed);
+
+        // A parse without info omits it, as in JSON
+        let parsed = ParsedInstructionEnum {
+            instruction_type: "getMinimumDelegation".into(),
+            info: Value::Null,
+        };
+        let decoded: ParsedInstructionEnum =
+            rmp_serde::from_slice(&to_msgpack(&parsed).unwrap()).unwrap();
+        assert_eq!(decoded, parsed);
+    }
+}
@@ -0,0 +1,135 @@
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+        },
+    },
+    borsh::BorshDeserialize,
+    serde_json::json,
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys},
+};
+
+solana_sdk::declare_id!("Feat1YXHhH6t1juaWF74WLcfv4XoNocjXA6sPWHNgAL");
+
+const PROPOSE: u8 = 0;
+const TALLY: u8 = 1;
+
+pub fn parse_feature_proposal(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    match instruction.accounts.iter().max() {
+        Some(index) if (*index as usize) < account_keys.len() => {}
+        _ => {
+            // Runtime should prevent this from ever happening
+            return Err(ParseInstructionError::InstructionKeyMismatch(
+                ParsableProgram::SplFeatureProposal,
+            ));
+        }
+    }
+    let not_parsable =
+        || ParseInstructionError::InstructionNotParsable(ParsableProgram::SplFeatureProposal);
+    let (tag, data) = instruction.data.split_first().ok_or_else(not_parsable)?;
+    let key = |i: usize| account_keys[instruction.accounts[i] as usize].to_string();
+    match *tag {
+        PROPOSE => {
+            // `tokens_to_mint`, then `AcceptanceCriteria { tokens_required, deadline }`
+            let (tokens_to_mint, tokens_required, deadline) =
+                <(u64, u64, i64)>::try_from_slice(data).map_err(|_| not_parsable())?;
+            check_num_feature_proposal_accounts(&instruction.accounts, 9)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::feature_proposal::PROPOSE.into(),
+                info: json!({
+                    "fundingAccount": key(0),
+                    "featureProposal": key(1),
+                    "mint": key(2),
+                    "distributorTokenAccount": key(3),
+                    "acceptanceTokenAccount": key(4),
+                    "feature": key(5),
+                    "systemProgram": key(6),
+                    "tokenProgram": key(7),
+                    "rentSysvar": key(8),
+                    "tokensToMint": tokens_to_mint,
+                    "acceptanceCriteria": {
+                        "tokensRequired": tokens_required,
+                        "deadline": deadline,
+                    },
+                }),
+            })
+        }
+        TALLY => {
+            check_num_feature_proposal_accounts(&instruction.accounts, 5)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::feature_proposal::TALLY.into(),
+                info: json!({
+                    "featureProposal": key(0),
+                    "acceptanceTokenAccount": key(1),
+                    "feature": key(2),
+                    "systemProgram": key(3),
+                    "clockSysvar": key(4),
+                }),
+            })
+        }
+        _ => Err(not_parsable()),
+    }
+}
+
+fn check_num_feature_proposal_accounts(
+    accounts: &[u8],
+    num: usize,
+) -> Result<(), ParseInstructionError> {
+    check_num_accounts(accounts, num, ParsableProgram::SplFeatureProposal)
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_sdk::pubkey::Pubkey};
+
+    #[test]
+    fn test_parse_feature_proposal_propose() {
+        let keys: Vec<Pubkey> = (0..9).map(|_| Pubkey::new_unique()).collect();
+        let mut data = vec![PROPOSE];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&950u64.to_le_bytes());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        let mut instruction = CompiledInstruction::new_from_raw_parts(0, data, (0..9).collect());
+        assert_eq!(
+            parse_feature_proposal(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "propose".into(),
+                info: json!({
+                    "fundingAccount": keys[0].to_string(),
+                    "featureProposal": keys[1].to_string(),
+                    "mint": keys[2].to_string(),
+                    "distributorTokenAccount": keys[3].to_string(),
+                    "acceptanceTokenAccount": keys[4].to_string(),
+                    "feature": keys[5].to_string(),
+                    "systemProgram": keys[6].to_string(),
+                    "tokenProgram": keys[7].to_string(),
+                    "rentSysvar": keys[8].to_string(),
+                    "tokensToMint": 1_000,
+                    "acceptanceCriteria": {
+                        "tokensRequired": 950,
+                        "deadline": 1_700_000_000,
+                    },
+                }),
+            }
+        );
+        assert!(
+            parse_feature_proposal(&instruction, &AccountKeys::new(&keys[0..8], None)).is_err()
+        );
+        instruction.accounts.pop();
+        assert!(parse_feature_proposal(&instruction, &AccountKeys::new(&keys, None)).is_err());
+
+        // Truncated acceptance criteria
+        instruction.data.truncate(17);
+        instruction.accounts.push(8);
+        assert!(matches!(
+            parse_feature_proposal(&instruction, &AccountKeys::new(&keys, None)),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::SplFeatureProposal
+            ))
+        ));
+    }
+}
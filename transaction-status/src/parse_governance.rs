@@ -0,0 +1,227 @@
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+        },
+    },
+    borsh::BorshDeserialize,
+    serde_json::{json, Value},
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys},
+};
+
+// The main deployment; realms may also run their own copy of the program under another id
+solana_sdk::declare_id!("GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZw");
+
+const DEPOSIT_GOVERNING_TOKENS: u8 = 1;
+const WITHDRAW_GOVERNING_TOKENS: u8 = 2;
+const CREATE_PROPOSAL: u8 = 6;
+const CAST_VOTE: u8 = 13;
+const EXECUTE_TRANSACTION: u8 = 16;
+
+pub fn parse_governance(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    match instruction.accounts.iter().max() {
+        Some(index) if (*index as usize) < account_keys.len() => {}
+        _ => {
+            // Runtime should prevent this from ever happening
+            return Err(ParseInstructionError::InstructionKeyMismatch(
+                ParsableProgram::SplGovernance,
+            ));
+        }
+    }
+    let not_parsable =
+        || ParseInstructionError::InstructionNotParsable(ParsableProgram::SplGovernance);
+    let (tag, mut data) = instruction.data.split_first().ok_or_else(not_parsable)?;
+    let key = |i: usize| account_keys[instruction.accounts[i] as usize].to_string();
+    match *tag {
+        DEPOSIT_GOVERNING_TOKENS => {
+            let amount = u64::deserialize(&mut data).map_err(|_| not_parsable())?;
+            check_num_governance_accounts(&instruction.accounts, 9)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::governance::DEPOSIT_GOVERNING_TOKENS.into(),
+                info: json!({
+                    "realm": key(0),
+                    "governingTokenHoldingAccount": key(1),
+                    "governingTokenSource": key(2),
+                    "governingTokenOwner": key(3),
+                    "governingTokenSourceAuthority": key(4),
+                    "tokenOwnerRecord": key(5),
+                    "payer": key(6),
+                    "systemProgram": key(7),
+                    "tokenProgram": key(8),
+                    "amount": amount,
+                }),
+            })
+        }
+        WITHDRAW_GOVERNING_TOKENS => {
+            check_num_governance_accounts(&instruction.accounts, 6)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::governance::WITHDRAW_GOVERNING_TOKENS.into(),
+                info: json!({
+                    "realm": key(0),
+                    "governingTokenHoldingAccount": key(1),
+                    "governingTokenDestination": key(2),
+                    "governingTokenOwner": key(3),
+                    "tokenOwnerRecord": key(4),
+                    "tokenProgram": key(5),
+                }),
+            })
+        }
+        CREATE_PROPOSAL => {
+            // Only the leading name and description link are decoded
+            let name = String::deserialize(&mut data).map_err(|_| not_parsable())?;
+            let description_link = String::deserialize(&mut data).map_err(|_| not_parsable())?;
+            check_num_governance_accounts(&instruction.accounts, 8)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::governance::CREATE_PROPOSAL.into(),
+                info: json!({
+                    "realm": key(0),
+                    "proposal": key(1),
+                    "governance": key(2),
+                    "tokenOwnerRecord": key(3),
+                    "governingTokenMint": key(4),
+                    "governanceAuthority": key(5),
+                    "payer": key(6),
+                    "systemProgram": key(7),
+                    "name": name,
+                    "descriptionLink": description_link,
+                }),
+            })
+        }
+        CAST_VOTE => {
+            let vote = parse_vote(&mut data).ok_or_else(not_parsable)?;
+            check_num_governance_accounts(&instruction.accounts, 10)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::governance::CAST_VOTE.into(),
+                info: json!({
+                    "realm": key(0),
+                    "governance": key(1),
+                    "proposal": key(2),
+                    "proposalOwnerRecord": key(3),
+                    "voterTokenOwnerRecord": key(4),
+                    "governanceAuthority": key(5),
+                    "voteRecord": key(6),
+                    "governingTokenMint": key(7),
+                    "payer": key(8),
+                    "systemProgram": key(9),
+                    "vote": vote,
+                }),
+            })
+        }
+        EXECUTE_TRANSACTION => {
+            check_num_governance_accounts(&instruction.accounts, 3)?;
+            let instruction_accounts: Vec<String> =
+                (3..instruction.accounts.len()).map(key).collect();
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::governance::EXECUTE_TRANSACTION.into(),
+                info: json!({
+                    "governance": key(0),
+                    "proposal": key(1),
+                    "proposalTransaction": key(2),
+                    "instructionAccounts": instruction_accounts,
+                }),
+            })
+        }
+        _ => Err(not_parsable()),
+    }
+}
+
+// Decodes a borsh `Vote`: Approve(Vec<VoteChoice>), Deny, Abstain or Veto
+fn parse_vote(data: &mut &[u8]) -> Option<Value> {
+    match u8::deserialize(data).ok()? {
+        0 => {
+            let choices = Vec::<(u8, u8)>::deserialize(data).ok()?;
+            let choices: Vec<Value> = choices
+                .into_iter()
+                .map(|(rank, weight_percentage)| {
+                    json!({
+                        "rank": rank,
+                        "weightPercentage": weight_percentage,
+                    })
+                })
+                .collect();
+            Some(json!({ "approve": choices }))
+        }
+        1 => Some(json!("deny")),
+        2 => Some(json!("abstain")),
+        3 => Some(json!("veto")),
+        _ => None,
+    }
+}
+
+fn check_num_governance_accounts(accounts: &[u8], num: usize) -> Result<(), ParseInstructionError> {
+    check_num_accounts(accounts, num, ParsableProgram::SplGovernance)
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_sdk::pubkey::Pubkey};
+
+    #[test]
+    fn test_parse_governance_cast_vote() {
+        let keys: Vec<Pubkey> = (0..10).map(|_| Pubkey::new_unique()).collect();
+        // Approve with a single full-weight choice
+        let mut instruction = CompiledInstruction::new_from_raw_parts(
+            0,
+            vec![CAST_VOTE, 0, 1, 0, 0, 0, 0, 100],
+            (0..10).collect(),
+        );
+        assert_eq!(
+            parse_governance(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "castVote".into(),
+                info: json!({
+                    "realm": keys[0].to_string(),
+                    "governance": keys[1].to_string(),
+                    "proposal": keys[2].to_string(),
+                    "proposalOwnerRecord": keys[3].to_string(),
+                    "voterTokenOwnerRecord": keys[4].to_string(),
+                    "governanceAuthority": keys[5].to_string(),
+                    "voteRecord": keys[6].to_string(),
+                    "governingTokenMint": keys[7].to_string(),
+                    "payer": keys[8].to_string(),
+                    "systemProgram": keys[9].to_string(),
+                    "vote": {
+                        "approve": [{"rank": 0, "weightPercentage": 100}],
+                    },
+                }),
+            }
+        );
+
+        instruction.data = vec![CAST_VOTE, 1];
+        assert_eq!(
+            parse_governance(&instruction, &AccountKeys::new(&keys, None))
+                .unwrap()
+                .info["vote"],
+            json!("deny")
+        );
+
+        // Unknown vote kind
+        instruction.data = vec![CAST_VOTE, 4];
+        assert!(parse_governance(&instruction, &AccountKeys::new(&keys, None)).is_err());
+        // Truncated choice list
+        instruction.data = vec![CAST_VOTE, 0, 1, 0, 0, 0, 0];
+        assert!(parse_governance(&instruction, &AccountKeys::new(&keys, None)).is_err());
+
+        instruction.data = vec![CAST_VOTE, 2];
+        assert!(parse_governance(&instruction, &AccountKeys::new(&keys[0..9], None)).is_err());
+        instruction.accounts.pop();
+        assert!(parse_governance(&instruction, &AccountKeys::new(&keys, None)).is_err());
+    }
+
+    #[test]
+    fn test_parse_governance_not_parsable() {
+        let keys = [Pubkey::new_unique()];
+        // CreateRealm is not parsed
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![0], vec![0]);
+        assert!(matches!(
+            parse_governance(&instruction, &AccountKeys::new(&keys, None)),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::SplGovernance
+            ))
+        ));
+    }
+}
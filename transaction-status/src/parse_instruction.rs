@@ -1,57 +1,148 @@
+#[cfg(feature = "legacy-programs")]
+use crate::parse_legacy;
 use {
     crate::{
         extract_memos::{spl_memo_id_v1, spl_memo_id_v3},
+        instruction_types,
+        parse_address_lookup_table::parse_address_lookup_table,
         parse_associated_token::{parse_associated_token, spl_associated_token_id},
-        parse_bpf_loader::{parse_bpf_loader, parse_bpf_upgradeable_loader},
-        parse_stake::parse_stake,
-        parse_system::parse_system,
-        parse_token::parse_token,
+        parse_bpf_loader::{parse_bpf_loader, parse_bpf_upgradeable_loader_with_config},
+        parse_compute_budget::parse_compute_budget,
+        parse_encode::verify_round_trip,
+        parse_export::to_canonical_json,
+        parse_feature_proposal::{self, parse_feature_proposal},
+        parse_governance::{self, parse_governance},
+        parse_jito::{self, parse_jito_tip_distribution, parse_jito_tip_payment},
+        parse_name_service::{self, parse_name_service},
+        parse_noop::{self, parse_noop},
+        parse_secp256r1::{self, parse_secp256r1},
+        parse_stake::parse_stake_with_config,
+        parse_stake_pool::{self, parse_stake_pool},
+        parse_system::parse_system_with_config,
+        parse_token::{is_checked_token_instruction, parse_token_with_config},
+        parse_token_lending::{self, parse_token_lending},
+        parse_token_metadata::{self, parse_token_metadata},
+        parse_token_swap::{self, parse_token_swap},
+        parse_token_upgrade::{self, parse_token_upgrade},
         parse_vote::parse_vote,
     },
     inflector::Inflector,
-    serde_json::Value,
-    solana_account_decoder::parse_token::spl_token_ids,
+    serde::Serialize,
+    serde_json::{json, Map, Value},
+    solana_account_decoder::parse_token::{real_number_string_trimmed, spl_token_ids},
     solana_sdk::{
-        instruction::CompiledInstruction, message::AccountKeys, pubkey::Pubkey, stake,
-        system_program,
+        clock::Epoch,
+        compute_budget,
+        hash::hash,
+        incinerator,
+        instruction::CompiledInstruction,
+        message::{AccountKeys, MessageHeader},
+        pubkey::Pubkey,
+        rent::Rent,
+        stake, system_program, sysvar,
     },
     std::{
-        collections::HashMap,
+        borrow::Cow,
+        collections::{BTreeSet, HashMap, HashSet},
         str::{from_utf8, Utf8Error},
     },
     thiserror::Error,
 };
 
+// Lamports per SOL, as a power of ten
+const SOL_DECIMALS: u8 = 9;
+
 lazy_static! {
+    static ref ADDRESS_LOOKUP_PROGRAM_ID: Pubkey = solana_address_lookup_table_program::id();
     static ref ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_id();
     static ref BPF_LOADER_PROGRAM_ID: Pubkey = solana_sdk::bpf_loader::id();
     static ref BPF_UPGRADEABLE_LOADER_PROGRAM_ID: Pubkey = solana_sdk::bpf_loader_upgradeable::id();
+    static ref COMPUTE_BUDGET_PROGRAM_ID: Pubkey = compute_budget::id();
+    static ref FEATURE_PROPOSAL_PROGRAM_ID: Pubkey = parse_feature_proposal::id();
+    static ref GOVERNANCE_PROGRAM_ID: Pubkey = parse_governance::id();
+    static ref JITO_TIP_DISTRIBUTION_PROGRAM_ID: Pubkey = parse_jito::tip_distribution::id();
+    static ref JITO_TIP_PAYMENT_PROGRAM_ID: Pubkey = parse_jito::tip_payment::id();
     static ref MEMO_V1_PROGRAM_ID: Pubkey = spl_memo_id_v1();
     static ref MEMO_V3_PROGRAM_ID: Pubkey = spl_memo_id_v3();
+    static ref NAME_SERVICE_PROGRAM_ID: Pubkey = parse_name_service::id();
+    static ref NOOP_PROGRAM_ID: Pubkey = parse_noop::id();
+    static ref SECP256R1_PROGRAM_ID: Pubkey = parse_secp256r1::id();
     static ref STAKE_PROGRAM_ID: Pubkey = stake::program::id();
+    static ref STAKE_POOL_PROGRAM_ID: Pubkey = parse_stake_pool::id();
     static ref SYSTEM_PROGRAM_ID: Pubkey = system_program::id();
+    static ref TOKEN_LENDING_PROGRAM_ID: Pubkey = parse_token_lending::id();
+    static ref TOKEN_METADATA_PROGRAM_ID: Pubkey = parse_token_metadata::id();
+    static ref TOKEN_SWAP_PROGRAM_ID: Pubkey = parse_token_swap::id();
+    static ref TOKEN_UPGRADE_PROGRAM_ID: Pubkey = parse_token_upgrade::id();
     static ref VOTE_PROGRAM_ID: Pubkey = solana_vote_program::id();
-    static ref PARSABLE_PROGRAM_IDS: HashMap<Pubkey, ParsableProgram> = {
+    pub(crate) static ref PARSABLE_PROGRAM_IDS: HashMap<Pubkey, ParsableProgram> = {
         let mut m = HashMap::new();
+        m.insert(
+            *ADDRESS_LOOKUP_PROGRAM_ID,
+            ParsableProgram::AddressLookupTable,
+        );
         m.insert(
             *ASSOCIATED_TOKEN_PROGRAM_ID,
             ParsableProgram::SplAssociatedTokenAccount,
         );
+        m.insert(
+            *FEATURE_PROPOSAL_PROGRAM_ID,
+            ParsableProgram::SplFeatureProposal,
+        );
+        m.insert(*GOVERNANCE_PROGRAM_ID, ParsableProgram::SplGovernance);
         m.insert(*MEMO_V1_PROGRAM_ID, ParsableProgram::SplMemo);
         m.insert(*MEMO_V3_PROGRAM_ID, ParsableProgram::SplMemo);
+        m.insert(*NAME_SERVICE_PROGRAM_ID, ParsableProgram::SplNameService);
+        m.insert(*NOOP_PROGRAM_ID, ParsableProgram::Noop);
         for spl_token_id in spl_token_ids() {
             m.insert(spl_token_id, ParsableProgram::SplToken);
         }
+        m.insert(*TOKEN_LENDING_PROGRAM_ID, ParsableProgram::SplTokenLending);
+        m.insert(*TOKEN_SWAP_PROGRAM_ID, ParsableProgram::SplTokenSwap);
+        m.insert(*TOKEN_UPGRADE_PROGRAM_ID, ParsableProgram::SplTokenUpgrade);
         m.insert(*BPF_LOADER_PROGRAM_ID, ParsableProgram::BpfLoader);
         m.insert(
             *BPF_UPGRADEABLE_LOADER_PROGRAM_ID,
             ParsableProgram::BpfUpgradeableLoader,
         );
+        m.insert(*COMPUTE_BUDGET_PROGRAM_ID, ParsableProgram::ComputeBudget);
+        m.insert(
+            *JITO_TIP_DISTRIBUTION_PROGRAM_ID,
+            ParsableProgram::JitoTipDistribution,
+        );
+        m.insert(
+            *JITO_TIP_PAYMENT_PROGRAM_ID,
+            ParsableProgram::JitoTipPayment,
+        );
+        #[cfg(feature = "legacy-programs")]
+        {
+            m.insert(parse_legacy::budget::id(), ParsableProgram::Budget);
+            m.insert(parse_legacy::exchange::id(), ParsableProgram::Exchange);
+        }
+        m.insert(*SECP256R1_PROGRAM_ID, ParsableProgram::Secp256r1);
         m.insert(*STAKE_PROGRAM_ID, ParsableProgram::Stake);
+        m.insert(*STAKE_POOL_PROGRAM_ID, ParsableProgram::SplStakePool);
         m.insert(*SYSTEM_PROGRAM_ID, ParsableProgram::System);
+        m.insert(*TOKEN_METADATA_PROGRAM_ID, ParsableProgram::TokenMetadata);
         m.insert(*VOTE_PROGRAM_ID, ParsableProgram::Vote);
         m
     };
+    static ref SYSVAR_NAMES: HashMap<Pubkey, &'static str> = {
+        let mut m = HashMap::new();
+        m.insert(sysvar::clock::id(), "clock");
+        m.insert(sysvar::epoch_schedule::id(), "epochSchedule");
+        #[allow(deprecated)]
+        m.insert(sysvar::fees::id(), "fees");
+        m.insert(sysvar::instructions::id(), "instructions");
+        #[allow(deprecated)]
+        m.insert(sysvar::recent_blockhashes::id(), "recentBlockhashes");
+        m.insert(sysvar::rent::id(), "rent");
+        m.insert(sysvar::rewards::id(), "rewards");
+        m.insert(sysvar::slot_hashes::id(), "slotHashes");
+        m.insert(sysvar::slot_history::id(), "slotHistory");
+        m.insert(sysvar::stake_history::id(), "stakeHistory");
+        m
+    };
 }
 
 #[derive(Error, Debug)]
@@ -65,6 +156,30 @@ pub enum ParseInstructionError {
     #[error("Program not parsable")]
     ProgramNotParsable,
 
+    #[error("Instruction {0} not found in message")]
+    InstructionIndexOutOfBounds(usize),
+
+    #[error("Instruction for program {actual} passed to the parser for {expected}")]
+    ProgramMismatch { expected: Pubkey, actual: Pubkey },
+
+    #[error("Unknown instruction for program {0}")]
+    UnknownInstruction(Pubkey),
+
+    #[error("{0:?} instruction data does not round-trip")]
+    RoundTripMismatch(ParsableProgram),
+
+    #[error("Address lookup table {0} not provided")]
+    LookupTableNotFound(Pubkey),
+
+    #[error("Index {index} out of bounds for address lookup table {table}")]
+    LookupTableIndexOutOfBounds { table: Pubkey, index: u8 },
+
+    #[error("Too many accounts: {0}")]
+    TooManyAccounts(usize),
+
+    #[error("Invalid base64 instruction data")]
+    Base64DecodeError(#[from] base64::DecodeError),
+
     #[error("Internal error, please report")]
     SerdeJsonError(#[from] serde_json::error::Error),
 }
@@ -75,61 +190,683 @@ pub struct ParsedInstruction {
     pub program: String,
     pub program_id: String,
     pub parsed: Value,
+    /// Length in bytes of the raw instruction data
+    #[serde(default)]
+    pub data_len: usize,
+    /// Position within the transaction, when supplied through [`ParseConfig`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instruction_index: Option<u8>,
+    /// The message's account keys, which `info` references by index when
+    /// [`ParseConfig::index_accounts`] is set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_keys: Option<Vec<String>>,
+}
+
+impl ParsedInstruction {
+    /// One-line human summary, e.g. `system: transfer`. Memos render as their text, with line
+    /// breaks and other control characters escaped so the summary stays on one line.
+    pub fn summary(&self) -> String {
+        match &self.parsed {
+            Value::String(memo) => format!("{}: {}", self.program, memo.escape_debug()),
+            parsed => match parsed.get("type").and_then(Value::as_str) {
+                Some(instruction_type) => format!("{}: {}", self.program, instruction_type),
+                None => self.program.clone(),
+            },
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct ParsedInstructionEnum {
+    /// Built-in parsers borrow a static name; custom parsers may supply an owned one
     #[serde(rename = "type")]
-    pub instruction_type: String,
+    pub instruction_type: Cow<'static, str>,
     #[serde(default, skip_serializing_if = "Value::is_null")]
     pub info: Value,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+impl ParsedInstructionEnum {
+    /// SHA-256 of the canonical JSON serialization, stable across runs for deduplication and
+    /// content addressing
+    pub fn content_hash(&self) -> [u8; 32] {
+        let value = serde_json::to_value(self).expect("parsed instructions serialize to JSON");
+        let canonical = to_canonical_json(&value).expect("JSON values serialize");
+        hash(&canonical).to_bytes()
+    }
+
+    /// Like [`content_hash`](Self::content_hash), truncated to a `u64`, but ignoring every
+    /// `info` value naming one of the accounts `instruction` references, so structurally-identical
+    /// instructions touching different accounts share a fingerprint. Pubkey and hash arguments,
+    /// like an `owner` or a vote `hash`, are kept.
+    pub fn shape_fingerprint(
+        &self,
+        instruction: &CompiledInstruction,
+        account_keys: &AccountKeys,
+    ) -> u64 {
+        let mut value = serde_json::to_value(self).expect("parsed instructions serialize to JSON");
+        if let Some(info) = value.get_mut("info") {
+            let accounts: HashSet<String> = instruction
+                .accounts
+                .iter()
+                .filter_map(|index| account_keys.get(*index as usize))
+                .map(ToString::to_string)
+                .collect();
+            strip_accounts(info, &accounts);
+        }
+        let canonical = to_canonical_json(&value).expect("JSON values serialize");
+        let mut fingerprint = [0; 8];
+        fingerprint.copy_from_slice(&hash(&canonical).to_bytes()[..8]);
+        u64::from_le_bytes(fingerprint)
+    }
+
+    /// Coarse classification for dashboards: "transfer", "account-management", "vote",
+    /// "staking", "token", "system" or "other". Instruction names are shared between programs,
+    /// e.g. `initialize` or `withdraw`, so the classification is by the `program` that produced
+    /// this instruction.
+    pub fn category(&self, program: ParsableProgram) -> &'static str {
+        let instruction_type = self.instruction_type.as_ref();
+        match program {
+            ParsableProgram::System => match instruction_type {
+                instruction_types::system::TRANSFER
+                | instruction_types::system::TRANSFER_WITH_SEED
+                | instruction_types::system::WITHDRAW_FROM_NONCE => "transfer",
+                instruction_types::system::CREATE_ACCOUNT
+                | instruction_types::system::CREATE_ACCOUNT_WITH_SEED
+                | instruction_types::system::ALLOCATE
+                | instruction_types::system::ALLOCATE_WITH_SEED
+                | instruction_types::system::ASSIGN
+                | instruction_types::system::ASSIGN_WITH_SEED => "account-management",
+                _ => "system",
+            },
+            ParsableProgram::Stake => match instruction_type {
+                instruction_types::stake::WITHDRAW => "transfer",
+                _ => "staking",
+            },
+            ParsableProgram::SplStakePool => "staking",
+            ParsableProgram::Vote => "vote",
+            ParsableProgram::SplToken => match instruction_type {
+                instruction_types::token::TRANSFER
+                | instruction_types::token::TRANSFER_CHECKED
+                | instruction_types::token::TRANSFER_CHECKED_WITH_FEE => "transfer",
+                _ => "token",
+            },
+            ParsableProgram::SplAssociatedTokenAccount => "token",
+            _ => "other",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum ParsableProgram {
+    AddressLookupTable,
+    Anchor,
     SplAssociatedTokenAccount,
+    SplFeatureProposal,
+    SplGovernance,
     SplMemo,
+    SplNameService,
+    SplStakePool,
     SplToken,
+    SplTokenLending,
+    SplTokenSwap,
+    SplTokenUpgrade,
     BpfLoader,
     BpfUpgradeableLoader,
+    ComputeBudget,
+    JitoTipDistribution,
+    JitoTipPayment,
+    Noop,
+    #[cfg(feature = "legacy-programs")]
+    Budget,
+    #[cfg(feature = "legacy-programs")]
+    Exchange,
+    Secp256r1,
     Stake,
     System,
+    TokenMetadata,
     Vote,
 }
 
+/// Outcome of [`parse_or_unknown`]. Programs without a registered parser are an expected
+/// outcome rather than an error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MaybeParsed {
+    Parsed(ParsedInstruction),
+    Unknown,
+}
+
+/// Options for [`parse_with_config`]; the default reproduces [`parse`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParseConfig {
+    /// Add the program id as `programId` to every parsed `info` object
+    pub include_program_id: bool,
+    /// Known owners of accounts referenced by instructions, enabling owner checks such as
+    /// `voteAccountValid` on stake delegations
+    pub account_owners: HashMap<Pubkey, Pubkey>,
+    /// Known data of accounts referenced by instructions, enabling fields decoded from account
+    /// state such as `currentBlockhash` on nonce instructions, and telling a Token-2022 mint
+    /// being closed from a token account
+    pub account_data: HashMap<Pubkey, Vec<u8>>,
+    /// Known decimals of token mints, enabling fields such as `amount` on `uiAmountToAmount`
+    pub mint_decimals: HashMap<Pubkey, u8>,
+    /// Passed through as `instructionIndex` on the parsed instruction
+    pub instruction_index: Option<u8>,
+    /// Re-serialize the decoded instruction and fail with `RoundTripMismatch` unless it matches
+    /// the original data. Only system and vote instructions are checked.
+    pub verify_round_trip: bool,
+    /// Emit every integer in `info` as a string, for consumers whose numbers lose precision
+    /// above 2^53
+    pub stringify_numbers: bool,
+    /// Header of the legacy message the instruction belongs to, enabling `writableAccountCount`,
+    /// `readonlyAccountCount` and `feePayerWritable` in `info`
+    pub message_header: Option<MessageHeader>,
+    /// Rent parameters of the cluster, enabling `rentExempt` on `createAccount` and
+    /// `estimatedRentExemption` on `createAccount` and `allocate`
+    pub rent: Option<Rent>,
+    /// Add the leading `u32` bincode variant tag as `discriminant` to system and vote `info`
+    /// objects, for low-level debugging
+    pub include_discriminant: bool,
+    /// Omit `info` fields whose value is a sysvar id, such as `clockSysvar`, rather than naming
+    /// the sysvar
+    pub elide_sysvars: bool,
+    /// Epoch the instruction executes in, enabling `lockupEpochActive` on stake lockups
+    pub current_epoch: Option<Epoch>,
+    /// Replace every `info` string naming one of the instruction's accounts with
+    /// `{"accountIndex": n}`, `n` indexing the message's account keys, and return the keys once
+    /// as `accountKeys`, mirroring the compiled transaction layout. Much smaller for bulk
+    /// storage. The object form keeps references apart from numeric fields such as `lamports`;
+    /// indexing runs after [`ParseConfig::stringify_numbers`], so `n` stays a JSON number either
+    /// way.
+    pub index_accounts: bool,
+}
+
+impl ParseConfig {
+    pub fn with_program_id(mut self, include_program_id: bool) -> Self {
+        self.include_program_id = include_program_id;
+        self
+    }
+
+    pub fn with_account_owner(mut self, account: Pubkey, owner: Pubkey) -> Self {
+        self.account_owners.insert(account, owner);
+        self
+    }
+
+    pub fn with_account_data(mut self, account: Pubkey, data: Vec<u8>) -> Self {
+        self.account_data.insert(account, data);
+        self
+    }
+
+    pub fn with_mint_decimals(mut self, mint: Pubkey, decimals: u8) -> Self {
+        self.mint_decimals.insert(mint, decimals);
+        self
+    }
+
+    pub fn with_instruction_index(mut self, instruction_index: u8) -> Self {
+        self.instruction_index = Some(instruction_index);
+        self
+    }
+
+    pub fn with_round_trip_verification(mut self, verify_round_trip: bool) -> Self {
+        self.verify_round_trip = verify_round_trip;
+        self
+    }
+
+    pub fn with_stringified_numbers(mut self, stringify_numbers: bool) -> Self {
+        self.stringify_numbers = stringify_numbers;
+        self
+    }
+
+    pub fn with_message_header(mut self, message_header: MessageHeader) -> Self {
+        self.message_header = Some(message_header);
+        self
+    }
+
+    pub fn with_rent(mut self, rent: Rent) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+
+    pub fn with_discriminant(mut self, include_discriminant: bool) -> Self {
+        self.include_discriminant = include_discriminant;
+        self
+    }
+
+    pub fn with_elided_sysvars(mut self, elide_sysvars: bool) -> Self {
+        self.elide_sysvars = elide_sysvars;
+        self
+    }
+
+    pub fn with_current_epoch(mut self, current_epoch: Epoch) -> Self {
+        self.current_epoch = Some(current_epoch);
+        self
+    }
+
+    pub fn with_indexed_accounts(mut self, index_accounts: bool) -> Self {
+        self.index_accounts = index_accounts;
+        self
+    }
+}
+
+/// A parser for a single program, usable as a trait object so parser sets can be assembled at
+/// runtime
+pub trait InstructionParser {
+    fn program_id(&self) -> Pubkey;
+    /// Reported as `program` on the parsed instruction
+    fn program_name(&self) -> String {
+        self.program_id().to_string()
+    }
+    fn parse(
+        &self,
+        instruction: &CompiledInstruction,
+        account_keys: &AccountKeys,
+    ) -> Result<ParsedInstructionEnum, ParseInstructionError>;
+    /// Like `parse`, but first checks that the instruction's `program_id` is the one this parser
+    /// handles, since another program's data may decode spuriously
+    fn parse_for_program(
+        &self,
+        program_id: &Pubkey,
+        instruction: &CompiledInstruction,
+        account_keys: &AccountKeys,
+    ) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+        if *program_id != self.program_id() {
+            return Err(ParseInstructionError::ProgramMismatch {
+                expected: self.program_id(),
+                actual: *program_id,
+            });
+        }
+        self.parse(instruction, account_keys)
+    }
+}
+
+/// Parses `instruction` with the parser registered for `program_id`. Callers embedding the
+/// output in a larger JSON object can move the entries of the returned `parsed` object into their
+/// own map with `Map::extend`.
 pub fn parse(
     program_id: &Pubkey,
     instruction: &CompiledInstruction,
     account_keys: &AccountKeys,
+) -> Result<ParsedInstruction, ParseInstructionError> {
+    parse_with_config(
+        program_id,
+        instruction,
+        account_keys,
+        &ParseConfig::default(),
+    )
+}
+
+pub fn parse_with_config(
+    program_id: &Pubkey,
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+    config: &ParseConfig,
 ) -> Result<ParsedInstruction, ParseInstructionError> {
     let program_name = PARSABLE_PROGRAM_IDS
         .get(program_id)
         .ok_or(ParseInstructionError::ProgramNotParsable)?;
-    let parsed_json = match program_name {
+    if config.verify_round_trip {
+        verify_round_trip(program_name, &instruction.data)?;
+    }
+    let mut parsed_json = match program_name {
+        ParsableProgram::AddressLookupTable => {
+            serde_json::to_value(parse_address_lookup_table(instruction, account_keys)?)?
+        }
+        // Anchor programs have no fixed id; they are parsed through a `ParserRegistry`
+        ParsableProgram::Anchor => return Err(ParseInstructionError::ProgramNotParsable),
         ParsableProgram::SplAssociatedTokenAccount => {
             serde_json::to_value(parse_associated_token(instruction, account_keys)?)?
         }
+        ParsableProgram::SplFeatureProposal => {
+            serde_json::to_value(parse_feature_proposal(instruction, account_keys)?)?
+        }
+        ParsableProgram::SplGovernance => {
+            serde_json::to_value(parse_governance(instruction, account_keys)?)?
+        }
         ParsableProgram::SplMemo => parse_memo(instruction)?,
-        ParsableProgram::SplToken => serde_json::to_value(parse_token(instruction, account_keys)?)?,
+        ParsableProgram::SplNameService => {
+            serde_json::to_value(parse_name_service(instruction, account_keys)?)?
+        }
+        ParsableProgram::SplStakePool => {
+            serde_json::to_value(parse_stake_pool(instruction, account_keys)?)?
+        }
+        ParsableProgram::SplToken => {
+            let parsed = parse_token_with_config(instruction, account_keys, config)?;
+            let checked = is_checked_token_instruction(&parsed.instruction_type);
+            let mut parsed_json = serde_json::to_value(parsed)?;
+            // Describes the instruction rather than its accounts, so sits next to `type`
+            if let Some(parsed_object) = parsed_json.as_object_mut() {
+                parsed_object.insert("checked".to_string(), json!(checked));
+            }
+            parsed_json
+        }
+        ParsableProgram::SplTokenLending => {
+            serde_json::to_value(parse_token_lending(instruction, account_keys)?)?
+        }
+        ParsableProgram::SplTokenSwap => {
+            serde_json::to_value(parse_token_swap(instruction, account_keys)?)?
+        }
+        ParsableProgram::SplTokenUpgrade => {
+            serde_json::to_value(parse_token_upgrade(instruction, account_keys)?)?
+        }
         ParsableProgram::BpfLoader => {
             serde_json::to_value(parse_bpf_loader(instruction, account_keys)?)?
         }
-        ParsableProgram::BpfUpgradeableLoader => {
-            serde_json::to_value(parse_bpf_upgradeable_loader(instruction, account_keys)?)?
+        ParsableProgram::BpfUpgradeableLoader => serde_json::to_value(
+            parse_bpf_upgradeable_loader_with_config(instruction, account_keys, config)?,
+        )?,
+        ParsableProgram::ComputeBudget => {
+            serde_json::to_value(parse_compute_budget(instruction, account_keys)?)?
+        }
+        ParsableProgram::JitoTipDistribution => {
+            serde_json::to_value(parse_jito_tip_distribution(instruction, account_keys)?)?
+        }
+        ParsableProgram::JitoTipPayment => {
+            serde_json::to_value(parse_jito_tip_payment(instruction, account_keys)?)?
+        }
+        ParsableProgram::Noop => serde_json::to_value(parse_noop(instruction, account_keys)?)?,
+        #[cfg(feature = "legacy-programs")]
+        ParsableProgram::Budget => serde_json::to_value(parse_legacy::parse_budget(instruction)?)?,
+        #[cfg(feature = "legacy-programs")]
+        ParsableProgram::Exchange => {
+            serde_json::to_value(parse_legacy::parse_exchange(instruction)?)?
+        }
+        ParsableProgram::Secp256r1 => serde_json::to_value(parse_secp256r1(instruction)?)?,
+        ParsableProgram::Stake => {
+            serde_json::to_value(parse_stake_with_config(instruction, account_keys, config)?)?
+        }
+        ParsableProgram::System => {
+            serde_json::to_value(parse_system_with_config(instruction, account_keys, config)?)?
+        }
+        ParsableProgram::TokenMetadata => {
+            serde_json::to_value(parse_token_metadata(instruction, account_keys)?)?
         }
-        ParsableProgram::Stake => serde_json::to_value(parse_stake(instruction, account_keys)?)?,
-        ParsableProgram::System => serde_json::to_value(parse_system(instruction, account_keys)?)?,
         ParsableProgram::Vote => serde_json::to_value(parse_vote(instruction, account_keys)?)?,
     };
+    if let Some(info) = parsed_json.get_mut("info").and_then(Value::as_object_mut) {
+        if config.elide_sysvars {
+            info.retain(|_, value| sysvar_name(value).is_none());
+        } else {
+            annotate_sysvar_names(info);
+        }
+    }
+    if let Some(header) = &config.message_header {
+        if let Some(info) = info_object_mut(&mut parsed_json) {
+            let (writable, readonly) =
+                count_account_access(header, account_keys.len(), &instruction.accounts);
+            info.insert("writableAccountCount".to_string(), json!(writable));
+            info.insert("readonlyAccountCount".to_string(), json!(readonly));
+            // The fee payer is always the first account key
+            let fee_payer_writable = instruction.accounts.contains(&0)
+                && is_writable_index(header, account_keys.len(), 0);
+            info.insert("feePayerWritable".to_string(), json!(fee_payer_writable));
+        }
+    }
+    if config.include_discriminant
+        && matches!(
+            program_name,
+            ParsableProgram::System | ParsableProgram::Vote
+        )
+    {
+        // The instruction decoded, so the tag is present
+        let tag = <[u8; 4]>::try_from(&instruction.data[..4]).unwrap();
+        if let Some(info) = info_object_mut(&mut parsed_json) {
+            info.insert("discriminant".to_string(), json!(u32::from_le_bytes(tag)));
+        }
+    }
+    if config.stringify_numbers {
+        if let Some(info) = parsed_json.get_mut("info") {
+            stringify_integers(info);
+        }
+    }
+    if config.include_program_id {
+        if let Some(info) = info_object_mut(&mut parsed_json) {
+            info.insert("programId".to_string(), json!(program_id.to_string()));
+        }
+    }
+    let account_keys = config.index_accounts.then(|| {
+        // Only the instruction's accounts are references; pubkey arguments, like an `owner`, may
+        // coincide with a message key but stay strings
+        let accounts: HashMap<String, usize> = instruction
+            .accounts
+            .iter()
+            .filter_map(|index| {
+                let key = account_keys.get(*index as usize)?;
+                Some((key.to_string(), *index as usize))
+            })
+            .collect();
+        if let Some(info) = parsed_json.get_mut("info") {
+            index_account_references(info, &accounts);
+        }
+        account_keys
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+    });
     Ok(ParsedInstruction {
-        program: format!("{:?}", program_name).to_kebab_case(),
+        program: match program_name {
+            // Kebab-casing would split the digits apart
+            ParsableProgram::Secp256r1 => "secp256r1".to_string(),
+            _ => format!("{:?}", program_name).to_kebab_case(),
+        },
         program_id: program_id.to_string(),
         parsed: parsed_json,
+        data_len: instruction.data.len(),
+        instruction_index: config.instruction_index,
+        account_keys,
+    })
+}
+
+/// Minimal entry point for FFI callers: parses raw instruction `data` whose accounts are
+/// `account_keys`, in instruction order, and returns the `{type, info}` value or an error message
+pub fn parse_to_value(
+    program_id: &Pubkey,
+    data: &[u8],
+    account_keys: &[Pubkey],
+) -> Result<Value, String> {
+    let instruction =
+        compile_instruction(data.to_vec(), account_keys).map_err(|err| err.to_string())?;
+    parse(
+        program_id,
+        &instruction,
+        &AccountKeys::new(account_keys, None),
+    )
+    .map(|parsed| parsed.parsed)
+    .map_err(|err| err.to_string())
+}
+
+/// Like [`parse_to_value`], for sources that hand over instruction data base64-encoded
+pub fn parse_base64_data(
+    program_id: &Pubkey,
+    data_b64: &str,
+    account_keys: &[Pubkey],
+) -> Result<ParsedInstruction, ParseInstructionError> {
+    let data = base64::decode(data_b64)?;
+    let instruction = compile_instruction(data, account_keys)?;
+    parse(
+        program_id,
+        &instruction,
+        &AccountKeys::new(account_keys, None),
+    )
+}
+
+/// Like [`parse`], but never fails: returns the serialized [`ParsedInstruction`], or on failure
+/// an object with the `error` message, the `program` id, the base-58 `data` and the `accounts`
+/// the instruction references, so RPC consumers can render either outcome as JSON
+pub fn parse_to_result_json(
+    program_id: &Pubkey,
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Value {
+    parse(program_id, instruction, account_keys)
+        .and_then(|parsed| Ok(serde_json::to_value(parsed)?))
+        .unwrap_or_else(|err| {
+            // Indexes past the end of `account_keys` render as null
+            let accounts: Vec<Option<String>> = instruction
+                .accounts
+                .iter()
+                .map(|index| account_keys.get(*index as usize).map(ToString::to_string))
+                .collect();
+            json!({
+                "error": err.to_string(),
+                "program": program_id.to_string(),
+                "data": bs58::encode(&instruction.data).into_string(),
+                "accounts": accounts,
+            })
+        })
+}
+
+/// Decodes the return data an instruction of `program_id` set. Only formats that stand on their
+/// own are decoded, like the stake program's minimum delegation, a little-endian `u64`. The
+/// token programs' `u64` and ui-amount string returns can't be told apart without the instruction.
+pub fn decode_return_data(
+    program_id: &Pubkey,
+    return_data: &[u8],
+) -> Result<Value, ParseInstructionError> {
+    match PARSABLE_PROGRAM_IDS.get(program_id) {
+        Some(ParsableProgram::Stake) => {
+            let minimum_delegation = <[u8; 8]>::try_from(return_data).map_err(|_| {
+                ParseInstructionError::InstructionNotParsable(ParsableProgram::Stake)
+            })?;
+            Ok(json!(u64::from_le_bytes(minimum_delegation)))
+        }
+        _ => Err(ParseInstructionError::ProgramNotParsable),
+    }
+}
+
+// Builds an instruction referencing every one of `account_keys`, in order
+fn compile_instruction(
+    data: Vec<u8>,
+    account_keys: &[Pubkey],
+) -> Result<CompiledInstruction, ParseInstructionError> {
+    let accounts = (0..account_keys.len())
+        .map(u8::try_from)
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|_| ParseInstructionError::TooManyAccounts(account_keys.len()))?;
+    Ok(CompiledInstruction {
+        program_id_index: 0,
+        accounts,
+        data,
     })
 }
 
+/// Like [`parse_with_config`], but returns [`MaybeParsed::Unknown`] instead of an error for
+/// unregistered programs, reserving errors for instructions that fail to decode
+pub fn parse_or_unknown(
+    program_id: &Pubkey,
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+    config: &ParseConfig,
+) -> Result<MaybeParsed, ParseInstructionError> {
+    if !PARSABLE_PROGRAM_IDS.contains_key(program_id) {
+        return Ok(MaybeParsed::Unknown);
+    }
+    parse_with_config(program_id, instruction, account_keys, config).map(MaybeParsed::Parsed)
+}
+
+/// Returns the `info` object of a parsed `{type, info}` value, creating it when the parser
+/// emitted none. Parsers that emit a bare value, like memos, have no `info`.
+fn info_object_mut(parsed_json: &mut Value) -> Option<&mut Map<String, Value>> {
+    parsed_json
+        .as_object_mut()?
+        .entry("info")
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+}
+
+// Counts the distinct accounts of an instruction the header allows to be written and those it
+// doesn't, as (writable, readonly)
+fn count_account_access(
+    header: &MessageHeader,
+    num_account_keys: usize,
+    accounts: &[u8],
+) -> (usize, usize) {
+    let accounts: BTreeSet<usize> = accounts.iter().map(|index| *index as usize).collect();
+    let writable = accounts
+        .iter()
+        .filter(|index| is_writable_index(header, num_account_keys, **index))
+        .count();
+    (writable, accounts.len() - writable)
+}
+
+// Whether the account at `index` of a legacy message is writable under `header`
+fn is_writable_index(header: &MessageHeader, num_account_keys: usize, index: usize) -> bool {
+    let num_signed = header.num_required_signatures as usize;
+    if index < num_signed {
+        index < num_signed.saturating_sub(header.num_readonly_signed_accounts as usize)
+    } else {
+        index < num_account_keys.saturating_sub(header.num_readonly_unsigned_accounts as usize)
+    }
+}
+
+fn strip_accounts(value: &mut Value, accounts: &HashSet<String>) {
+    let is_account = |value: &Value| {
+        value
+            .as_str()
+            .map_or(false, |value| accounts.contains(value))
+    };
+    match value {
+        Value::Array(values) => {
+            values.retain(|value| !is_account(value));
+            values
+                .iter_mut()
+                .for_each(|value| strip_accounts(value, accounts));
+        }
+        Value::Object(map) => {
+            map.retain(|_, value| !is_account(value));
+            map.values_mut()
+                .for_each(|value| strip_accounts(value, accounts));
+        }
+        _ => {}
+    }
+}
+
+fn index_account_references(value: &mut Value, accounts: &HashMap<String, usize>) {
+    match value {
+        Value::String(string) => {
+            if let Some(index) = accounts.get(string.as_str()) {
+                *value = json!({ "accountIndex": index });
+            }
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| index_account_references(value, accounts)),
+        Value::Object(map) => map
+            .values_mut()
+            .for_each(|value| index_account_references(value, accounts)),
+        _ => {}
+    }
+}
+
+fn stringify_integers(value: &mut Value) {
+    match value {
+        Value::Number(number) if number.is_u64() || number.is_i64() => {
+            *value = Value::String(number.to_string());
+        }
+        Value::Array(values) => values.iter_mut().for_each(stringify_integers),
+        Value::Object(map) => map.values_mut().for_each(stringify_integers),
+        _ => {}
+    }
+}
+
+// Names every info account that is a known sysvar, e.g. `clockSysvar` gains `clockSysvarName`
+fn annotate_sysvar_names(info: &mut Map<String, Value>) {
+    let names: Vec<(String, &str)> = info
+        .iter()
+        .filter_map(|(key, value)| {
+            let name = sysvar_name(value)?;
+            Some((format!("{}Name", key), name))
+        })
+        .collect();
+    for (key, name) in names {
+        info.insert(key, json!(name));
+    }
+}
+
+fn sysvar_name(value: &Value) -> Option<&'static str> {
+    SYSVAR_NAMES.get(&value.as_str()?.parse().ok()?).copied()
+}
+
 fn parse_memo(instruction: &CompiledInstruction) -> Result<Value, ParseInstructionError> {
     parse_memo_data(&instruction.data)
         .map(Value::String)
@@ -140,6 +877,19 @@ pub fn parse_memo_data(data: &[u8]) -> Result<String, Utf8Error> {
     from_utf8(data).map(|s| s.to_string())
 }
 
+/// Renders `lamports` as an exact SOL amount, e.g. `"0.000000055"` for 55 lamports
+pub(crate) fn lamports_to_sol_string(lamports: u64) -> String {
+    real_number_string_trimmed(lamports, SOL_DECIMALS)
+}
+
+/// Flags lamports moved into the incinerator, which burns them
+pub(crate) fn flag_incinerator_destination(value: &mut Value, destination: &Pubkey) {
+    if *destination == incinerator::id() {
+        let map = value.as_object_mut().unwrap();
+        map.insert("destinationIsIncinerator".to_string(), json!(true));
+    }
+}
+
 pub(crate) fn check_num_accounts(
     accounts: &[u8],
     num: usize,
@@ -156,7 +906,21 @@ pub(crate) fn check_num_accounts(
 
 #[cfg(test)]
 mod test {
-    use {super::*, serde_json::json};
+    use {
+        super::*,
+        crate::{
+            parse_stake::parse_stake,
+            parse_system::{parse_system, SystemParser},
+            parse_token::spl_token_instruction,
+            parse_vote::VoteParser,
+        },
+        solana_account_decoder::parse_token::{pubkey_from_spl_token, spl_token_pubkey},
+        solana_sdk::{hash::Hash, instruction::Instruction, message::Message, system_instruction},
+        solana_vote_program::{
+            vote_instruction,
+            vote_state::{Vote, VoteAuthorize},
+        },
+    };
 
     #[test]
     fn test_parse() {
@@ -172,6 +936,9 @@ mod test {
                 program: "spl-memo".to_string(),
                 program_id: MEMO_V1_PROGRAM_ID.to_string(),
                 parsed: json!("🦖"),
+                data_len: 4,
+                instruction_index: None,
+                account_keys: None,
             }
         );
         assert_eq!(
@@ -180,6 +947,9 @@ mod test {
                 program: "spl-memo".to_string(),
                 program_id: MEMO_V3_PROGRAM_ID.to_string(),
                 parsed: json!("🦖"),
+                data_len: 4,
+                instruction_index: None,
+                account_keys: None,
             }
         );
 
@@ -209,4 +979,994 @@ mod test {
         })
         .is_err(),);
     }
+
+    #[test]
+    fn test_category() {
+        let category = |program: ParsableProgram, instruction_type: &'static str| {
+            ParsedInstructionEnum {
+                instruction_type: instruction_type.into(),
+                info: Value::Null,
+            }
+            .category(program)
+        };
+        assert_eq!(category(ParsableProgram::System, "transfer"), "transfer");
+        assert_eq!(category(ParsableProgram::SplToken, "transfer"), "transfer");
+        assert_eq!(
+            category(ParsableProgram::SplToken, "transferChecked"),
+            "transfer"
+        );
+        assert_eq!(category(ParsableProgram::Stake, "withdraw"), "transfer");
+        assert_eq!(category(ParsableProgram::Vote, "initialize"), "vote");
+        assert_eq!(category(ParsableProgram::Stake, "initialize"), "staking");
+        assert_eq!(category(ParsableProgram::Stake, "merge"), "staking");
+        assert_eq!(category(ParsableProgram::SplToken, "mintTo"), "token");
+        assert_eq!(
+            category(ParsableProgram::System, "createAccount"),
+            "account-management"
+        );
+        assert_eq!(category(ParsableProgram::System, "advanceNonce"), "system");
+
+        // Delegations name the vote account, but are stake instructions
+        let stake_account = Pubkey::new_unique();
+        let authorized = Pubkey::new_unique();
+        let vote_account = Pubkey::new_unique();
+        let message = Message::new(
+            &[stake::instruction::delegate_stake(
+                &stake_account,
+                &authorized,
+                &vote_account,
+            )],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let parsed = parse_stake(&message.instructions[0], &account_keys).unwrap();
+        assert_eq!(parsed.info["voteAccount"], json!(vote_account.to_string()));
+        assert_eq!(parsed.category(ParsableProgram::Stake), "staking");
+
+        // Non-system programs are not lumped in with the system program
+        let lookup_table = Pubkey::new_unique();
+        let message = Message::new(
+            &[
+                solana_address_lookup_table_program::instruction::freeze_lookup_table(
+                    lookup_table,
+                    authorized,
+                ),
+            ],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let parsed = parse_address_lookup_table(&message.instructions[0], &account_keys).unwrap();
+        assert_eq!(
+            parsed.category(ParsableProgram::AddressLookupTable),
+            "other"
+        );
+    }
+
+    #[test]
+    fn test_builtin_instruction_type_is_borrowed() {
+        let from_pubkey = Pubkey::new_unique();
+        let to_pubkey = Pubkey::new_unique();
+        let instruction = system_instruction::transfer(&from_pubkey, &to_pubkey, 42);
+        let message = Message::new(&[instruction], None);
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+
+        let parsed = parse_system(&message.instructions[0], &account_keys).unwrap();
+        assert!(matches!(parsed.instruction_type, Cow::Borrowed("transfer")));
+
+        // Serialization is unaffected by the borrowed representation
+        let serialized = serde_json::to_value(&parsed).unwrap();
+        assert_eq!(serialized["type"], json!("transfer"));
+        let deserialized: ParsedInstructionEnum = serde_json::from_value(serialized).unwrap();
+        assert_eq!(deserialized, parsed);
+
+        let custom = ParsedInstructionEnum {
+            instruction_type: format!("custom{}", 1).into(),
+            info: Value::Null,
+        };
+        assert!(matches!(custom.instruction_type, Cow::Owned(_)));
+        assert_eq!(
+            serde_json::to_string(&custom).unwrap(),
+            r#"{"type":"custom1"}"#
+        );
+    }
+
+    #[test]
+    fn test_parse_with_config_include_program_id() {
+        let from_pubkey = Pubkey::new_unique();
+        let to_pubkey = Pubkey::new_unique();
+        let message = Message::new(
+            &[system_instruction::transfer(&from_pubkey, &to_pubkey, 42)],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let config = ParseConfig {
+            include_program_id: true,
+            ..ParseConfig::default()
+        };
+
+        let parsed = parse_with_config(
+            &SYSTEM_PROGRAM_ID,
+            &message.instructions[0],
+            &account_keys,
+            &ParseConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            parsed,
+            parse(&SYSTEM_PROGRAM_ID, &message.instructions[0], &account_keys).unwrap()
+        );
+        assert!(parsed.parsed["info"].get("programId").is_none());
+
+        let parsed = parse_with_config(
+            &SYSTEM_PROGRAM_ID,
+            &message.instructions[0],
+            &account_keys,
+            &config,
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.parsed,
+            json!({
+                "type": "transfer",
+                "info": {
+                    "source": from_pubkey.to_string(),
+                    "destination": to_pubkey.to_string(),
+                    "lamports": 42,
+                    "sol": "0.000000042",
+                    "programId": SYSTEM_PROGRAM_ID.to_string(),
+                },
+            })
+        );
+
+        // Instructions without info gain one
+        let mut parsed_json = json!({"type": "getMinimumDelegation"});
+        info_object_mut(&mut parsed_json)
+            .unwrap()
+            .insert("programId".to_string(), json!(STAKE_PROGRAM_ID.to_string()));
+        assert_eq!(
+            parsed_json,
+            json!({
+                "type": "getMinimumDelegation",
+                "info": {
+                    "programId": STAKE_PROGRAM_ID.to_string(),
+                },
+            })
+        );
+
+        // Memos have no info object to extend
+        let memo_instruction = CompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![],
+            data: b"memo".to_vec(),
+        };
+        let no_keys = AccountKeys::new(&[], None);
+        assert_eq!(
+            parse_with_config(&MEMO_V3_PROGRAM_ID, &memo_instruction, &no_keys, &config)
+                .unwrap()
+                .parsed,
+            json!("memo")
+        );
+    }
+
+    #[test]
+    fn test_parse_with_indexed_accounts() {
+        fn resolve(value: &mut Value, account_keys: &[String]) {
+            if let Some(index) = value.get("accountIndex").and_then(Value::as_u64) {
+                *value = json!(account_keys[index as usize]);
+                return;
+            }
+            match value {
+                Value::Array(values) => values.iter_mut().for_each(|v| resolve(v, account_keys)),
+                Value::Object(map) => map.values_mut().for_each(|v| resolve(v, account_keys)),
+                _ => {}
+            }
+        }
+
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let message = Message::new(
+            &[system_instruction::create_account(
+                &from, &to, 42, 8, &owner,
+            )],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let instruction = &message.instructions[0];
+        let program_id = system_program::id();
+        let config = ParseConfig::default()
+            .with_indexed_accounts(true)
+            .with_stringified_numbers(true);
+        let indexed = parse_with_config(&program_id, instruction, &account_keys, &config).unwrap();
+        let keys = indexed.account_keys.clone().unwrap();
+        assert_eq!(
+            keys,
+            vec![from.to_string(), to.to_string(), program_id.to_string()]
+        );
+        // The owner isn't one of the instruction's accounts, so stays a pubkey string
+        assert_eq!(
+            indexed.parsed["info"],
+            json!({
+                "source": {"accountIndex": 0},
+                "newAccount": {"accountIndex": 1},
+                "lamports": "42",
+                "sol": "0.000000042",
+                "space": "8",
+                "owner": owner.to_string(),
+                "ownerIsSystemProgram": false,
+            })
+        );
+
+        let mut resolved = indexed.parsed;
+        resolve(&mut resolved["info"], &keys);
+        let plain = parse_with_config(
+            &program_id,
+            instruction,
+            &account_keys,
+            &ParseConfig::default().with_stringified_numbers(true),
+        )
+        .unwrap();
+        assert_eq!(resolved, plain.parsed);
+        assert_eq!(plain.account_keys, None);
+        assert!(!serde_json::to_value(&plain)
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .contains_key("accountKeys"));
+
+        // The owner is a message key here, as another instruction invokes it, but remains an
+        // argument rather than an account reference
+        let message = Message::new(
+            &[
+                system_instruction::create_account(&from, &to, 42, 8, &owner),
+                Instruction::new_with_bytes(owner, &[], vec![]),
+            ],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let indexed = parse_with_config(
+            &program_id,
+            &message.instructions[0],
+            &account_keys,
+            &config,
+        )
+        .unwrap();
+        assert!(indexed.account_keys.unwrap().contains(&owner.to_string()));
+        assert_eq!(indexed.parsed["info"]["source"], json!({"accountIndex": 0}));
+        assert_eq!(
+            indexed.parsed["info"]["newAccount"],
+            json!({"accountIndex": 1})
+        );
+        assert_eq!(indexed.parsed["info"]["owner"], json!(owner.to_string()));
+
+        // Without stringified numbers, references still differ from plain numeric fields
+        let indexed = parse_with_config(
+            &program_id,
+            &message.instructions[0],
+            &account_keys,
+            &ParseConfig::default().with_indexed_accounts(true),
+        )
+        .unwrap();
+        assert_eq!(indexed.parsed["info"]["source"], json!({"accountIndex": 0}));
+        assert_eq!(indexed.parsed["info"]["lamports"], json!(42));
+        assert_eq!(indexed.parsed["info"]["space"], json!(8));
+    }
+
+    #[test]
+    fn test_parse_or_unknown() {
+        let config = ParseConfig::default();
+        let memo_instruction = CompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![],
+            data: vec![240, 159, 166, 150],
+        };
+        let no_keys = AccountKeys::new(&[], None);
+        assert_eq!(
+            parse_or_unknown(&Pubkey::new_unique(), &memo_instruction, &no_keys, &config).unwrap(),
+            MaybeParsed::Unknown
+        );
+        assert_eq!(
+            parse_or_unknown(&MEMO_V3_PROGRAM_ID, &memo_instruction, &no_keys, &config).unwrap(),
+            MaybeParsed::Parsed(parse(&MEMO_V3_PROGRAM_ID, &memo_instruction, &no_keys).unwrap())
+        );
+
+        // Malformed data for a known program is still an error
+        let bad_instruction = CompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![],
+            data: vec![255; 4],
+        };
+        assert!(matches!(
+            parse_or_unknown(&system_program::id(), &bad_instruction, &no_keys, &config),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::System
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_parse_with_config_instruction_index() {
+        let memo_instruction = CompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![],
+            data: vec![240, 159, 166, 150],
+        };
+        let no_keys = AccountKeys::new(&[], None);
+        let config = ParseConfig {
+            instruction_index: Some(3),
+            ..ParseConfig::default()
+        };
+        let parsed =
+            parse_with_config(&MEMO_V3_PROGRAM_ID, &memo_instruction, &no_keys, &config).unwrap();
+        assert_eq!(parsed.instruction_index, Some(3));
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            json!({
+                "program": "spl-memo",
+                "programId": MEMO_V3_PROGRAM_ID.to_string(),
+                "parsed": "🦖",
+                "dataLen": 4,
+                "instructionIndex": 3,
+            })
+        );
+
+        // Omitted from the envelope unless supplied
+        let parsed = parse(&MEMO_V3_PROGRAM_ID, &memo_instruction, &no_keys).unwrap();
+        assert!(serde_json::to_value(parsed)
+            .unwrap()
+            .get("instructionIndex")
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_token_checked() {
+        let source = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let parse_spl_token = |instruction| {
+            let message = Message::new(&[spl_token_instruction(instruction)], None);
+            parse(
+                &pubkey_from_spl_token(&spl_token::id()),
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None),
+            )
+            .unwrap()
+        };
+
+        let transfer = spl_token::instruction::transfer(
+            &spl_token::id(),
+            &spl_token_pubkey(&source),
+            &spl_token_pubkey(&destination),
+            &spl_token_pubkey(&owner),
+            &[],
+            42,
+        )
+        .unwrap();
+        assert_eq!(parse_spl_token(transfer).parsed["checked"], json!(false));
+
+        let transfer_checked = spl_token::instruction::transfer_checked(
+            &spl_token::id(),
+            &spl_token_pubkey(&source),
+            &spl_token_pubkey(&mint),
+            &spl_token_pubkey(&destination),
+            &spl_token_pubkey(&owner),
+            &[],
+            42,
+            2,
+        )
+        .unwrap();
+        let parsed = parse_spl_token(transfer_checked).parsed;
+        assert_eq!(parsed["checked"], json!(true));
+        assert!(parsed["info"].get("checked").is_none());
+    }
+
+    #[test]
+    fn test_parse_token_wrapped_sol_mint() {
+        let source = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let parse_transfer_checked = |mint| {
+            let instruction = spl_token::instruction::transfer_checked(
+                &spl_token::id(),
+                &spl_token_pubkey(&source),
+                &mint,
+                &spl_token_pubkey(&destination),
+                &spl_token_pubkey(&owner),
+                &[],
+                42,
+                9,
+            )
+            .unwrap();
+            let message = Message::new(&[spl_token_instruction(instruction)], None);
+            parse(
+                &pubkey_from_spl_token(&spl_token::id()),
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None),
+            )
+            .unwrap()
+        };
+
+        let parsed = parse_transfer_checked(spl_token::native_mint::id());
+        assert_eq!(parsed.parsed["info"]["mintIsWrappedSol"], json!(true));
+        let parsed = parse_transfer_checked(spl_token_pubkey(&Pubkey::new_unique()));
+        assert!(parsed.parsed["info"].get("mintIsWrappedSol").is_none());
+    }
+
+    #[test]
+    fn test_parse_to_result_json() {
+        let from_pubkey = Pubkey::new_unique();
+        let to_pubkey = Pubkey::new_unique();
+        let message = Message::new(
+            &[system_instruction::transfer(&from_pubkey, &to_pubkey, 42)],
+            None,
+        );
+        let instruction = &message.instructions[0];
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        assert_eq!(
+            parse_to_result_json(&SYSTEM_PROGRAM_ID, instruction, &account_keys),
+            serde_json::to_value(parse(&SYSTEM_PROGRAM_ID, instruction, &account_keys).unwrap())
+                .unwrap()
+        );
+
+        let program_id = Pubkey::new_unique();
+        assert_eq!(
+            parse_to_result_json(&program_id, instruction, &account_keys),
+            json!({
+                "error": "Program not parsable",
+                "program": program_id.to_string(),
+                "data": bs58::encode(&instruction.data).into_string(),
+                "accounts": [from_pubkey.to_string(), to_pubkey.to_string()],
+            })
+        );
+
+        // Missing account keys
+        let account_keys = AccountKeys::new(&message.account_keys[..1], None);
+        assert_eq!(
+            parse_to_result_json(&SYSTEM_PROGRAM_ID, instruction, &account_keys),
+            json!({
+                "error": "System instruction key mismatch",
+                "program": SYSTEM_PROGRAM_ID.to_string(),
+                "data": bs58::encode(&instruction.data).into_string(),
+                "accounts": [from_pubkey.to_string(), null],
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_to_value() {
+        let from_pubkey = Pubkey::new_unique();
+        let to_pubkey = Pubkey::new_unique();
+        let instruction = system_instruction::transfer(&from_pubkey, &to_pubkey, 42);
+        assert_eq!(
+            parse_to_value(
+                &system_program::id(),
+                &instruction.data,
+                &[from_pubkey, to_pubkey]
+            )
+            .unwrap(),
+            json!({
+                "type": "transfer",
+                "info": {
+                    "source": from_pubkey.to_string(),
+                    "destination": to_pubkey.to_string(),
+                    "lamports": 42,
+                    "sol": "0.000000042",
+                },
+            })
+        );
+        assert_eq!(
+            parse_to_value(&system_program::id(), &instruction.data, &[from_pubkey]).unwrap_err(),
+            "System instruction key mismatch"
+        );
+        assert_eq!(
+            parse_to_value(&Pubkey::new_unique(), &instruction.data, &[]).unwrap_err(),
+            "Program not parsable"
+        );
+    }
+
+    #[test]
+    fn test_instruction_parser_trait_object() {
+        let parsers: Vec<Box<dyn InstructionParser>> =
+            vec![Box::new(SystemParser), Box::new(VoteParser)];
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(&[system_instruction::transfer(&from, &to, 42)], None);
+        let instruction = &message.instructions[0];
+        let program_id = message.account_keys[instruction.program_id_index as usize];
+        let parser = parsers
+            .iter()
+            .find(|parser| parser.program_id() == program_id)
+            .unwrap();
+        assert_eq!(
+            parser
+                .parse(instruction, &AccountKeys::new(&message.account_keys, None))
+                .unwrap()
+                .instruction_type,
+            "transfer"
+        );
+        assert_eq!(parsers[1].program_id(), solana_vote_program::id());
+        assert!(parsers[1]
+            .parse(instruction, &AccountKeys::new(&message.account_keys, None))
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_base64_data() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let instruction = system_instruction::transfer(&from, &to, 42);
+        let data_b64 = base64::encode(&instruction.data);
+        assert_eq!(
+            parse_base64_data(&system_program::id(), &data_b64, &[from, to])
+                .unwrap()
+                .parsed,
+            parse_to_value(&system_program::id(), &instruction.data, &[from, to]).unwrap()
+        );
+
+        assert!(matches!(
+            parse_base64_data(&system_program::id(), "not base64!", &[from, to]),
+            Err(ParseInstructionError::Base64DecodeError(_))
+        ));
+    }
+
+    #[test]
+    fn test_sysvar_names() {
+        let vote_pubkey = Pubkey::new_unique();
+        let authorized_pubkey = Pubkey::new_unique();
+        let instruction = vote_instruction::authorize(
+            &vote_pubkey,
+            &authorized_pubkey,
+            &Pubkey::new_unique(),
+            VoteAuthorize::Voter,
+        );
+        let message = Message::new(&[instruction], None);
+        let parsed = parse(
+            &message.account_keys[message.instructions[0].program_id_index as usize],
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.parsed["info"]["clockSysvar"],
+            json!("SysvarC1ock11111111111111111111111111111111")
+        );
+        assert_eq!(parsed.parsed["info"]["clockSysvarName"], json!("clock"));
+        assert!(parsed.parsed["info"].get("voteAccountName").is_none());
+
+        // Instructions without info aren't given any
+        let keys = [Pubkey::new_unique(), *STAKE_PROGRAM_ID];
+        let instruction = CompiledInstruction::new_from_raw_parts(
+            1,
+            stake::instruction::get_minimum_delegation().data,
+            vec![0],
+        );
+        let parsed = parse(
+            &STAKE_PROGRAM_ID,
+            &instruction,
+            &AccountKeys::new(&keys, None),
+        )
+        .unwrap();
+        assert!(parsed.parsed.get("info").is_none());
+    }
+
+    #[test]
+    fn test_parse_with_config_elide_sysvars() {
+        let vote_pubkey = Pubkey::new_unique();
+        let instruction = vote_instruction::authorize(
+            &vote_pubkey,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            VoteAuthorize::Voter,
+        );
+        let message = Message::new(&[instruction], None);
+        let parsed = parse_with_config(
+            &VOTE_PROGRAM_ID,
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+            &ParseConfig::default().with_elided_sysvars(true),
+        )
+        .unwrap();
+        let info = &parsed.parsed["info"];
+        assert!(info.get("clockSysvar").is_none());
+        assert!(info.get("clockSysvarName").is_none());
+        assert_eq!(info["voteAccount"], json!(vote_pubkey.to_string()));
+    }
+
+    #[test]
+    fn test_verify_round_trip() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let mut message = Message::new(&[system_instruction::transfer(&from, &to, 42)], None);
+        let config = ParseConfig {
+            verify_round_trip: true,
+            ..ParseConfig::default()
+        };
+        assert_eq!(
+            parse_with_config(
+                &SYSTEM_PROGRAM_ID,
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None),
+                &config,
+            )
+            .unwrap()
+            .parsed["type"],
+            json!("transfer")
+        );
+
+        // Trailing bytes are ignored when decoding, but don't survive re-encoding
+        message.instructions[0].data.push(0);
+        assert!(parse(
+            &SYSTEM_PROGRAM_ID,
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+        )
+        .is_ok());
+        assert!(matches!(
+            parse_with_config(
+                &SYSTEM_PROGRAM_ID,
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None),
+                &config,
+            ),
+            Err(ParseInstructionError::RoundTripMismatch(
+                ParsableProgram::System
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_decode_return_data() {
+        let minimum_delegation: u64 = 1_000_000_000;
+        assert_eq!(
+            decode_return_data(
+                &solana_sdk::stake::program::id(),
+                &minimum_delegation.to_le_bytes()
+            )
+            .unwrap(),
+            json!(1_000_000_000)
+        );
+        assert!(matches!(
+            decode_return_data(&solana_sdk::stake::program::id(), &[0; 4]),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::Stake
+            ))
+        ));
+        assert!(matches!(
+            decode_return_data(&Pubkey::new_unique(), &minimum_delegation.to_le_bytes()),
+            Err(ParseInstructionError::ProgramNotParsable)
+        ));
+    }
+
+    #[test]
+    fn test_stringify_numbers() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(&[system_instruction::transfer(&from, &to, u64::MAX)], None);
+        let config = ParseConfig {
+            stringify_numbers: true,
+            ..ParseConfig::default()
+        };
+        let parsed = parse_with_config(
+            &SYSTEM_PROGRAM_ID,
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+            &config,
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.parsed["info"]["lamports"],
+            json!("18446744073709551615")
+        );
+        assert_eq!(parsed.parsed["info"]["source"], json!(from.to_string()));
+
+        let mut value = json!({"slots": [1, 2], "nested": {"n": -3, "ratio": 0.5, "flag": true}});
+        stringify_integers(&mut value);
+        assert_eq!(
+            value,
+            json!({"slots": ["1", "2"], "nested": {"n": "-3", "ratio": 0.5, "flag": true}})
+        );
+    }
+
+    #[test]
+    fn test_data_len() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(&[system_instruction::transfer(&from, &to, 42)], None);
+        let parsed = parse(
+            &SYSTEM_PROGRAM_ID,
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+        )
+        .unwrap();
+        assert_eq!(parsed.data_len, message.instructions[0].data.len());
+        assert_eq!(serde_json::to_value(&parsed).unwrap()["dataLen"], json!(12));
+
+        // Envelopes serialized before the field existed still deserialize
+        let mut value = serde_json::to_value(&parsed).unwrap();
+        value.as_object_mut().unwrap().remove("dataLen");
+        assert_eq!(
+            serde_json::from_value::<ParsedInstruction>(value)
+                .unwrap()
+                .data_len,
+            0
+        );
+    }
+
+    #[test]
+    fn test_content_hash() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(
+            &[
+                system_instruction::transfer(&from, &to, 42),
+                system_instruction::transfer(&from, &to, 42),
+                system_instruction::transfer(&from, &to, 43),
+            ],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let hashes: Vec<[u8; 32]> = message
+            .instructions
+            .iter()
+            .map(|instruction| {
+                parse_system(instruction, &account_keys)
+                    .unwrap()
+                    .content_hash()
+            })
+            .collect();
+        assert_eq!(hashes[0], hashes[1]);
+        assert_ne!(hashes[0], hashes[2]);
+    }
+
+    #[test]
+    fn test_shape_fingerprint() {
+        let keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let message = Message::new(
+            &[
+                system_instruction::transfer(&keys[0], &keys[1], 42),
+                system_instruction::transfer(&keys[2], &keys[3], 42),
+                system_instruction::transfer(&keys[0], &keys[1], 43),
+            ],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let parsed: Vec<ParsedInstructionEnum> = message
+            .instructions
+            .iter()
+            .map(|instruction| parse_system(instruction, &account_keys).unwrap())
+            .collect();
+        let fingerprints: Vec<u64> = parsed
+            .iter()
+            .zip(&message.instructions)
+            .map(|(parsed, instruction)| parsed.shape_fingerprint(instruction, &account_keys))
+            .collect();
+        assert_ne!(parsed[0].content_hash(), parsed[1].content_hash());
+        assert_eq!(fingerprints[0], fingerprints[1]);
+        assert_ne!(fingerprints[0], fingerprints[2]);
+
+        // The instruction type is part of the shape
+        let mut renamed = ParsedInstructionEnum {
+            instruction_type: "transferWithSeed".into(),
+            info: parsed[0].info.clone(),
+        };
+        let instruction = &message.instructions[0];
+        assert_ne!(
+            fingerprints[0],
+            renamed.shape_fingerprint(instruction, &account_keys)
+        );
+        renamed.instruction_type = "transfer".into();
+        assert_eq!(
+            fingerprints[0],
+            renamed.shape_fingerprint(instruction, &account_keys)
+        );
+
+        // Hashes and pubkey arguments aren't accounts, so are part of the shape
+        let vote_pubkey = Pubkey::new_unique();
+        let authorized_voter = Pubkey::new_unique();
+        let message = Message::new(
+            &[
+                vote_instruction::vote(
+                    &vote_pubkey,
+                    &authorized_voter,
+                    Vote::new(vec![1], Hash::new_unique()),
+                ),
+                vote_instruction::vote(
+                    &vote_pubkey,
+                    &authorized_voter,
+                    Vote::new(vec![1], Hash::new_unique()),
+                ),
+            ],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let fingerprints: Vec<u64> = message
+            .instructions
+            .iter()
+            .map(|instruction| {
+                parse_vote(instruction, &account_keys)
+                    .unwrap()
+                    .shape_fingerprint(instruction, &account_keys)
+            })
+            .collect();
+        assert_ne!(fingerprints[0], fingerprints[1]);
+
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(
+            &[
+                system_instruction::create_account(&from, &to, 42, 8, &Pubkey::new_unique()),
+                system_instruction::create_account(&from, &to, 42, 8, &Pubkey::new_unique()),
+            ],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let fingerprints: Vec<u64> = message
+            .instructions
+            .iter()
+            .map(|instruction| {
+                parse_system(instruction, &account_keys)
+                    .unwrap()
+                    .shape_fingerprint(instruction, &account_keys)
+            })
+            .collect();
+        assert_ne!(fingerprints[0], fingerprints[1]);
+    }
+
+    #[test]
+    fn test_parse_for_program_mismatch() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(&[system_instruction::transfer(&from, &to, 42)], None);
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        assert!(SystemParser
+            .parse_for_program(&SYSTEM_PROGRAM_ID, &message.instructions[0], &account_keys)
+            .is_ok());
+
+        // System transfer data handed to the vote parser, or routed under the vote program id
+        assert!(matches!(
+            VoteParser.parse_for_program(&SYSTEM_PROGRAM_ID, &message.instructions[0], &account_keys),
+            Err(ParseInstructionError::ProgramMismatch { expected, actual })
+                if expected == *VOTE_PROGRAM_ID && actual == *SYSTEM_PROGRAM_ID
+        ));
+        assert!(matches!(
+            SystemParser.parse_for_program(
+                &VOTE_PROGRAM_ID,
+                &message.instructions[0],
+                &account_keys
+            ),
+            Err(ParseInstructionError::ProgramMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_account_access_counts() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(&[system_instruction::transfer(&from, &to, 42)], None);
+        let config = ParseConfig {
+            message_header: Some(message.header),
+            ..ParseConfig::default()
+        };
+        let parsed = parse_with_config(
+            &SYSTEM_PROGRAM_ID,
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+            &config,
+        )
+        .unwrap();
+        assert_eq!(parsed.parsed["info"]["writableAccountCount"], json!(2));
+        assert_eq!(parsed.parsed["info"]["readonlyAccountCount"], json!(0));
+
+        // Readonly signer and unsigned accounts, with a repeated account counted once
+        let header = MessageHeader {
+            num_required_signatures: 2,
+            num_readonly_signed_accounts: 1,
+            num_readonly_unsigned_accounts: 1,
+        };
+        assert_eq!(count_account_access(&header, 4, &[0, 1, 2, 3, 3]), (2, 2));
+    }
+
+    #[test]
+    fn test_fee_payer_writable() {
+        let payer = Pubkey::new_unique();
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(
+            &[
+                system_instruction::transfer(&payer, &to, 42),
+                system_instruction::transfer(&from, &to, 42),
+            ],
+            Some(&payer),
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let config = ParseConfig::default().with_message_header(message.header);
+        let fee_payer_writable = |instruction: &CompiledInstruction| {
+            parse_with_config(&SYSTEM_PROGRAM_ID, instruction, &account_keys, &config)
+                .unwrap()
+                .parsed["info"]["feePayerWritable"]
+                .clone()
+        };
+        assert_eq!(fee_payer_writable(&message.instructions[0]), json!(true));
+        assert_eq!(fee_payer_writable(&message.instructions[1]), json!(false));
+
+        // Not emitted without the message header
+        let parsed = parse(&SYSTEM_PROGRAM_ID, &message.instructions[0], &account_keys).unwrap();
+        assert!(parsed.parsed["info"].get("feePayerWritable").is_none());
+    }
+
+    #[test]
+    fn test_parse_with_config_discriminant() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(&[system_instruction::transfer(&from, &to, 42)], None);
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let config = ParseConfig::default().with_discriminant(true);
+        let parsed = parse_with_config(
+            &SYSTEM_PROGRAM_ID,
+            &message.instructions[0],
+            &account_keys,
+            &config,
+        )
+        .unwrap();
+        assert_eq!(parsed.parsed["type"], json!("transfer"));
+        assert_eq!(parsed.parsed["info"]["discriminant"], json!(2));
+
+        let parsed = parse(&SYSTEM_PROGRAM_ID, &message.instructions[0], &account_keys).unwrap();
+        assert!(parsed.parsed["info"].get("discriminant").is_none());
+    }
+
+    #[test]
+    fn test_parse_config_builder() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(&[system_instruction::transfer(&from, &to, 42)], None);
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let config = ParseConfig::default()
+            .with_program_id(true)
+            .with_stringified_numbers(true);
+        assert_eq!(
+            config,
+            ParseConfig {
+                include_program_id: true,
+                stringify_numbers: true,
+                ..ParseConfig::default()
+            }
+        );
+        let parsed = parse_with_config(
+            &SYSTEM_PROGRAM_ID,
+            &message.instructions[0],
+            &account_keys,
+            &config,
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.parsed["info"]["programId"],
+            json!(SYSTEM_PROGRAM_ID.to_string())
+        );
+        assert_eq!(parsed.parsed["info"]["lamports"], json!("42"));
+
+        // Toggled back off, the default output is reproduced
+        let config = config
+            .with_program_id(false)
+            .with_stringified_numbers(false);
+        assert_eq!(
+            parse_with_config(
+                &SYSTEM_PROGRAM_ID,
+                &message.instructions[0],
+                &account_keys,
+                &config
+            )
+            .unwrap(),
+            parse(&SYSTEM_PROGRAM_ID, &message.instructions[0], &account_keys).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_lamports_to_sol_string() {
+        assert_eq!(lamports_to_sol_string(0), "0");
+        assert_eq!(lamports_to_sol_string(55), "0.000000055");
+        assert_eq!(lamports_to_sol_string(1_500_000_000), "1.5");
+        assert_eq!(lamports_to_sol_string(u64::MAX), "18446744073.709551615");
+    }
 }
@@ -0,0 +1,278 @@
+//! Parsers for Jito's MEV tip programs. Searchers tip by transferring lamports to the tip
+//! payment program's tip accounts; tips are later swept into per-validator tip distribution
+//! accounts and claimed by stakers against a merkle root.
+
+use {
+    crate::{
+        instruction_types,
+        parse_anchor::{anchor_discriminator, DISCRIMINATOR_LEN},
+        parse_instruction::{
+            check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+        },
+    },
+    borsh::BorshDeserialize,
+    serde_json::{json, Value},
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys, pubkey::Pubkey},
+    std::collections::HashSet,
+};
+
+pub mod tip_distribution {
+    solana_sdk::declare_id!("4R3gSG8BpU4t19KYj8CfnbtRpnT8gtk4dvTHxVRwc2r7");
+}
+
+pub mod tip_payment {
+    solana_sdk::declare_id!("T1pyyaTNZsKv2WcRAB8oVnk93mLJw2XzjtVYqCsaHqt");
+
+    // The tip accounts, PDAs of the program in seed order
+    solana_sdk::pubkeys!(
+        tip_accounts,
+        [
+            "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5",
+            "HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe",
+            "Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY",
+            "ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49",
+            "DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh",
+            "ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt",
+            "DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL",
+            "3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT",
+        ]
+    );
+}
+
+// The tip payment program's eight tip accounts, passed in order
+const NUM_TIP_ACCOUNTS: usize = 8;
+
+lazy_static! {
+    static ref INIT_TIP_DISTRIBUTION_ACCOUNT: [u8; DISCRIMINATOR_LEN] =
+        anchor_discriminator("init_tip_distribution_account");
+    static ref UPLOAD_MERKLE_ROOT: [u8; DISCRIMINATOR_LEN] =
+        anchor_discriminator("upload_merkle_root");
+    static ref CLAIM: [u8; DISCRIMINATOR_LEN] = anchor_discriminator("claim");
+    static ref CLAIM_TIPS: [u8; DISCRIMINATOR_LEN] = anchor_discriminator("claim_tips");
+    static ref CHANGE_TIP_RECEIVER: [u8; DISCRIMINATOR_LEN] =
+        anchor_discriminator("change_tip_receiver");
+    static ref TIP_ACCOUNTS: HashSet<Pubkey> = tip_payment::tip_accounts().into_iter().collect();
+}
+
+pub fn is_tip_account(pubkey: &Pubkey) -> bool {
+    TIP_ACCOUNTS.contains(pubkey)
+}
+
+/// Flags system transfers into a Jito tip account, i.e. tips to the block builder
+pub(crate) fn flag_tip_destination(value: &mut Value, destination: &Pubkey) {
+    if is_tip_account(destination) {
+        let map = value.as_object_mut().unwrap();
+        map.insert("destinationIsJitoTipAccount".to_string(), json!(true));
+    }
+}
+
+pub fn parse_jito_tip_distribution(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    match instruction.accounts.iter().max() {
+        Some(index) if (*index as usize) < account_keys.len() => {}
+        _ => {
+            // Runtime should prevent this from ever happening
+            return Err(ParseInstructionError::InstructionKeyMismatch(
+                ParsableProgram::JitoTipDistribution,
+            ));
+        }
+    }
+    let not_parsable =
+        || ParseInstructionError::InstructionNotParsable(ParsableProgram::JitoTipDistribution);
+    if instruction.data.len() < DISCRIMINATOR_LEN {
+        return Err(not_parsable());
+    }
+    let (discriminator, mut args) = instruction.data.split_at(DISCRIMINATOR_LEN);
+    let key = |i: usize| account_keys[instruction.accounts[i] as usize].to_string();
+    if discriminator == *INIT_TIP_DISTRIBUTION_ACCOUNT {
+        let (merkle_root_upload_authority, validator_commission_bps, _bump) =
+            <([u8; 32], u16, u8)>::deserialize(&mut args).map_err(|_| not_parsable())?;
+        check_num_tip_distribution_accounts(&instruction.accounts, 5)?;
+        Ok(ParsedInstructionEnum {
+            instruction_type:
+                instruction_types::jito_tip_distribution::INIT_TIP_DISTRIBUTION_ACCOUNT.into(),
+            info: json!({
+                "config": key(0),
+                "tipDistributionAccount": key(1),
+                "validatorVoteAccount": key(2),
+                "signer": key(3),
+                "systemProgram": key(4),
+                "merkleRootUploadAuthority":
+                    Pubkey::new_from_array(merkle_root_upload_authority).to_string(),
+                "validatorCommissionBps": validator_commission_bps,
+            }),
+        })
+    } else if discriminator == *UPLOAD_MERKLE_ROOT {
+        let (root, max_total_claim, max_num_nodes) =
+            <([u8; 32], u64, u64)>::deserialize(&mut args).map_err(|_| not_parsable())?;
+        check_num_tip_distribution_accounts(&instruction.accounts, 3)?;
+        Ok(ParsedInstructionEnum {
+            instruction_type: instruction_types::jito_tip_distribution::UPLOAD_MERKLE_ROOT.into(),
+            info: json!({
+                "config": key(0),
+                "tipDistributionAccount": key(1),
+                "merkleRootUploadAuthority": key(2),
+                "root": bs58::encode(root).into_string(),
+                "maxTotalClaim": max_total_claim,
+                "maxNumNodes": max_num_nodes,
+            }),
+        })
+    } else if discriminator == *CLAIM {
+        let (_bump, amount, proof) =
+            <(u8, u64, Vec<[u8; 32]>)>::deserialize(&mut args).map_err(|_| not_parsable())?;
+        check_num_tip_distribution_accounts(&instruction.accounts, 6)?;
+        Ok(ParsedInstructionEnum {
+            instruction_type: instruction_types::jito_tip_distribution::CLAIM.into(),
+            info: json!({
+                "config": key(0),
+                "tipDistributionAccount": key(1),
+                "claimStatus": key(2),
+                "claimant": key(3),
+                "payer": key(4),
+                "systemProgram": key(5),
+                "amount": amount,
+                "proofLength": proof.len(),
+            }),
+        })
+    } else {
+        Err(not_parsable())
+    }
+}
+
+pub fn parse_jito_tip_payment(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    match instruction.accounts.iter().max() {
+        Some(index) if (*index as usize) < account_keys.len() => {}
+        _ => {
+            // Runtime should prevent this from ever happening
+            return Err(ParseInstructionError::InstructionKeyMismatch(
+                ParsableProgram::JitoTipPayment,
+            ));
+        }
+    }
+    let not_parsable =
+        || ParseInstructionError::InstructionNotParsable(ParsableProgram::JitoTipPayment);
+    if instruction.data.len() < DISCRIMINATOR_LEN {
+        return Err(not_parsable());
+    }
+    let discriminator = &instruction.data[..DISCRIMINATOR_LEN];
+    let key = |i: usize| account_keys[instruction.accounts[i] as usize].to_string();
+    let tip_accounts =
+        |start: usize| -> Vec<String> { (start..start + NUM_TIP_ACCOUNTS).map(key).collect() };
+    if discriminator == *CLAIM_TIPS {
+        // Sweeps the tip accounts into the current tip receiver
+        check_num_tip_payment_accounts(&instruction.accounts, NUM_TIP_ACCOUNTS + 4)?;
+        Ok(ParsedInstructionEnum {
+            instruction_type: instruction_types::jito_tip_payment::CLAIM_TIPS.into(),
+            info: json!({
+                "config": key(0),
+                "tipAccounts": tip_accounts(1),
+                "tipReceiver": key(NUM_TIP_ACCOUNTS + 1),
+                "blockBuilder": key(NUM_TIP_ACCOUNTS + 2),
+                "signer": key(NUM_TIP_ACCOUNTS + 3),
+            }),
+        })
+    } else if discriminator == *CHANGE_TIP_RECEIVER {
+        check_num_tip_payment_accounts(&instruction.accounts, NUM_TIP_ACCOUNTS + 5)?;
+        Ok(ParsedInstructionEnum {
+            instruction_type: instruction_types::jito_tip_payment::CHANGE_TIP_RECEIVER.into(),
+            info: json!({
+                "config": key(0),
+                "oldTipReceiver": key(1),
+                "newTipReceiver": key(2),
+                "blockBuilder": key(3),
+                "tipAccounts": tip_accounts(4),
+                "signer": key(NUM_TIP_ACCOUNTS + 4),
+            }),
+        })
+    } else {
+        Err(not_parsable())
+    }
+}
+
+fn check_num_tip_distribution_accounts(
+    accounts: &[u8],
+    num: usize,
+) -> Result<(), ParseInstructionError> {
+    check_num_accounts(accounts, num, ParsableProgram::JitoTipDistribution)
+}
+
+fn check_num_tip_payment_accounts(
+    accounts: &[u8],
+    num: usize,
+) -> Result<(), ParseInstructionError> {
+    check_num_accounts(accounts, num, ParsableProgram::JitoTipPayment)
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, borsh::BorshSerialize};
+
+    #[test]
+    fn test_parse_jito_tip_distribution_claim() {
+        let keys: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        let mut data = CLAIM.to_vec();
+        data.extend(
+            (255u8, 1_000_000u64, vec![[1u8; 32], [2u8; 32]])
+                .try_to_vec()
+                .unwrap(),
+        );
+        let mut instruction = CompiledInstruction::new_from_raw_parts(0, data, (0..6).collect());
+        assert_eq!(
+            parse_jito_tip_distribution(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "claim".into(),
+                info: json!({
+                    "config": keys[0].to_string(),
+                    "tipDistributionAccount": keys[1].to_string(),
+                    "claimStatus": keys[2].to_string(),
+                    "claimant": keys[3].to_string(),
+                    "payer": keys[4].to_string(),
+                    "systemProgram": keys[5].to_string(),
+                    "amount": 1_000_000,
+                    "proofLength": 2,
+                }),
+            }
+        );
+        assert!(
+            parse_jito_tip_distribution(&instruction, &AccountKeys::new(&keys[0..5], None))
+                .is_err()
+        );
+        instruction.accounts.pop();
+        assert!(parse_jito_tip_distribution(&instruction, &AccountKeys::new(&keys, None)).is_err());
+
+        // Tips are claimed from the tip distribution program, not the tip payment program
+        instruction.accounts.push(5);
+        assert!(matches!(
+            parse_jito_tip_payment(&instruction, &AccountKeys::new(&keys, None)),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::JitoTipPayment
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_parse_jito_tip_payment_claim_tips() {
+        let keys: Vec<Pubkey> = (0..12).map(|_| Pubkey::new_unique()).collect();
+        let instruction =
+            CompiledInstruction::new_from_raw_parts(0, CLAIM_TIPS.to_vec(), (0..12).collect());
+        let tip_accounts: Vec<String> = keys[1..9].iter().map(ToString::to_string).collect();
+        assert_eq!(
+            parse_jito_tip_payment(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "claimTips".into(),
+                info: json!({
+                    "config": keys[0].to_string(),
+                    "tipAccounts": tip_accounts,
+                    "tipReceiver": keys[9].to_string(),
+                    "blockBuilder": keys[10].to_string(),
+                    "signer": keys[11].to_string(),
+                }),
+            }
+        );
+    }
+}
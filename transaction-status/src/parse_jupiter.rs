@@ -0,0 +1,58 @@
+//! Stub parser for the Jupiter v6 aggregator, showing how a custom [`InstructionParser`] can be
+//! registered with a [`ParserRegistry`](crate::parse_registry::ParserRegistry). Route arguments
+//! are not decoded; the instruction is only named from its Anchor discriminator.
+
+use {
+    crate::{
+        parse_anchor::{anchor_discriminator, DISCRIMINATOR_LEN},
+        parse_instruction::{InstructionParser, ParseInstructionError, ParsedInstructionEnum},
+    },
+    serde_json::json,
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys, pubkey::Pubkey},
+    std::collections::HashMap,
+};
+
+solana_sdk::declare_id!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+
+lazy_static! {
+    static ref INSTRUCTION_NAMES: HashMap<[u8; DISCRIMINATOR_LEN], &'static str> = [
+        ("route", "route"),
+        ("shared_accounts_route", "sharedAccountsRoute"),
+        ("exact_out_route", "exactOutRoute"),
+        (
+            "shared_accounts_exact_out_route",
+            "sharedAccountsExactOutRoute"
+        ),
+    ]
+    .into_iter()
+    .map(|(method, name)| (anchor_discriminator(method), name))
+    .collect();
+}
+
+pub struct JupiterParser;
+
+impl InstructionParser for JupiterParser {
+    fn program_id(&self) -> Pubkey {
+        id()
+    }
+
+    fn program_name(&self) -> String {
+        "jupiter".to_string()
+    }
+
+    fn parse(
+        &self,
+        instruction: &CompiledInstruction,
+        _account_keys: &AccountKeys,
+    ) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+        let name = instruction
+            .data
+            .get(..DISCRIMINATOR_LEN)
+            .and_then(|discriminator| INSTRUCTION_NAMES.get(discriminator))
+            .ok_or(ParseInstructionError::UnknownInstruction(id()))?;
+        Ok(ParsedInstructionEnum {
+            instruction_type: (*name).into(),
+            info: json!({}),
+        })
+    }
+}
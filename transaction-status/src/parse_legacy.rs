@@ -0,0 +1,136 @@
+//! Minimal parsers for the retired Budget and Exchange programs, which only appear in early
+//! ledger history. Their crates are no longer built, so only the instruction name is decoded and
+//! the data is passed through as base64.
+
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{ParsableProgram, ParseInstructionError, ParsedInstructionEnum},
+    },
+    serde_json::json,
+    solana_sdk::instruction::CompiledInstruction,
+};
+
+pub mod budget {
+    solana_sdk::declare_id!("Budget1111111111111111111111111111111111111");
+}
+
+pub mod exchange {
+    solana_sdk::declare_id!("Exchange11111111111111111111111111111111111");
+}
+
+// Variant order of the bincode-encoded `BudgetInstruction`
+const BUDGET_INSTRUCTIONS: &[&str] = &[
+    instruction_types::budget::INITIALIZE_ACCOUNT,
+    instruction_types::budget::APPLY_TIMESTAMP,
+    instruction_types::budget::APPLY_SIGNATURE,
+    instruction_types::budget::APPLY_ACCOUNT_DATA,
+];
+
+// Variant order of the bincode-encoded `ExchangeInstruction`
+const EXCHANGE_INSTRUCTIONS: &[&str] = &[
+    instruction_types::exchange::ACCOUNT_REQUEST,
+    instruction_types::exchange::TRANSFER_REQUEST,
+    instruction_types::exchange::ORDER_REQUEST,
+    instruction_types::exchange::ORDER_CANCELLATION,
+    instruction_types::exchange::SWAP_REQUEST,
+];
+
+pub fn parse_budget(
+    instruction: &CompiledInstruction,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    parse_legacy(instruction, BUDGET_INSTRUCTIONS, ParsableProgram::Budget)
+}
+
+pub fn parse_exchange(
+    instruction: &CompiledInstruction,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    parse_legacy(
+        instruction,
+        EXCHANGE_INSTRUCTIONS,
+        ParsableProgram::Exchange,
+    )
+}
+
+fn parse_legacy(
+    instruction: &CompiledInstruction,
+    instruction_names: &[&'static str],
+    program: ParsableProgram,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    let variant = instruction
+        .data
+        .get(..4)
+        .map(|tag| u32::from_le_bytes(tag.try_into().unwrap()) as usize);
+    let instruction_type = variant
+        .and_then(|variant| instruction_names.get(variant))
+        .ok_or(ParseInstructionError::InstructionNotParsable(program))?;
+    Ok(ParsedInstructionEnum {
+        instruction_type: (*instruction_type).into(),
+        info: json!({
+            "data": base64::encode(&instruction.data),
+        }),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_budget() {
+        // ApplySignature carries no payload
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![2, 0, 0, 0], vec![0, 1]);
+        assert_eq!(
+            parse_budget(&instruction).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "applySignature".into(),
+                info: json!({
+                    "data": "AgAAAA==",
+                }),
+            }
+        );
+
+        // ApplyTimestamp followed by an RFC 3339 string
+        let mut data = vec![1, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0];
+        data.extend_from_slice(b"2018-12-01T00:00:00+00:00");
+        let instruction = CompiledInstruction::new_from_raw_parts(0, data.clone(), vec![0, 1]);
+        assert_eq!(
+            parse_budget(&instruction).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "applyTimestamp".into(),
+                info: json!({
+                    "data": base64::encode(&data),
+                }),
+            }
+        );
+
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![4, 0, 0, 0], vec![]);
+        assert!(parse_budget(&instruction).is_err());
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![2, 0], vec![]);
+        assert!(parse_budget(&instruction).is_err());
+    }
+
+    #[test]
+    fn test_parse_exchange() {
+        // TransferRequest(Token::B, 1_000)
+        let data = vec![1, 0, 0, 0, 1, 0, 0, 0, 232, 3, 0, 0, 0, 0, 0, 0];
+        let instruction = CompiledInstruction::new_from_raw_parts(0, data.clone(), vec![0, 1, 2]);
+        assert_eq!(
+            parse_exchange(&instruction).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "transferRequest".into(),
+                info: json!({
+                    "data": base64::encode(&data),
+                }),
+            }
+        );
+
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![5, 0, 0, 0], vec![]);
+        assert!(matches!(
+            parse_exchange(&instruction),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::Exchange
+            ))
+        ));
+    }
+}
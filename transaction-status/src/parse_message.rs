@@ -0,0 +1,347 @@
+//! Message-level helpers built on the instruction dispatcher: one-line summaries, lazy
+//! iteration over a message's instructions, and account key resolution for v0 messages.
+
+use {
+    crate::parse_instruction::{parse, ParseInstructionError, ParsedInstruction},
+    solana_sdk::{
+        address_lookup_table_account::AddressLookupTableAccount,
+        message::{
+            v0::{self, LoadedAddresses},
+            AccountKeys, Message, VersionedMessage,
+        },
+        pubkey::Pubkey,
+    },
+};
+
+/// Summarizes every instruction of `message` on its own line, see [`ParsedInstruction::summary`]
+pub fn summarize_message(message: &Message) -> String {
+    let account_keys = AccountKeys::new(&message.account_keys, None);
+    message
+        .instructions
+        .iter()
+        .map(|instruction| {
+            match account_keys.get(instruction.program_id_index as usize) {
+                Some(program_id) => match parse(program_id, instruction, &account_keys) {
+                    Ok(parsed) => parsed.summary(),
+                    Err(_) => format!("{}: unknown ({} bytes)", program_id, instruction.data.len()),
+                },
+                // Unsanitized messages may index past the account keys
+                None => format!(
+                    "program #{}: unknown ({} bytes)",
+                    instruction.program_id_index,
+                    instruction.data.len()
+                ),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parses the instructions of `message` in order, each only as the iterator reaches it, so huge
+/// transactions need not be parsed or held in memory all at once
+pub fn parse_instructions_iter(
+    message: &Message,
+) -> impl Iterator<Item = Result<ParsedInstruction, ParseInstructionError>> + '_ {
+    message.instructions.iter().map(|instruction| {
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let program_id = account_keys
+            .get(instruction.program_id_index as usize)
+            .ok_or(ParseInstructionError::ProgramNotParsable)?;
+        parse(program_id, instruction, &account_keys)
+    })
+}
+
+/// Parses the instruction at `index` of a legacy or v0 message. Account keys resolve in
+/// canonical order: static keys, then loaded writable, then loaded readonly addresses. `loaded`
+/// is ignored for legacy messages.
+pub fn parse_versioned(
+    message: &VersionedMessage,
+    loaded: &LoadedAddresses,
+    index: usize,
+) -> Result<ParsedInstruction, ParseInstructionError> {
+    let instruction = message
+        .instructions()
+        .get(index)
+        .ok_or(ParseInstructionError::InstructionIndexOutOfBounds(index))?;
+    let account_keys = match message {
+        VersionedMessage::Legacy(message) => AccountKeys::new(&message.account_keys, None),
+        VersionedMessage::V0(message) => AccountKeys::new(&message.account_keys, Some(loaded)),
+    };
+    let program_id = account_keys
+        .get(instruction.program_id_index as usize)
+        .ok_or(ParseInstructionError::ProgramNotParsable)?;
+    parse(program_id, instruction, &account_keys)
+}
+
+/// Expands the account keys of a v0 message from the given lookup table accounts, in canonical
+/// order: static keys, then every table's writable addresses, then every table's readonly
+/// addresses. The result can be wrapped in [`AccountKeys`] and handed to any parser.
+pub fn resolve_account_keys(
+    message: &v0::Message,
+    lookup_tables: &[AddressLookupTableAccount],
+) -> Result<Vec<Pubkey>, ParseInstructionError> {
+    let mut loaded = LoadedAddresses::default();
+    for lookup in &message.address_table_lookups {
+        let table = lookup_tables
+            .iter()
+            .find(|table| table.key == lookup.account_key)
+            .ok_or(ParseInstructionError::LookupTableNotFound(
+                lookup.account_key,
+            ))?;
+        let address = |index: &u8| {
+            table.addresses.get(*index as usize).copied().ok_or(
+                ParseInstructionError::LookupTableIndexOutOfBounds {
+                    table: table.key,
+                    index: *index,
+                },
+            )
+        };
+        for index in &lookup.writable_indexes {
+            loaded.writable.push(address(index)?);
+        }
+        for index in &lookup.readonly_indexes {
+            loaded.readonly.push(address(index)?);
+        }
+    }
+    Ok(message
+        .account_keys
+        .iter()
+        .chain(loaded.writable.iter())
+        .chain(loaded.readonly.iter())
+        .copied()
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::extract_memos::spl_memo_id_v3,
+        serde_json::json,
+        solana_sdk::{
+            instruction::{CompiledInstruction, Instruction},
+            message::{v0::MessageAddressTableLookup, MessageHeader},
+            system_instruction::{self, SystemInstruction},
+            system_program,
+        },
+    };
+
+    #[test]
+    fn test_parse_versioned() {
+        let from_pubkey = Pubkey::new_unique();
+        let to_pubkey = Pubkey::new_unique();
+        let transfer = system_instruction::transfer(&from_pubkey, &to_pubkey, 42);
+        let expected = ParsedInstruction {
+            program: "system".to_string(),
+            program_id: system_program::id().to_string(),
+            parsed: json!({
+                "type": "transfer",
+                "info": {
+                    "source": from_pubkey.to_string(),
+                    "destination": to_pubkey.to_string(),
+                    "lamports": 42,
+                    "sol": "0.000000042",
+                },
+            }),
+            data_len: 12,
+            instruction_index: None,
+            account_keys: None,
+        };
+
+        let legacy = VersionedMessage::Legacy(Message::new(&[transfer], None));
+        assert_eq!(
+            parse_versioned(&legacy, &LoadedAddresses::default(), 0).unwrap(),
+            expected
+        );
+        assert!(matches!(
+            parse_versioned(&legacy, &LoadedAddresses::default(), 1),
+            Err(ParseInstructionError::InstructionIndexOutOfBounds(1))
+        ));
+
+        // Destination is resolved from a lookup table
+        let v0_message = VersionedMessage::V0(v0::Message {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![from_pubkey, system_program::id()],
+            instructions: vec![CompiledInstruction::new_from_raw_parts(
+                1,
+                bincode::serialize(&system_instruction::SystemInstruction::Transfer {
+                    lamports: 42,
+                })
+                .unwrap(),
+                vec![0, 2],
+            )],
+            ..v0::Message::default()
+        });
+        let loaded = LoadedAddresses {
+            writable: vec![to_pubkey],
+            readonly: vec![],
+        };
+        assert_eq!(parse_versioned(&v0_message, &loaded, 0).unwrap(), expected);
+        assert!(parse_versioned(&v0_message, &LoadedAddresses::default(), 0).is_err());
+    }
+
+    #[test]
+    fn test_resolve_account_keys() {
+        let payer = Pubkey::new_unique();
+        let table_a = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: (0..3).map(|_| Pubkey::new_unique()).collect(),
+        };
+        let table_b = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: vec![Pubkey::new_unique(), system_program::id()],
+        };
+        // Transfers from the payer to table A's address 2, with the destination and the system
+        // program both looked up
+        let mut message = v0::Message {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 0,
+            },
+            account_keys: vec![payer],
+            instructions: vec![CompiledInstruction::new_from_raw_parts(
+                4,
+                bincode::serialize(&SystemInstruction::Transfer { lamports: 42 }).unwrap(),
+                vec![0, 2],
+            )],
+            address_table_lookups: vec![
+                MessageAddressTableLookup {
+                    account_key: table_a.key,
+                    writable_indexes: vec![0, 2],
+                    readonly_indexes: vec![1],
+                },
+                MessageAddressTableLookup {
+                    account_key: table_b.key,
+                    writable_indexes: vec![],
+                    readonly_indexes: vec![1],
+                },
+            ],
+            ..v0::Message::default()
+        };
+        let lookup_tables = [table_a.clone(), table_b.clone()];
+        let keys = resolve_account_keys(&message, &lookup_tables).unwrap();
+        assert_eq!(
+            keys,
+            vec![
+                payer,
+                table_a.addresses[0],
+                table_a.addresses[2],
+                table_a.addresses[1],
+                system_program::id(),
+            ]
+        );
+        // Table order in the slice doesn't matter
+        assert_eq!(
+            resolve_account_keys(&message, &[table_b.clone(), table_a.clone()]).unwrap(),
+            keys
+        );
+
+        let instruction = &message.instructions[0];
+        let parsed = parse(
+            &keys[instruction.program_id_index as usize],
+            instruction,
+            &AccountKeys::new(&keys, None),
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.parsed["info"]["destination"],
+            json!(table_a.addresses[2].to_string())
+        );
+
+        assert!(matches!(
+            resolve_account_keys(&message, &lookup_tables[..1]),
+            Err(ParseInstructionError::LookupTableNotFound(key)) if key == table_b.key
+        ));
+        message.address_table_lookups[1].readonly_indexes.push(2);
+        assert!(matches!(
+            resolve_account_keys(&message, &lookup_tables),
+            Err(ParseInstructionError::LookupTableIndexOutOfBounds { table, index: 2 })
+                if table == table_b.key
+        ));
+    }
+
+    #[test]
+    fn test_parse_instructions_iter() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let message = Message::new(
+            &[
+                system_instruction::transfer(&from, &to, 42),
+                Instruction::new_with_bytes(Pubkey::new_unique(), &[1, 2, 3], vec![]),
+            ],
+            None,
+        );
+        let parsed: Vec<_> = parse_instructions_iter(&message).take(1).collect();
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(
+            parsed[0].as_ref().unwrap().parsed["type"],
+            json!("transfer")
+        );
+
+        let mut iter = parse_instructions_iter(&message).skip(1);
+        assert!(matches!(
+            iter.next(),
+            Some(Err(ParseInstructionError::ProgramNotParsable))
+        ));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_summarize_message() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let unknown_program_id = Pubkey::new_unique();
+        let mut message = Message::new(
+            &[
+                system_instruction::transfer(&from, &to, 42),
+                Instruction::new_with_bytes(unknown_program_id, &[1, 2, 3], vec![]),
+            ],
+            Some(&from),
+        );
+        assert_eq!(
+            summarize_message(&message),
+            format!(
+                "system: transfer\n{}: unknown (3 bytes)",
+                unknown_program_id
+            )
+        );
+
+        message.instructions[1].program_id_index = message.account_keys.len() as u8;
+        assert_eq!(
+            summarize_message(&message),
+            format!(
+                "system: transfer\nprogram #{}: unknown (3 bytes)",
+                message.account_keys.len()
+            )
+        );
+    }
+
+    #[test]
+    fn test_summarize_message_multi_line_memo() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        // A memo must not be able to pass itself off as further instructions
+        let message = Message::new(
+            &[
+                Instruction::new_with_bytes(
+                    spl_memo_id_v3(),
+                    b"thanks\nsystem: transfer\r\n",
+                    vec![],
+                ),
+                system_instruction::transfer(&from, &to, 42),
+            ],
+            Some(&from),
+        );
+        let summary = summarize_message(&message);
+        assert_eq!(
+            summary,
+            "spl-memo: thanks\\nsystem: transfer\\r\\n\nsystem: transfer"
+        );
+        assert_eq!(summary.lines().count(), message.instructions.len());
+    }
+}
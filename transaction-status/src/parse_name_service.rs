@@ -0,0 +1,215 @@
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+        },
+    },
+    borsh::BorshDeserialize,
+    serde_json::json,
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys, pubkey::Pubkey},
+};
+
+solana_sdk::declare_id!("namesLPneVptA9Z5rqUDD9tMTWEJwofgaYwp8cawRkX");
+
+const CREATE: u8 = 0;
+const UPDATE: u8 = 1;
+const TRANSFER: u8 = 2;
+const DELETE: u8 = 3;
+
+pub fn parse_name_service(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    match instruction.accounts.iter().max() {
+        Some(index) if (*index as usize) < account_keys.len() => {}
+        _ => {
+            // Runtime should prevent this from ever happening
+            return Err(ParseInstructionError::InstructionKeyMismatch(
+                ParsableProgram::SplNameService,
+            ));
+        }
+    }
+    let not_parsable =
+        || ParseInstructionError::InstructionNotParsable(ParsableProgram::SplNameService);
+    let (tag, data) = instruction.data.split_first().ok_or_else(not_parsable)?;
+    let key = |i: usize| account_keys[instruction.accounts[i] as usize].to_string();
+    match *tag {
+        CREATE => {
+            let (hashed_name, lamports, space) =
+                <(Vec<u8>, u64, u32)>::try_from_slice(data).map_err(|_| not_parsable())?;
+            check_num_name_service_accounts(&instruction.accounts, 6)?;
+            let mut value = json!({
+                "systemProgram": key(0),
+                "payer": key(1),
+                "nameAccount": key(2),
+                "nameOwner": key(3),
+                "nameClass": key(4),
+                "parentName": key(5),
+                "hashedName": base64::encode(hashed_name),
+                "lamports": lamports,
+                "space": space,
+            });
+            if instruction.accounts.len() >= 7 {
+                let map = value.as_object_mut().unwrap();
+                map.insert("parentNameOwner".to_string(), json!(key(6)));
+            }
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::name_service::CREATE.into(),
+                info: value,
+            })
+        }
+        UPDATE => {
+            let (offset, name_data) =
+                <(u32, Vec<u8>)>::try_from_slice(data).map_err(|_| not_parsable())?;
+            check_num_name_service_accounts(&instruction.accounts, 2)?;
+            let mut value = json!({
+                "nameAccount": key(0),
+                "updateAuthority": key(1),
+                "offset": offset,
+                "length": name_data.len(),
+                "data": base64::encode(name_data),
+            });
+            if instruction.accounts.len() >= 3 {
+                let map = value.as_object_mut().unwrap();
+                map.insert("parentName".to_string(), json!(key(2)));
+            }
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::name_service::UPDATE.into(),
+                info: value,
+            })
+        }
+        TRANSFER => {
+            let new_owner = <[u8; 32]>::try_from_slice(data).map_err(|_| not_parsable())?;
+            check_num_name_service_accounts(&instruction.accounts, 2)?;
+            let mut value = json!({
+                "nameAccount": key(0),
+                "nameOwner": key(1),
+                "newOwner": Pubkey::new_from_array(new_owner).to_string(),
+            });
+            if instruction.accounts.len() >= 3 {
+                let map = value.as_object_mut().unwrap();
+                map.insert("nameClass".to_string(), json!(key(2)));
+            }
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::name_service::TRANSFER.into(),
+                info: value,
+            })
+        }
+        DELETE => {
+            if !data.is_empty() {
+                return Err(not_parsable());
+            }
+            check_num_name_service_accounts(&instruction.accounts, 3)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::name_service::DELETE.into(),
+                info: json!({
+                    "nameAccount": key(0),
+                    "nameOwner": key(1),
+                    "refundTarget": key(2),
+                }),
+            })
+        }
+        _ => Err(not_parsable()),
+    }
+}
+
+fn check_num_name_service_accounts(
+    accounts: &[u8],
+    num: usize,
+) -> Result<(), ParseInstructionError> {
+    check_num_accounts(accounts, num, ParsableProgram::SplNameService)
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, borsh::BorshSerialize};
+
+    fn instruction_data(tag: u8, args: impl BorshSerialize) -> Vec<u8> {
+        let mut data = vec![tag];
+        data.extend(args.try_to_vec().unwrap());
+        data
+    }
+
+    #[test]
+    fn test_parse_name_service_create() {
+        let keys: Vec<Pubkey> = (0..7).map(|_| Pubkey::new_unique()).collect();
+        let mut instruction = CompiledInstruction::new_from_raw_parts(
+            0,
+            instruction_data(CREATE, (vec![1u8, 2, 3], 2_000_000u64, 96u32)),
+            (0..6).collect(),
+        );
+        let expected_info = json!({
+            "systemProgram": keys[0].to_string(),
+            "payer": keys[1].to_string(),
+            "nameAccount": keys[2].to_string(),
+            "nameOwner": keys[3].to_string(),
+            "nameClass": keys[4].to_string(),
+            "parentName": keys[5].to_string(),
+            "hashedName": "AQID",
+            "lamports": 2_000_000,
+            "space": 96,
+        });
+        assert_eq!(
+            parse_name_service(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "create".into(),
+                info: expected_info.clone(),
+            }
+        );
+
+        // With a signing parent name owner
+        instruction.accounts.push(6);
+        let mut expected_info = expected_info;
+        expected_info["parentNameOwner"] = json!(keys[6].to_string());
+        assert_eq!(
+            parse_name_service(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "create".into(),
+                info: expected_info,
+            }
+        );
+
+        assert!(parse_name_service(&instruction, &AccountKeys::new(&keys[0..6], None)).is_err());
+        instruction.accounts.truncate(5);
+        assert!(parse_name_service(&instruction, &AccountKeys::new(&keys, None)).is_err());
+    }
+
+    #[test]
+    fn test_parse_name_service_update() {
+        let keys: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let mut instruction = CompiledInstruction::new_from_raw_parts(
+            0,
+            instruction_data(UPDATE, (32u32, b"hello".to_vec())),
+            vec![0, 1],
+        );
+        assert_eq!(
+            parse_name_service(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "update".into(),
+                info: json!({
+                    "nameAccount": keys[0].to_string(),
+                    "updateAuthority": keys[1].to_string(),
+                    "offset": 32,
+                    "length": 5,
+                    "data": "aGVsbG8=",
+                }),
+            }
+        );
+
+        instruction.accounts.push(2);
+        assert_eq!(
+            parse_name_service(&instruction, &AccountKeys::new(&keys, None))
+                .unwrap()
+                .info["parentName"],
+            json!(keys[2].to_string())
+        );
+
+        // Truncated data
+        instruction.data.pop();
+        assert!(parse_name_service(&instruction, &AccountKeys::new(&keys, None)).is_err());
+        instruction.data = instruction_data(UPDATE, (32u32, b"hello".to_vec()));
+        instruction.accounts.truncate(1);
+        assert!(parse_name_service(&instruction, &AccountKeys::new(&keys, None)).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{ParsableProgram, ParseInstructionError, ParsedInstructionEnum},
+    },
+    serde_json::json,
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys},
+};
+
+// The program accepts any instruction, so transactions use it to lock accounts or to anchor data
+// in the ledger
+solana_sdk::declare_id!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+pub fn parse_noop(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    let accounts = instruction
+        .accounts
+        .iter()
+        .map(|index| {
+            account_keys
+                .get(*index as usize)
+                .map(ToString::to_string)
+                .ok_or(ParseInstructionError::InstructionKeyMismatch(
+                    ParsableProgram::Noop,
+                ))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(ParsedInstructionEnum {
+        instruction_type: instruction_types::noop::NOOP.into(),
+        info: json!({
+            "accounts": accounts,
+        }),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_sdk::pubkey::Pubkey};
+
+    #[test]
+    fn test_parse_noop() {
+        let keys = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut instruction = CompiledInstruction::new_from_raw_parts(0, vec![1, 2, 3], vec![1, 0]);
+        assert_eq!(
+            parse_noop(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "noop".into(),
+                info: json!({
+                    "accounts": [keys[1].to_string(), keys[0].to_string()],
+                }),
+            }
+        );
+
+        instruction.accounts.clear();
+        assert_eq!(
+            parse_noop(&instruction, &AccountKeys::new(&keys, None))
+                .unwrap()
+                .info,
+            json!({ "accounts": [] })
+        );
+
+        instruction.accounts.push(2);
+        assert!(matches!(
+            parse_noop(&instruction, &AccountKeys::new(&keys, None)),
+            Err(ParseInstructionError::InstructionKeyMismatch(
+                ParsableProgram::Noop
+            ))
+        ));
+    }
+}
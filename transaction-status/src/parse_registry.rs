@@ -0,0 +1,127 @@
+//! Runtime registration of parsers for programs this crate doesn't know about.
+//!
+//! A custom parser implements [`InstructionParser`] for a single program id and is added to a
+//! [`ParserRegistry`], which consults registered parsers before the built-in ones:
+//!
+//! ```
+//! use solana_transaction_status::{parse_jupiter::JupiterParser, parse_registry::ParserRegistry};
+//!
+//! let mut registry = ParserRegistry::default();
+//! registry.register(Box::new(JupiterParser));
+//! ```
+//!
+//! See [`JupiterParser`](crate::parse_jupiter::JupiterParser) for a stub that only names
+//! instructions from their Anchor discriminator.
+
+use {
+    crate::parse_instruction::{
+        parse, InstructionParser, ParseInstructionError, ParsedInstruction,
+    },
+    serde_json::Value,
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys, pubkey::Pubkey},
+    std::collections::HashMap,
+};
+
+#[derive(Default)]
+pub struct ParserRegistry {
+    parsers: HashMap<Pubkey, Box<dyn InstructionParser + Send + Sync>>,
+}
+
+impl ParserRegistry {
+    /// Registers `parser` for its program id, replacing any parser registered before it
+    pub fn register(&mut self, parser: Box<dyn InstructionParser + Send + Sync>) {
+        self.parsers.insert(parser.program_id(), parser);
+    }
+
+    pub fn is_registered(&self, program_id: &Pubkey) -> bool {
+        self.parsers.contains_key(program_id)
+    }
+
+    /// Parses with the parser registered for `program_id`, falling back to the built-in parsers
+    pub fn parse(
+        &self,
+        program_id: &Pubkey,
+        instruction: &CompiledInstruction,
+        account_keys: &AccountKeys,
+    ) -> Result<ParsedInstruction, ParseInstructionError> {
+        let parser = match self.parsers.get(program_id) {
+            Some(parser) => parser,
+            None => return parse(program_id, instruction, account_keys),
+        };
+        let parsed: Value = serde_json::to_value(parser.parse_for_program(
+            program_id,
+            instruction,
+            account_keys,
+        )?)?;
+        Ok(ParsedInstruction {
+            program: parser.program_name(),
+            program_id: program_id.to_string(),
+            parsed,
+            data_len: instruction.data.len(),
+            instruction_index: None,
+            account_keys: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::parse_jupiter::{self, JupiterParser},
+        serde_json::json,
+        solana_sdk::{hash::hash, message::Message, system_instruction, system_program},
+    };
+
+    #[test]
+    fn test_registry_discriminant_named_parser() {
+        let mut registry = ParserRegistry::default();
+        assert!(!registry.is_registered(&parse_jupiter::id()));
+        registry.register(Box::new(JupiterParser));
+        assert!(registry.is_registered(&parse_jupiter::id()));
+
+        let keys = [parse_jupiter::id(), Pubkey::new_unique()];
+        let mut data = hash(b"global:shared_accounts_route").to_bytes()[..8].to_vec();
+        data.extend_from_slice(&[1, 2, 3]);
+        let instruction = CompiledInstruction::new_from_raw_parts(0, data, vec![1]);
+        assert_eq!(
+            registry
+                .parse(&keys[0], &instruction, &AccountKeys::new(&keys, None))
+                .unwrap(),
+            ParsedInstruction {
+                program: "jupiter".to_string(),
+                program_id: keys[0].to_string(),
+                parsed: json!({
+                    "type": "sharedAccountsRoute",
+                    "info": {},
+                }),
+                data_len: 11,
+                instruction_index: None,
+                account_keys: None,
+            }
+        );
+
+        // Unregistered programs still reach the built-in parsers
+        let message = Message::new(
+            &[system_instruction::assign(&keys[1], &Pubkey::new_unique())],
+            None,
+        );
+        assert_eq!(
+            registry
+                .parse(
+                    &system_program::id(),
+                    &message.instructions[0],
+                    &AccountKeys::new(&message.account_keys, None),
+                )
+                .unwrap()
+                .program,
+            "system"
+        );
+
+        // Unknown discriminator
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![0; 8], vec![1]);
+        assert!(registry
+            .parse(&keys[0], &instruction, &AccountKeys::new(&keys, None))
+            .is_err());
+    }
+}
@@ -0,0 +1,110 @@
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{ParsableProgram, ParseInstructionError, ParsedInstructionEnum},
+    },
+    serde_json::{json, Value},
+    solana_sdk::instruction::CompiledInstruction,
+};
+
+// The SDK predates the secp256r1 precompile, so its id and header layout are mirrored here
+solana_sdk::declare_id!("Secp256r1SigVerify1111111111111111111111111");
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+
+pub fn parse_secp256r1(
+    instruction: &CompiledInstruction,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    let not_parsable = || ParseInstructionError::InstructionNotParsable(ParsableProgram::Secp256r1);
+    let data = &instruction.data;
+    let num_signatures = *data.first().ok_or_else(not_parsable)? as usize;
+    let offsets_end = SIGNATURE_OFFSETS_START + num_signatures * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+    if data.len() < offsets_end {
+        return Err(not_parsable());
+    }
+    let signatures: Vec<Value> = data[SIGNATURE_OFFSETS_START..offsets_end]
+        .chunks(SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+        .map(|offsets| {
+            let read_u16 = |i: usize| u16::from_le_bytes([offsets[i], offsets[i + 1]]);
+            json!({
+                "signatureOffset": read_u16(0),
+                "signatureInstructionIndex": read_u16(2),
+                "publicKeyOffset": read_u16(4),
+                "publicKeyInstructionIndex": read_u16(6),
+                "messageDataOffset": read_u16(8),
+                "messageDataSize": read_u16(10),
+                "messageInstructionIndex": read_u16(12),
+            })
+        })
+        .collect();
+    Ok(ParsedInstructionEnum {
+        instruction_type: instruction_types::secp256r1::VERIFY.into(),
+        info: json!({
+            "signatures": signatures,
+        }),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, crate::parse_instruction::parse, solana_sdk::message::AccountKeys};
+
+    #[test]
+    fn test_parse_secp256r1() {
+        let mut data = vec![2, 0];
+        for offsets in [
+            [16u16, 0xffff, 80, 0xffff, 113, 32, 0xffff],
+            [1, 2, 3, 4, 5, 6, 7],
+        ] {
+            for offset in offsets {
+                data.extend_from_slice(&offset.to_le_bytes());
+            }
+        }
+        // Signature, public key and message data follow the header
+        data.extend_from_slice(&[0; 64]);
+        let instruction = CompiledInstruction::new_from_raw_parts(0, data, vec![]);
+        assert_eq!(
+            parse_secp256r1(&instruction).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "verify".into(),
+                info: json!({
+                    "signatures": [
+                        {
+                            "signatureOffset": 16,
+                            "signatureInstructionIndex": u16::MAX,
+                            "publicKeyOffset": 80,
+                            "publicKeyInstructionIndex": u16::MAX,
+                            "messageDataOffset": 113,
+                            "messageDataSize": 32,
+                            "messageInstructionIndex": u16::MAX,
+                        },
+                        {
+                            "signatureOffset": 1,
+                            "signatureInstructionIndex": 2,
+                            "publicKeyOffset": 3,
+                            "publicKeyInstructionIndex": 4,
+                            "messageDataOffset": 5,
+                            "messageDataSize": 6,
+                            "messageInstructionIndex": 7,
+                        },
+                    ],
+                }),
+            }
+        );
+
+        assert_eq!(
+            parse(&id(), &instruction, &AccountKeys::new(&[], None))
+                .unwrap()
+                .program,
+            "secp256r1"
+        );
+
+        // Header claims two entries but only holds one and a half
+        let mut truncated = instruction;
+        truncated.data.truncate(SIGNATURE_OFFSETS_START + 21);
+        assert!(parse_secp256r1(&truncated).is_err());
+        truncated.data.clear();
+        assert!(parse_secp256r1(&truncated).is_err());
+    }
+}
@@ -1,18 +1,32 @@
 use {
-    crate::parse_instruction::{
-        check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            check_num_accounts, flag_incinerator_destination, lamports_to_sol_string,
+            ParsableProgram, ParseConfig, ParseInstructionError, ParsedInstructionEnum,
+        },
     },
     bincode::deserialize,
     serde_json::{json, Map, Value},
     solana_sdk::{
-        instruction::CompiledInstruction, message::AccountKeys,
-        stake::instruction::StakeInstruction,
+        clock::Epoch,
+        instruction::CompiledInstruction,
+        message::AccountKeys,
+        stake::{instruction::StakeInstruction, state::StakeAuthorize},
     },
 };
 
 pub fn parse_stake(
     instruction: &CompiledInstruction,
     account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    parse_stake_with_config(instruction, account_keys, &ParseConfig::default())
+}
+
+pub fn parse_stake_with_config(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+    config: &ParseConfig,
 ) -> Result<ParsedInstructionEnum, ParseInstructionError> {
     let stake_instruction: StakeInstruction = deserialize(&instruction.data)
         .map_err(|_| ParseInstructionError::InstructionNotParsable(ParsableProgram::Stake))?;
@@ -32,19 +46,22 @@ pub fn parse_stake(
                 "staker": authorized.staker.to_string(),
                 "withdrawer": authorized.withdrawer.to_string(),
             });
+            let lockup_epoch = lockup.epoch;
             let lockup = json!({
                 "unixTimestamp": lockup.unix_timestamp,
                 "epoch": lockup.epoch,
                 "custodian": lockup.custodian.to_string(),
             });
+            let mut value = json!({
+                "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                "rentSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
+                "authorized": authorized,
+                "lockup": lockup,
+            });
+            insert_lockup_epoch_active(&mut value, Some(lockup_epoch), config);
             Ok(ParsedInstructionEnum {
-                instruction_type: "initialize".to_string(),
-                info: json!({
-                    "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "rentSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "authorized": authorized,
-                    "lockup": lockup,
-                }),
+                instruction_type: instruction_types::stake::INITIALIZE.into(),
+                info: value,
             })
         }
         StakeInstruction::Authorize(new_authorized, authority_type) => {
@@ -55,6 +72,7 @@ pub fn parse_stake(
                 "authority": account_keys[instruction.accounts[2] as usize].to_string(),
                 "newAuthority": new_authorized.to_string(),
                 "authorityType": authority_type,
+                "authorityTypeCode": stake_authorize_code(authority_type),
             });
             let map = value.as_object_mut().unwrap();
             if instruction.accounts.len() >= 4 {
@@ -64,28 +82,37 @@ pub fn parse_stake(
                 );
             }
             Ok(ParsedInstructionEnum {
-                instruction_type: "authorize".to_string(),
+                instruction_type: instruction_types::stake::AUTHORIZE.into(),
                 info: value,
             })
         }
         StakeInstruction::DelegateStake => {
             check_num_stake_accounts(&instruction.accounts, 6)?;
+            let vote_account = &account_keys[instruction.accounts[1] as usize];
+            let mut value = json!({
+                "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                "voteAccount": vote_account.to_string(),
+                "clockSysvar": account_keys[instruction.accounts[2] as usize].to_string(),
+                "stakeHistorySysvar": account_keys[instruction.accounts[3] as usize].to_string(),
+                "stakeConfigAccount": account_keys[instruction.accounts[4] as usize].to_string(),
+                "stakeAuthority": account_keys[instruction.accounts[5] as usize].to_string(),
+            });
+            let map = value.as_object_mut().unwrap();
+            if let Some(owner) = config.account_owners.get(vote_account) {
+                map.insert(
+                    "voteAccountValid".to_string(),
+                    json!(*owner == solana_vote_program::id()),
+                );
+            }
             Ok(ParsedInstructionEnum {
-                instruction_type: "delegate".to_string(),
-                info: json!({
-                    "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "voteAccount": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "clockSysvar": account_keys[instruction.accounts[2] as usize].to_string(),
-                    "stakeHistorySysvar": account_keys[instruction.accounts[3] as usize].to_string(),
-                    "stakeConfigAccount": account_keys[instruction.accounts[4] as usize].to_string(),
-                    "stakeAuthority": account_keys[instruction.accounts[5] as usize].to_string(),
-                }),
+                instruction_type: instruction_types::stake::DELEGATE.into(),
+                info: value,
             })
         }
         StakeInstruction::Split(lamports) => {
             check_num_stake_accounts(&instruction.accounts, 3)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "split".to_string(),
+                instruction_type: instruction_types::stake::SPLIT.into(),
                 info: json!({
                     "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "newSplitAccount": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -96,14 +123,17 @@ pub fn parse_stake(
         }
         StakeInstruction::Withdraw(lamports) => {
             check_num_stake_accounts(&instruction.accounts, 5)?;
+            let destination = &account_keys[instruction.accounts[1] as usize];
             let mut value = json!({
                 "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
-                "destination": account_keys[instruction.accounts[1] as usize].to_string(),
+                "destination": destination.to_string(),
                 "clockSysvar": account_keys[instruction.accounts[2] as usize].to_string(),
                 "stakeHistorySysvar": account_keys[instruction.accounts[3] as usize].to_string(),
                 "withdrawAuthority": account_keys[instruction.accounts[4] as usize].to_string(),
                 "lamports": lamports,
+                "sol": lamports_to_sol_string(lamports),
             });
+            flag_incinerator_destination(&mut value, destination);
             let map = value.as_object_mut().unwrap();
             if instruction.accounts.len() >= 6 {
                 map.insert(
@@ -112,14 +142,14 @@ pub fn parse_stake(
                 );
             }
             Ok(ParsedInstructionEnum {
-                instruction_type: "withdraw".to_string(),
+                instruction_type: instruction_types::stake::WITHDRAW.into(),
                 info: value,
             })
         }
         StakeInstruction::Deactivate => {
             check_num_stake_accounts(&instruction.accounts, 3)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "deactivate".to_string(),
+                instruction_type: instruction_types::stake::DEACTIVATE.into(),
                 info: json!({
                     "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "clockSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -139,19 +169,21 @@ pub fn parse_stake(
             if let Some(custodian) = lockup_args.custodian {
                 lockup_map.insert("custodian".to_string(), json!(custodian.to_string()));
             }
+            let mut value = json!({
+                "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                "custodian": account_keys[instruction.accounts[1] as usize].to_string(),
+                "lockup": lockup_map,
+            });
+            insert_lockup_epoch_active(&mut value, lockup_args.epoch, config);
             Ok(ParsedInstructionEnum {
-                instruction_type: "setLockup".to_string(),
-                info: json!({
-                    "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "custodian": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "lockup": lockup_map,
-                }),
+                instruction_type: instruction_types::stake::SET_LOCKUP.into(),
+                info: value,
             })
         }
         StakeInstruction::Merge => {
             check_num_stake_accounts(&instruction.accounts, 5)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "merge".to_string(),
+                instruction_type: instruction_types::stake::MERGE.into(),
                 info: json!({
                     "destination": account_keys[instruction.accounts[0] as usize].to_string(),
                     "source": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -168,6 +200,7 @@ pub fn parse_stake(
                     "authorityBase": account_keys[instruction.accounts[1] as usize].to_string(),
                     "newAuthorized": args.new_authorized_pubkey.to_string(),
                     "authorityType": args.stake_authorize,
+                    "authorityTypeCode": stake_authorize_code(args.stake_authorize),
                     "authoritySeed": args.authority_seed,
                     "authorityOwner": args.authority_owner.to_string(),
             });
@@ -185,14 +218,14 @@ pub fn parse_stake(
                 );
             }
             Ok(ParsedInstructionEnum {
-                instruction_type: "authorizeWithSeed".to_string(),
+                instruction_type: instruction_types::stake::AUTHORIZE_WITH_SEED.into(),
                 info: value,
             })
         }
         StakeInstruction::InitializeChecked => {
             check_num_stake_accounts(&instruction.accounts, 4)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "initializeChecked".to_string(),
+                instruction_type: instruction_types::stake::INITIALIZE_CHECKED.into(),
                 info: json!({
                     "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "rentSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -209,6 +242,7 @@ pub fn parse_stake(
                 "authority": account_keys[instruction.accounts[2] as usize].to_string(),
                 "newAuthority": account_keys[instruction.accounts[3] as usize].to_string(),
                 "authorityType": authority_type,
+                "authorityTypeCode": stake_authorize_code(authority_type),
             });
             let map = value.as_object_mut().unwrap();
             if instruction.accounts.len() >= 5 {
@@ -218,7 +252,7 @@ pub fn parse_stake(
                 );
             }
             Ok(ParsedInstructionEnum {
-                instruction_type: "authorizeChecked".to_string(),
+                instruction_type: instruction_types::stake::AUTHORIZE_CHECKED.into(),
                 info: value,
             })
         }
@@ -230,6 +264,7 @@ pub fn parse_stake(
                     "clockSysvar": account_keys[instruction.accounts[2] as usize].to_string(),
                     "newAuthorized": account_keys[instruction.accounts[3] as usize].to_string(),
                     "authorityType": args.stake_authorize,
+                    "authorityTypeCode": stake_authorize_code(args.stake_authorize),
                     "authoritySeed": args.authority_seed,
                     "authorityOwner": args.authority_owner.to_string(),
             });
@@ -241,7 +276,7 @@ pub fn parse_stake(
                 );
             }
             Ok(ParsedInstructionEnum {
-                instruction_type: "authorizeCheckedWithSeed".to_string(),
+                instruction_type: instruction_types::stake::AUTHORIZE_CHECKED_WITH_SEED.into(),
                 info: value,
             })
         }
@@ -260,23 +295,25 @@ pub fn parse_stake(
                     json!(account_keys[instruction.accounts[2] as usize].to_string()),
                 );
             }
+            let mut value = json!({
+                "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                "custodian": account_keys[instruction.accounts[1] as usize].to_string(),
+                "lockup": lockup_map,
+            });
+            insert_lockup_epoch_active(&mut value, lockup_args.epoch, config);
             Ok(ParsedInstructionEnum {
-                instruction_type: "setLockupChecked".to_string(),
-                info: json!({
-                    "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "custodian": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "lockup": lockup_map,
-                }),
+                instruction_type: instruction_types::stake::SET_LOCKUP_CHECKED.into(),
+                info: value,
             })
         }
         StakeInstruction::GetMinimumDelegation => Ok(ParsedInstructionEnum {
-            instruction_type: "getMinimumDelegation".to_string(),
+            instruction_type: instruction_types::stake::GET_MINIMUM_DELEGATION.into(),
             info: Value::default(),
         }),
         StakeInstruction::DeactivateDelinquent => {
             check_num_stake_accounts(&instruction.accounts, 3)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "deactivateDeactive".to_string(),
+                instruction_type: instruction_types::stake::DEACTIVATE_DELINQUENT.into(),
                 info: json!({
                     "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "voteAccount": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -287,6 +324,31 @@ pub fn parse_stake(
     }
 }
 
+// Pinned alongside the serialized name for consumers that key on numbers, matching the bincode
+// tag
+fn stake_authorize_code(authority_type: StakeAuthorize) -> u8 {
+    match authority_type {
+        StakeAuthorize::Staker => 0,
+        StakeAuthorize::Withdrawer => 1,
+    }
+}
+
+// Like `Lockup::is_in_force`, the epoch lockup holds until the current epoch reaches it. Added
+// when both the lockup epoch and the current epoch are known.
+fn insert_lockup_epoch_active(
+    value: &mut Value,
+    lockup_epoch: Option<Epoch>,
+    config: &ParseConfig,
+) {
+    if let (Some(lockup_epoch), Some(current_epoch)) = (lockup_epoch, config.current_epoch) {
+        let map = value.as_object_mut().unwrap();
+        map.insert(
+            "lockupEpochActive".to_string(),
+            json!(lockup_epoch > current_epoch),
+        );
+    }
+}
+
 fn check_num_stake_accounts(accounts: &[u8], num: usize) -> Result<(), ParseInstructionError> {
     check_num_accounts(accounts, num, ParsableProgram::Stake)
 }
@@ -301,12 +363,23 @@ mod test {
             stake::{
                 config,
                 instruction::{self, LockupArgs},
-                state::{Authorized, Lockup, StakeAuthorize},
+                state::{Authorized, Lockup},
             },
             sysvar,
         },
     };
 
+    #[test]
+    fn test_stake_authorize_code() {
+        assert_eq!(stake_authorize_code(StakeAuthorize::Staker), 0);
+        assert_eq!(stake_authorize_code(StakeAuthorize::Withdrawer), 1);
+        // Matches the bincode tag
+        assert_eq!(
+            bincode::serialize(&StakeAuthorize::Withdrawer).unwrap(),
+            vec![1, 0, 0, 0]
+        );
+    }
+
     #[test]
     fn test_parse_stake_initialize_ix() {
         let from_pubkey = Pubkey::new_unique();
@@ -337,7 +410,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initialize".to_string(),
+                instruction_type: "initialize".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "rentSysvar": sysvar::rent::ID.to_string(),
@@ -363,6 +436,98 @@ mod test {
         assert!(parse_stake(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
     }
 
+    #[test]
+    fn test_parse_stake_initialize_default_lockup() {
+        let stake_pubkey = Pubkey::new_unique();
+        let authorized = Authorized::auto(&Pubkey::new_unique());
+        let instructions = instruction::create_account(
+            &Pubkey::new_unique(),
+            &stake_pubkey,
+            &authorized,
+            &Lockup::default(),
+            55,
+        );
+        let message = Message::new(&instructions, None);
+        // An all-default lockup is still emitted in full
+        assert_eq!(
+            parse_stake(
+                &message.instructions[1],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap()
+            .info["lockup"],
+            json!({
+                "unixTimestamp": 0,
+                "epoch": 0,
+                "custodian": Pubkey::default().to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_stake_lockup_epoch_active() {
+        let keys = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let lockup_epoch_active = |lockup: &LockupArgs, config: &ParseConfig| {
+            let message =
+                Message::new(&[instruction::set_lockup(&keys[1], lockup, &keys[0])], None);
+            parse_stake_with_config(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None),
+                config,
+            )
+            .unwrap()
+            .info
+            .get("lockupEpochActive")
+            .cloned()
+        };
+        let lockup = LockupArgs {
+            epoch: Some(10),
+            ..LockupArgs::default()
+        };
+        let at_epoch = |epoch| ParseConfig::default().with_current_epoch(epoch);
+        assert_eq!(
+            lockup_epoch_active(&lockup, &at_epoch(5)),
+            Some(json!(true))
+        );
+        // The lockup ends as the current epoch reaches it
+        assert_eq!(
+            lockup_epoch_active(&lockup, &at_epoch(10)),
+            Some(json!(false))
+        );
+        assert_eq!(
+            lockup_epoch_active(&lockup, &at_epoch(20)),
+            Some(json!(false))
+        );
+        assert_eq!(lockup_epoch_active(&lockup, &ParseConfig::default()), None);
+        assert_eq!(
+            lockup_epoch_active(&LockupArgs::default(), &at_epoch(5)),
+            None
+        );
+
+        // Initialize always carries a lockup epoch
+        let instructions = instruction::create_account(
+            &keys[0],
+            &keys[1],
+            &Authorized::auto(&keys[0]),
+            &Lockup {
+                epoch: 10,
+                ..Lockup::default()
+            },
+            55,
+        );
+        let message = Message::new(&instructions, None);
+        assert_eq!(
+            parse_stake_with_config(
+                &message.instructions[1],
+                &AccountKeys::new(&message.account_keys, None),
+                &at_epoch(20),
+            )
+            .unwrap()
+            .info["lockupEpochActive"],
+            json!(false)
+        );
+    }
+
     #[test]
     fn test_parse_stake_authorize_ix() {
         let stake_pubkey = Pubkey::new_unique();
@@ -384,13 +549,14 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "authorize".to_string(),
+                instruction_type: "authorize".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "clockSysvar": sysvar::clock::ID.to_string(),
                     "authority": authorized_pubkey.to_string(),
                     "newAuthority": new_authorized_pubkey.to_string(),
                     "authorityType": StakeAuthorize::Staker,
+                    "authorityTypeCode": 0,
                 }),
             }
         );
@@ -419,13 +585,14 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "authorize".to_string(),
+                instruction_type: "authorize".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "clockSysvar": sysvar::clock::ID.to_string(),
                     "authority": authorized_pubkey.to_string(),
                     "newAuthority": new_authorized_pubkey.to_string(),
                     "authorityType": StakeAuthorize::Withdrawer,
+                    "authorityTypeCode": 1,
                     "custodian": custodian_pubkey.to_string(),
                 }),
             }
@@ -456,7 +623,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "delegate".to_string(),
+                instruction_type: "delegate".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "voteAccount": vote_pubkey.to_string(),
@@ -477,6 +644,41 @@ mod test {
         assert!(parse_stake(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
     }
 
+    #[test]
+    fn test_parse_stake_delegate_vote_account_owner() {
+        let stake_pubkey = Pubkey::new_unique();
+        let authorized_pubkey = Pubkey::new_unique();
+        let vote_pubkey = Pubkey::new_unique();
+        let instruction =
+            instruction::delegate_stake(&stake_pubkey, &authorized_pubkey, &vote_pubkey);
+        let message = Message::new(&[instruction], None);
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+
+        let mut parse_config = ParseConfig::default();
+        parse_config
+            .account_owners
+            .insert(vote_pubkey, solana_vote_program::id());
+        let parsed =
+            parse_stake_with_config(&message.instructions[0], &account_keys, &parse_config)
+                .unwrap();
+        assert_eq!(parsed.info["voteAccountValid"], json!(true));
+
+        parse_config
+            .account_owners
+            .insert(vote_pubkey, Pubkey::new_unique());
+        let parsed =
+            parse_stake_with_config(&message.instructions[0], &account_keys, &parse_config)
+                .unwrap();
+        assert_eq!(parsed.info["voteAccountValid"], json!(false));
+
+        // Omitted when the owner is unknown
+        parse_config.account_owners.clear();
+        let parsed =
+            parse_stake_with_config(&message.instructions[0], &account_keys, &parse_config)
+                .unwrap();
+        assert!(parsed.info.get("voteAccountValid").is_none());
+    }
+
     #[test]
     fn test_parse_stake_split_ix() {
         let lamports = 55;
@@ -497,7 +699,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "split".to_string(),
+                instruction_type: "split".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "newSplitAccount": split_stake_pubkey.to_string(),
@@ -538,7 +740,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "withdraw".to_string(),
+                instruction_type: "withdraw".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "destination": to_pubkey.to_string(),
@@ -546,6 +748,7 @@ mod test {
                     "stakeHistorySysvar": sysvar::stake_history::ID.to_string(),
                     "withdrawAuthority": withdrawer_pubkey.to_string(),
                     "lamports": lamports,
+                    "sol": "0.000000055",
                 }),
             }
         );
@@ -564,7 +767,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "withdraw".to_string(),
+                instruction_type: "withdraw".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "destination": to_pubkey.to_string(),
@@ -573,6 +776,7 @@ mod test {
                     "withdrawAuthority": withdrawer_pubkey.to_string(),
                     "custodian": custodian_pubkey.to_string(),
                     "lamports": lamports,
+                    "sol": "0.000000055",
                 }),
             }
         );
@@ -582,7 +786,15 @@ mod test {
         )
         .is_err());
         let keys = message.account_keys.clone();
+        // Dropping the custodian leaves the 5-account layout
         message.instructions[0].accounts.pop();
+        assert!(
+            parse_stake(&message.instructions[0], &AccountKeys::new(&keys, None))
+                .unwrap()
+                .info
+                .get("custodian")
+                .is_none()
+        );
         message.instructions[0].accounts.pop();
         assert!(parse_stake(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
     }
@@ -600,7 +812,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "deactivate".to_string(),
+                instruction_type: "deactivate".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "clockSysvar": sysvar::clock::ID.to_string(),
@@ -636,7 +848,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "merge".to_string(),
+                instruction_type: "merge".into(),
                 info: json!({
                     "destination": destination_stake_pubkey.to_string(),
                     "source": source_stake_pubkey.to_string(),
@@ -682,7 +894,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "authorizeWithSeed".to_string(),
+                instruction_type: "authorizeWithSeed".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "authorityOwner": authority_owner_pubkey.to_string(),
@@ -690,6 +902,7 @@ mod test {
                     "authorityBase": authority_base_pubkey.to_string(),
                     "authoritySeed": seed,
                     "authorityType": StakeAuthorize::Staker,
+                    "authorityTypeCode": 0,
                     "clockSysvar": sysvar::clock::ID.to_string(),
                 }),
             }
@@ -721,7 +934,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "authorizeWithSeed".to_string(),
+                instruction_type: "authorizeWithSeed".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "authorityOwner": authority_owner_pubkey.to_string(),
@@ -729,6 +942,7 @@ mod test {
                     "authorityBase": authority_base_pubkey.to_string(),
                     "authoritySeed": seed,
                     "authorityType": StakeAuthorize::Withdrawer,
+                    "authorityTypeCode": 1,
                     "clockSysvar": sysvar::clock::ID.to_string(),
                     "custodian": custodian_pubkey.to_string(),
                 }),
@@ -771,7 +985,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "setLockup".to_string(),
+                instruction_type: "setLockup".into(),
                 info: json!({
                     "stakeAccount": keys[1].to_string(),
                     "custodian": keys[0].to_string(),
@@ -796,7 +1010,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "setLockup".to_string(),
+                instruction_type: "setLockup".into(),
                 info: json!({
                     "stakeAccount": keys[1].to_string(),
                     "custodian": keys[0].to_string(),
@@ -822,7 +1036,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "setLockup".to_string(),
+                instruction_type: "setLockup".into(),
                 info: json!({
                     "stakeAccount": keys[1].to_string(),
                     "custodian": keys[0].to_string(),
@@ -858,7 +1072,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "setLockupChecked".to_string(),
+                instruction_type: "setLockupChecked".into(),
                 info: json!({
                     "stakeAccount": keys[1].to_string(),
                     "custodian": keys[0].to_string(),
@@ -883,7 +1097,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "setLockupChecked".to_string(),
+                instruction_type: "setLockupChecked".into(),
                 info: json!({
                     "stakeAccount": keys[1].to_string(),
                     "custodian": keys[0].to_string(),
@@ -917,7 +1131,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "setLockupChecked".to_string(),
+                instruction_type: "setLockupChecked".into(),
                 info: json!({
                     "stakeAccount": keys[2].to_string(),
                     "custodian": keys[0].to_string(),
@@ -940,6 +1154,48 @@ mod test {
         assert!(parse_stake(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
     }
 
+    #[test]
+    fn test_parse_stake_set_lockup_custodian() {
+        // The signer is the custodian while the lockup is in force and the withdraw authority
+        // once it has expired; both sign at the same position, so only `custodian` is reported
+        let stake_pubkey = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+        let lockup = LockupArgs {
+            unix_timestamp: None,
+            epoch: Some(11),
+            custodian: None,
+        };
+        for (instruction, instruction_type) in [
+            (
+                instruction::set_lockup(&stake_pubkey, &lockup, &signer),
+                "setLockup",
+            ),
+            (
+                instruction::set_lockup_checked(&stake_pubkey, &lockup, &signer),
+                "setLockupChecked",
+            ),
+        ] {
+            let message = Message::new(&[instruction], None);
+            assert_eq!(
+                parse_stake(
+                    &message.instructions[0],
+                    &AccountKeys::new(&message.account_keys, None)
+                )
+                .unwrap(),
+                ParsedInstructionEnum {
+                    instruction_type: instruction_type.into(),
+                    info: json!({
+                        "stakeAccount": stake_pubkey.to_string(),
+                        "custodian": signer.to_string(),
+                        "lockup": {
+                            "epoch": 11,
+                        }
+                    }),
+                }
+            );
+        }
+    }
+
     #[test]
     fn test_parse_stake_create_account_checked_ix() {
         let from_pubkey = Pubkey::new_unique();
@@ -961,7 +1217,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeChecked".to_string(),
+                instruction_type: "initializeChecked".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "rentSysvar": sysvar::rent::ID.to_string(),
@@ -1002,13 +1258,14 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "authorizeChecked".to_string(),
+                instruction_type: "authorizeChecked".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "clockSysvar": sysvar::clock::ID.to_string(),
                     "authority": authorized_pubkey.to_string(),
                     "newAuthority": new_authorized_pubkey.to_string(),
                     "authorityType": StakeAuthorize::Staker,
+                    "authorityTypeCode": 0,
                 }),
             }
         );
@@ -1037,13 +1294,14 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "authorizeChecked".to_string(),
+                instruction_type: "authorizeChecked".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "clockSysvar": sysvar::clock::ID.to_string(),
                     "authority": authorized_pubkey.to_string(),
                     "newAuthority": new_authorized_pubkey.to_string(),
                     "authorityType": StakeAuthorize::Withdrawer,
+                    "authorityTypeCode": 1,
                     "custodian": custodian_pubkey.to_string(),
                 }),
             }
@@ -1085,7 +1343,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "authorizeCheckedWithSeed".to_string(),
+                instruction_type: "authorizeCheckedWithSeed".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "authorityOwner": authority_owner_pubkey.to_string(),
@@ -1093,6 +1351,7 @@ mod test {
                     "authorityBase": authority_base_pubkey.to_string(),
                     "authoritySeed": seed,
                     "authorityType": StakeAuthorize::Staker,
+                    "authorityTypeCode": 0,
                     "clockSysvar": sysvar::clock::ID.to_string(),
                 }),
             }
@@ -1124,7 +1383,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "authorizeCheckedWithSeed".to_string(),
+                instruction_type: "authorizeCheckedWithSeed".into(),
                 info: json!({
                     "stakeAccount": stake_pubkey.to_string(),
                     "authorityOwner": authority_owner_pubkey.to_string(),
@@ -1132,6 +1391,7 @@ mod test {
                     "authorityBase": authority_base_pubkey.to_string(),
                     "authoritySeed": seed,
                     "authorityType": StakeAuthorize::Withdrawer,
+                    "authorityTypeCode": 1,
                     "clockSysvar": sysvar::clock::ID.to_string(),
                     "custodian": custodian_pubkey.to_string(),
                 }),
@@ -0,0 +1,303 @@
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+        },
+    },
+    borsh::BorshDeserialize,
+    serde_json::json,
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys},
+};
+
+solana_sdk::declare_id!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+// Tags of the `StakePoolInstruction` variants parsed here
+const UPDATE_VALIDATOR_LIST_BALANCE: u8 = 6;
+const DEPOSIT_STAKE: u8 = 9;
+const WITHDRAW_STAKE: u8 = 10;
+const DEPOSIT_SOL: u8 = 14;
+const WITHDRAW_SOL: u8 = 16;
+
+pub fn parse_stake_pool(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    match instruction.accounts.iter().max() {
+        Some(index) if (*index as usize) < account_keys.len() => {}
+        _ => {
+            // Runtime should prevent this from ever happening
+            return Err(ParseInstructionError::InstructionKeyMismatch(
+                ParsableProgram::SplStakePool,
+            ));
+        }
+    }
+    let not_parsable =
+        || ParseInstructionError::InstructionNotParsable(ParsableProgram::SplStakePool);
+    let (tag, data) = instruction.data.split_first().ok_or_else(not_parsable)?;
+    let key = |i: usize| account_keys[instruction.accounts[i] as usize].to_string();
+    match *tag {
+        UPDATE_VALIDATOR_LIST_BALANCE => {
+            let (start_index, no_merge) =
+                <(u32, bool)>::try_from_slice(data).map_err(|_| not_parsable())?;
+            check_num_stake_pool_accounts(&instruction.accounts, 7)?;
+            let validator_accounts = &instruction.accounts[7..];
+            if validator_accounts.len() % 2 != 0 {
+                return Err(not_parsable());
+            }
+            let (validator_stake_accounts, transient_stake_accounts): (Vec<_>, Vec<_>) =
+                validator_accounts
+                    .chunks(2)
+                    .map(|pair| {
+                        (
+                            account_keys[pair[0] as usize].to_string(),
+                            account_keys[pair[1] as usize].to_string(),
+                        )
+                    })
+                    .unzip();
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::stake_pool::UPDATE_VALIDATOR_LIST_BALANCE
+                    .into(),
+                info: json!({
+                    "stakePool": key(0),
+                    "withdrawAuthority": key(1),
+                    "validatorList": key(2),
+                    "reserveStake": key(3),
+                    "clockSysvar": key(4),
+                    "stakeHistorySysvar": key(5),
+                    "stakeProgram": key(6),
+                    "validatorStakeAccounts": validator_stake_accounts,
+                    "transientStakeAccounts": transient_stake_accounts,
+                    "startIndex": start_index,
+                    "noMerge": no_merge,
+                }),
+            })
+        }
+        DEPOSIT_STAKE => {
+            if !data.is_empty() {
+                return Err(not_parsable());
+            }
+            check_num_stake_pool_accounts(&instruction.accounts, 15)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::stake_pool::DEPOSIT_STAKE.into(),
+                info: json!({
+                    "stakePool": key(0),
+                    "validatorList": key(1),
+                    "depositAuthority": key(2),
+                    "withdrawAuthority": key(3),
+                    "depositStake": key(4),
+                    "validatorStake": key(5),
+                    "reserveStake": key(6),
+                    "destination": key(7),
+                    "managerFeeAccount": key(8),
+                    "referrerFeeAccount": key(9),
+                    "poolMint": key(10),
+                    "clockSysvar": key(11),
+                    "stakeHistorySysvar": key(12),
+                    "tokenProgram": key(13),
+                    "stakeProgram": key(14),
+                }),
+            })
+        }
+        WITHDRAW_STAKE => {
+            let pool_tokens = u64::try_from_slice(data).map_err(|_| not_parsable())?;
+            check_num_stake_pool_accounts(&instruction.accounts, 13)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::stake_pool::WITHDRAW_STAKE.into(),
+                info: json!({
+                    "stakePool": key(0),
+                    "validatorList": key(1),
+                    "withdrawAuthority": key(2),
+                    "sourceStake": key(3),
+                    "destinationStake": key(4),
+                    "newStakeAuthority": key(5),
+                    "userTransferAuthority": key(6),
+                    "source": key(7),
+                    "managerFeeAccount": key(8),
+                    "poolMint": key(9),
+                    "clockSysvar": key(10),
+                    "tokenProgram": key(11),
+                    "stakeProgram": key(12),
+                    "poolTokens": pool_tokens,
+                }),
+            })
+        }
+        DEPOSIT_SOL => {
+            let lamports = u64::try_from_slice(data).map_err(|_| not_parsable())?;
+            check_num_stake_pool_accounts(&instruction.accounts, 10)?;
+            let mut value = json!({
+                "stakePool": key(0),
+                "withdrawAuthority": key(1),
+                "reserveStake": key(2),
+                "source": key(3),
+                "destination": key(4),
+                "managerFeeAccount": key(5),
+                "referrerFeeAccount": key(6),
+                "poolMint": key(7),
+                "systemProgram": key(8),
+                "tokenProgram": key(9),
+                "lamports": lamports,
+            });
+            if instruction.accounts.len() >= 11 {
+                let map = value.as_object_mut().unwrap();
+                map.insert("solDepositAuthority".to_string(), json!(key(10)));
+            }
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::stake_pool::DEPOSIT_SOL.into(),
+                info: value,
+            })
+        }
+        WITHDRAW_SOL => {
+            let pool_tokens = u64::try_from_slice(data).map_err(|_| not_parsable())?;
+            check_num_stake_pool_accounts(&instruction.accounts, 12)?;
+            let mut value = json!({
+                "stakePool": key(0),
+                "withdrawAuthority": key(1),
+                "userTransferAuthority": key(2),
+                "source": key(3),
+                "reserveStake": key(4),
+                "destination": key(5),
+                "managerFeeAccount": key(6),
+                "poolMint": key(7),
+                "clockSysvar": key(8),
+                "stakeHistorySysvar": key(9),
+                "stakeProgram": key(10),
+                "tokenProgram": key(11),
+                "poolTokens": pool_tokens,
+            });
+            if instruction.accounts.len() >= 13 {
+                let map = value.as_object_mut().unwrap();
+                map.insert("solWithdrawAuthority".to_string(), json!(key(12)));
+            }
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::stake_pool::WITHDRAW_SOL.into(),
+                info: value,
+            })
+        }
+        _ => Err(not_parsable()),
+    }
+}
+
+fn check_num_stake_pool_accounts(accounts: &[u8], num: usize) -> Result<(), ParseInstructionError> {
+    check_num_accounts(accounts, num, ParsableProgram::SplStakePool)
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, borsh::BorshSerialize, solana_sdk::pubkey::Pubkey};
+
+    fn instruction_data(tag: u8, args: impl BorshSerialize) -> Vec<u8> {
+        let mut data = vec![tag];
+        data.extend(args.try_to_vec().unwrap());
+        data
+    }
+
+    #[test]
+    fn test_parse_stake_pool_deposit_sol() {
+        let keys: Vec<Pubkey> = (0..11).map(|_| Pubkey::new_unique()).collect();
+        let mut instruction = CompiledInstruction::new_from_raw_parts(
+            0,
+            instruction_data(DEPOSIT_SOL, 42u64),
+            (0..10).collect(),
+        );
+        let expected_info = json!({
+            "stakePool": keys[0].to_string(),
+            "withdrawAuthority": keys[1].to_string(),
+            "reserveStake": keys[2].to_string(),
+            "source": keys[3].to_string(),
+            "destination": keys[4].to_string(),
+            "managerFeeAccount": keys[5].to_string(),
+            "referrerFeeAccount": keys[6].to_string(),
+            "poolMint": keys[7].to_string(),
+            "systemProgram": keys[8].to_string(),
+            "tokenProgram": keys[9].to_string(),
+            "lamports": 42,
+        });
+        assert_eq!(
+            parse_stake_pool(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "depositSol".into(),
+                info: expected_info.clone(),
+            }
+        );
+
+        // With a sol deposit authority
+        instruction.accounts.push(10);
+        let mut expected_info = expected_info;
+        expected_info["solDepositAuthority"] = json!(keys[10].to_string());
+        assert_eq!(
+            parse_stake_pool(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "depositSol".into(),
+                info: expected_info,
+            }
+        );
+
+        assert!(parse_stake_pool(&instruction, &AccountKeys::new(&keys[0..10], None)).is_err());
+        instruction.accounts.truncate(9);
+        assert!(parse_stake_pool(&instruction, &AccountKeys::new(&keys, None)).is_err());
+    }
+
+    #[test]
+    fn test_parse_stake_pool_withdraw_sol() {
+        let keys: Vec<Pubkey> = (0..13).map(|_| Pubkey::new_unique()).collect();
+        let mut instruction = CompiledInstruction::new_from_raw_parts(
+            0,
+            instruction_data(WITHDRAW_SOL, 1_000u64),
+            (0..13).collect(),
+        );
+        assert_eq!(
+            parse_stake_pool(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "withdrawSol".into(),
+                info: json!({
+                    "stakePool": keys[0].to_string(),
+                    "withdrawAuthority": keys[1].to_string(),
+                    "userTransferAuthority": keys[2].to_string(),
+                    "source": keys[3].to_string(),
+                    "reserveStake": keys[4].to_string(),
+                    "destination": keys[5].to_string(),
+                    "managerFeeAccount": keys[6].to_string(),
+                    "poolMint": keys[7].to_string(),
+                    "clockSysvar": keys[8].to_string(),
+                    "stakeHistorySysvar": keys[9].to_string(),
+                    "stakeProgram": keys[10].to_string(),
+                    "tokenProgram": keys[11].to_string(),
+                    "solWithdrawAuthority": keys[12].to_string(),
+                    "poolTokens": 1_000,
+                }),
+            }
+        );
+
+        instruction.accounts.truncate(12);
+        assert!(
+            parse_stake_pool(&instruction, &AccountKeys::new(&keys, None))
+                .unwrap()
+                .info
+                .get("solWithdrawAuthority")
+                .is_none()
+        );
+        instruction.accounts.pop();
+        assert!(parse_stake_pool(&instruction, &AccountKeys::new(&keys, None)).is_err());
+
+        // Truncated amount
+        instruction.accounts = (0..12).collect();
+        instruction.data.pop();
+        assert!(parse_stake_pool(&instruction, &AccountKeys::new(&keys, None)).is_err());
+    }
+
+    #[test]
+    fn test_parse_stake_pool_not_parsable() {
+        let keys = [Pubkey::new_unique()];
+        // SetManager is not parsed
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![11], vec![0]);
+        assert!(matches!(
+            parse_stake_pool(&instruction, &AccountKeys::new(&keys, None)),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::SplStakePool
+            ))
+        ));
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![], vec![0]);
+        assert!(parse_stake_pool(&instruction, &AccountKeys::new(&keys, None)).is_err());
+    }
+}
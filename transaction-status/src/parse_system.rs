@@ -1,18 +1,38 @@
 use {
-    crate::parse_instruction::{
-        check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+    crate::{
+        instruction_types,
+        parse_encode::ParsedFields,
+        parse_instruction::{
+            check_num_accounts, flag_incinerator_destination, lamports_to_sol_string,
+            InstructionParser, ParsableProgram, ParseConfig, ParseInstructionError,
+            ParsedInstructionEnum,
+        },
+        parse_jito::flag_tip_destination,
     },
     bincode::deserialize,
-    serde_json::json,
+    serde_json::{json, Value},
     solana_sdk::{
-        instruction::CompiledInstruction, message::AccountKeys,
+        hash::Hash,
+        instruction::CompiledInstruction,
+        message::{AccountKeys, Message},
+        nonce::state::{State as NonceState, Versions as NonceVersions},
+        pubkey::Pubkey,
         system_instruction::SystemInstruction,
+        system_program,
     },
 };
 
 pub fn parse_system(
     instruction: &CompiledInstruction,
     account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    parse_system_with_config(instruction, account_keys, &ParseConfig::default())
+}
+
+pub fn parse_system_with_config(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+    config: &ParseConfig,
 ) -> Result<ParsedInstructionEnum, ParseInstructionError> {
     let system_instruction: SystemInstruction = deserialize(&instruction.data)
         .map_err(|_| ParseInstructionError::InstructionNotParsable(ParsableProgram::System))?;
@@ -32,36 +52,56 @@ pub fn parse_system(
             owner,
         } => {
             check_num_system_accounts(&instruction.accounts, 2)?;
+            let mut value = json!({
+                "source": account_keys[instruction.accounts[0] as usize].to_string(),
+                "newAccount": account_keys[instruction.accounts[1] as usize].to_string(),
+                "lamports": lamports,
+                "sol": lamports_to_sol_string(lamports),
+                "space": space,
+                "owner": owner.to_string(),
+                "ownerIsSystemProgram": system_program::check_id(&owner),
+            });
+            let map = value.as_object_mut().unwrap();
+            if space == 0 {
+                // Data-less accounts, e.g. PDAs that only ever sign
+                map.insert("dataless".to_string(), json!(true));
+            }
+            if let Some(rent) = &config.rent {
+                // Underfunded creations leave the account subject to rent collection
+                let minimum_balance = rent.minimum_balance(space as usize);
+                map.insert("estimatedRentExemption".to_string(), json!(minimum_balance));
+                map.insert("rentExempt".to_string(), json!(lamports >= minimum_balance));
+            }
             Ok(ParsedInstructionEnum {
-                instruction_type: "createAccount".to_string(),
-                info: json!({
-                    "source": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "newAccount": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "lamports": lamports,
-                    "space": space,
-                    "owner": owner.to_string(),
-                }),
+                instruction_type: instruction_types::system::CREATE_ACCOUNT.into(),
+                info: value,
             })
         }
         SystemInstruction::Assign { owner } => {
             check_num_system_accounts(&instruction.accounts, 1)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "assign".to_string(),
+                instruction_type: instruction_types::system::ASSIGN.into(),
                 info: json!({
                     "account": account_keys[instruction.accounts[0] as usize].to_string(),
                     "owner": owner.to_string(),
+                    "ownerIsSystemProgram": system_program::check_id(&owner),
                 }),
             })
         }
         SystemInstruction::Transfer { lamports } => {
             check_num_system_accounts(&instruction.accounts, 2)?;
+            let destination = &account_keys[instruction.accounts[1] as usize];
+            let mut value = json!({
+                "source": account_keys[instruction.accounts[0] as usize].to_string(),
+                "destination": destination.to_string(),
+                "lamports": lamports,
+                "sol": lamports_to_sol_string(lamports),
+            });
+            flag_incinerator_destination(&mut value, destination);
+            flag_tip_destination(&mut value, destination);
             Ok(ParsedInstructionEnum {
-                instruction_type: "transfer".to_string(),
-                info: json!({
-                    "source": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "destination": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "lamports": lamports,
-                }),
+                instruction_type: instruction_types::system::TRANSFER.into(),
+                info: value,
             })
         }
         SystemInstruction::CreateAccountWithSeed {
@@ -73,13 +113,14 @@ pub fn parse_system(
         } => {
             check_num_system_accounts(&instruction.accounts, 2)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "createAccountWithSeed".to_string(),
+                instruction_type: instruction_types::system::CREATE_ACCOUNT_WITH_SEED.into(),
                 info: json!({
                     "source": account_keys[instruction.accounts[0] as usize].to_string(),
                     "newAccount": account_keys[instruction.accounts[1] as usize].to_string(),
                     "base": base.to_string(),
                     "seed": seed,
                     "lamports": lamports,
+                    "sol": lamports_to_sol_string(lamports),
                     "space": space,
                     "owner": owner.to_string(),
                 }),
@@ -87,33 +128,40 @@ pub fn parse_system(
         }
         SystemInstruction::AdvanceNonceAccount => {
             check_num_system_accounts(&instruction.accounts, 3)?;
+            let mut value = json!({
+                "nonceAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                "recentBlockhashesSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
+                "nonceAuthority": account_keys[instruction.accounts[2] as usize].to_string(),
+            });
+            insert_current_blockhash(&mut value, instruction, account_keys, config);
             Ok(ParsedInstructionEnum {
-                instruction_type: "advanceNonce".to_string(),
-                info: json!({
-                    "nonceAccount": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "recentBlockhashesSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "nonceAuthority": account_keys[instruction.accounts[2] as usize].to_string(),
-                }),
+                instruction_type: instruction_types::system::ADVANCE_NONCE.into(),
+                info: value,
             })
         }
         SystemInstruction::WithdrawNonceAccount(lamports) => {
             check_num_system_accounts(&instruction.accounts, 5)?;
+            let destination = &account_keys[instruction.accounts[1] as usize];
+            let mut value = json!({
+                "nonceAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                "destination": destination.to_string(),
+                "recentBlockhashesSysvar": account_keys[instruction.accounts[2] as usize].to_string(),
+                "rentSysvar": account_keys[instruction.accounts[3] as usize].to_string(),
+                "nonceAuthority": account_keys[instruction.accounts[4] as usize].to_string(),
+                "lamports": lamports,
+                "sol": lamports_to_sol_string(lamports),
+            });
+            insert_current_blockhash(&mut value, instruction, account_keys, config);
+            flag_incinerator_destination(&mut value, destination);
             Ok(ParsedInstructionEnum {
-                instruction_type: "withdrawFromNonce".to_string(),
-                info: json!({
-                    "nonceAccount": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "destination": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "recentBlockhashesSysvar": account_keys[instruction.accounts[2] as usize].to_string(),
-                    "rentSysvar": account_keys[instruction.accounts[3] as usize].to_string(),
-                    "nonceAuthority": account_keys[instruction.accounts[4] as usize].to_string(),
-                    "lamports": lamports,
-                }),
+                instruction_type: instruction_types::system::WITHDRAW_FROM_NONCE.into(),
+                info: value,
             })
         }
         SystemInstruction::InitializeNonceAccount(authority) => {
             check_num_system_accounts(&instruction.accounts, 3)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "initializeNonce".to_string(),
+                instruction_type: instruction_types::system::INITIALIZE_NONCE.into(),
                 info: json!({
                     "nonceAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "recentBlockhashesSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -124,19 +172,21 @@ pub fn parse_system(
         }
         SystemInstruction::AuthorizeNonceAccount(authority) => {
             check_num_system_accounts(&instruction.accounts, 2)?;
+            let nonce_authority = &account_keys[instruction.accounts[1] as usize];
             Ok(ParsedInstructionEnum {
-                instruction_type: "authorizeNonce".to_string(),
+                instruction_type: instruction_types::system::AUTHORIZE_NONCE.into(),
                 info: json!({
                     "nonceAccount": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "nonceAuthority": account_keys[instruction.accounts[1] as usize].to_string(),
+                    "nonceAuthority": nonce_authority.to_string(),
                     "newAuthorized": authority.to_string(),
+                    "authorityUnchanged": &authority == nonce_authority,
                 }),
             })
         }
         SystemInstruction::UpgradeNonceAccount => {
             check_num_system_accounts(&instruction.accounts, 1)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "upgradeNonce".to_string(),
+                instruction_type: instruction_types::system::UPGRADE_NONCE.into(),
                 info: json!({
                     "nonceAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                 }),
@@ -144,12 +194,20 @@ pub fn parse_system(
         }
         SystemInstruction::Allocate { space } => {
             check_num_system_accounts(&instruction.accounts, 1)?;
+            let mut value = json!({
+                "account": account_keys[instruction.accounts[0] as usize].to_string(),
+                "space": space,
+            });
+            if let Some(rent) = &config.rent {
+                let map = value.as_object_mut().unwrap();
+                map.insert(
+                    "estimatedRentExemption".to_string(),
+                    json!(rent.minimum_balance(space as usize)),
+                );
+            }
             Ok(ParsedInstructionEnum {
-                instruction_type: "allocate".to_string(),
-                info: json!({
-                    "account": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "space": space,
-                }),
+                instruction_type: instruction_types::system::ALLOCATE.into(),
+                info: value,
             })
         }
         SystemInstruction::AllocateWithSeed {
@@ -159,27 +217,33 @@ pub fn parse_system(
             owner,
         } => {
             check_num_system_accounts(&instruction.accounts, 2)?;
+            let account = &account_keys[instruction.accounts[0] as usize];
+            let mut value = json!({
+                "account": account.to_string(),
+                "base": base.to_string(),
+                "seed": seed,
+                "space": space,
+                "owner": owner.to_string(),
+            });
+            insert_derived_address(&mut value, &base, &seed, &owner, account);
             Ok(ParsedInstructionEnum {
-                instruction_type: "allocateWithSeed".to_string(),
-                info: json!({
-                    "account": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "base": base.to_string(),
-                    "seed": seed,
-                    "space": space,
-                    "owner": owner.to_string(),
-                }),
+                instruction_type: instruction_types::system::ALLOCATE_WITH_SEED.into(),
+                info: value,
             })
         }
         SystemInstruction::AssignWithSeed { base, seed, owner } => {
             check_num_system_accounts(&instruction.accounts, 2)?;
+            let account = &account_keys[instruction.accounts[0] as usize];
+            let mut value = json!({
+                "account": account.to_string(),
+                "base": base.to_string(),
+                "seed": seed,
+                "owner": owner.to_string(),
+            });
+            insert_derived_address(&mut value, &base, &seed, &owner, account);
             Ok(ParsedInstructionEnum {
-                instruction_type: "assignWithSeed".to_string(),
-                info: json!({
-                    "account": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "base": base.to_string(),
-                    "seed": seed,
-                    "owner": owner.to_string(),
-                }),
+                instruction_type: instruction_types::system::ASSIGN_WITH_SEED.into(),
+                info: value,
             })
         }
         SystemInstruction::TransferWithSeed {
@@ -188,21 +252,274 @@ pub fn parse_system(
             from_owner,
         } => {
             check_num_system_accounts(&instruction.accounts, 3)?;
+            let source_base = &account_keys[instruction.accounts[1] as usize];
+            let destination = &account_keys[instruction.accounts[2] as usize];
+            let mut value = json!({
+                "source": account_keys[instruction.accounts[0] as usize].to_string(),
+                "sourceBase": source_base.to_string(),
+                "destination": destination.to_string(),
+                "lamports": lamports,
+                "sol": lamports_to_sol_string(lamports),
+                "sourceSeed": from_seed,
+                "sourceOwner": from_owner.to_string(),
+            });
+            // Flag transfers back into the seed-derived source, a likely misconfiguration
+            if let Ok(derived_source) =
+                Pubkey::create_with_seed(source_base, &from_seed, &from_owner)
+            {
+                let map = value.as_object_mut().unwrap();
+                map.insert(
+                    "selfTransfer".to_string(),
+                    json!(&derived_source == destination),
+                );
+            }
+            flag_incinerator_destination(&mut value, destination);
+            flag_tip_destination(&mut value, destination);
             Ok(ParsedInstructionEnum {
-                instruction_type: "transferWithSeed".to_string(),
-                info: json!({
-                    "source": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "sourceBase": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "destination": account_keys[instruction.accounts[2] as usize].to_string(),
-                    "lamports": lamports,
-                    "sourceSeed": from_seed,
-                    "sourceOwner": from_owner.to_string(),
-                }),
+                instruction_type: instruction_types::system::TRANSFER_WITH_SEED.into(),
+                info: value,
             })
         }
     }
 }
 
+/// Inverse of [`parse_system`]: the instruction and account indexes described by the parsed
+/// `info`, see [`to_compiled_instruction`](crate::parse_encode::to_compiled_instruction)
+pub(crate) fn encode_system(
+    instruction_type: &str,
+    fields: &ParsedFields,
+) -> Result<(SystemInstruction, Vec<u8>), ParseInstructionError> {
+    let accounts = |names: &[&str]| -> Result<Vec<u8>, ParseInstructionError> {
+        names.iter().map(|name| fields.account(name)).collect()
+    };
+    match instruction_type {
+        instruction_types::system::CREATE_ACCOUNT => Ok((
+            SystemInstruction::CreateAccount {
+                lamports: fields.u64("lamports")?,
+                space: fields.u64("space")?,
+                owner: fields.pubkey("owner")?,
+            },
+            accounts(&["source", "newAccount"])?,
+        )),
+        instruction_types::system::ASSIGN => Ok((
+            SystemInstruction::Assign {
+                owner: fields.pubkey("owner")?,
+            },
+            accounts(&["account"])?,
+        )),
+        instruction_types::system::TRANSFER => Ok((
+            SystemInstruction::Transfer {
+                lamports: fields.u64("lamports")?,
+            },
+            accounts(&["source", "destination"])?,
+        )),
+        instruction_types::system::CREATE_ACCOUNT_WITH_SEED => Ok((
+            SystemInstruction::CreateAccountWithSeed {
+                base: fields.pubkey("base")?,
+                seed: fields.string("seed")?,
+                lamports: fields.u64("lamports")?,
+                space: fields.u64("space")?,
+                owner: fields.pubkey("owner")?,
+            },
+            accounts(&["source", "newAccount"])?,
+        )),
+        instruction_types::system::ADVANCE_NONCE => Ok((
+            SystemInstruction::AdvanceNonceAccount,
+            accounts(&["nonceAccount", "recentBlockhashesSysvar", "nonceAuthority"])?,
+        )),
+        instruction_types::system::WITHDRAW_FROM_NONCE => Ok((
+            SystemInstruction::WithdrawNonceAccount(fields.u64("lamports")?),
+            accounts(&[
+                "nonceAccount",
+                "destination",
+                "recentBlockhashesSysvar",
+                "rentSysvar",
+                "nonceAuthority",
+            ])?,
+        )),
+        instruction_types::system::INITIALIZE_NONCE => Ok((
+            SystemInstruction::InitializeNonceAccount(fields.pubkey("nonceAuthority")?),
+            accounts(&["nonceAccount", "recentBlockhashesSysvar", "rentSysvar"])?,
+        )),
+        instruction_types::system::AUTHORIZE_NONCE => Ok((
+            SystemInstruction::AuthorizeNonceAccount(fields.pubkey("newAuthorized")?),
+            accounts(&["nonceAccount", "nonceAuthority"])?,
+        )),
+        instruction_types::system::UPGRADE_NONCE => Ok((
+            SystemInstruction::UpgradeNonceAccount,
+            accounts(&["nonceAccount"])?,
+        )),
+        instruction_types::system::ALLOCATE => Ok((
+            SystemInstruction::Allocate {
+                space: fields.u64("space")?,
+            },
+            accounts(&["account"])?,
+        )),
+        // The base signs as the second account
+        instruction_types::system::ALLOCATE_WITH_SEED => Ok((
+            SystemInstruction::AllocateWithSeed {
+                base: fields.pubkey("base")?,
+                seed: fields.string("seed")?,
+                space: fields.u64("space")?,
+                owner: fields.pubkey("owner")?,
+            },
+            accounts(&["account", "base"])?,
+        )),
+        instruction_types::system::ASSIGN_WITH_SEED => Ok((
+            SystemInstruction::AssignWithSeed {
+                base: fields.pubkey("base")?,
+                seed: fields.string("seed")?,
+                owner: fields.pubkey("owner")?,
+            },
+            accounts(&["account", "base"])?,
+        )),
+        instruction_types::system::TRANSFER_WITH_SEED => Ok((
+            SystemInstruction::TransferWithSeed {
+                lamports: fields.u64("lamports")?,
+                from_seed: fields.string("sourceSeed")?,
+                from_owner: fields.pubkey("sourceOwner")?,
+            },
+            accounts(&["source", "sourceBase", "destination"])?,
+        )),
+        _ => Err(ParseInstructionError::InstructionNotParsable(
+            ParsableProgram::System,
+        )),
+    }
+}
+
+/// [`InstructionParser`] for the system program
+pub struct SystemParser;
+
+impl InstructionParser for SystemParser {
+    fn program_id(&self) -> Pubkey {
+        system_program::id()
+    }
+
+    fn program_name(&self) -> String {
+        "system".to_string()
+    }
+
+    fn parse(
+        &self,
+        instruction: &CompiledInstruction,
+        account_keys: &AccountKeys,
+    ) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+        parse_system(instruction, account_keys)
+    }
+}
+
+/// System transfers in a message sharing one source account, see [`aggregate_transfers`]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferBatch {
+    pub source: String,
+    pub destinations: Vec<TransferDestination>,
+    pub total: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferDestination {
+    pub destination: String,
+    pub lamports: u64,
+}
+
+/// Groups the system `transfer` instructions of `message` by source account, keeping only
+/// sources that transfer more than once, as airdrops and payroll runs do. Batches are ordered by
+/// the first transfer of each source, destinations by instruction order.
+pub fn aggregate_transfers(message: &Message) -> Vec<TransferBatch> {
+    let mut batches: Vec<TransferBatch> = vec![];
+    for instruction in &message.instructions {
+        let is_system = message
+            .account_keys
+            .get(instruction.program_id_index as usize)
+            .map_or(false, system_program::check_id);
+        if !is_system {
+            continue;
+        }
+        let lamports = match deserialize(&instruction.data) {
+            Ok(SystemInstruction::Transfer { lamports }) => lamports,
+            _ => continue,
+        };
+        let key = |i: usize| {
+            instruction
+                .accounts
+                .get(i)
+                .and_then(|index| message.account_keys.get(*index as usize))
+        };
+        let (source, destination) = match (key(0), key(1)) {
+            (Some(source), Some(destination)) => (source.to_string(), destination.to_string()),
+            _ => continue,
+        };
+        let position = match batches.iter().position(|batch| batch.source == source) {
+            Some(position) => position,
+            None => {
+                batches.push(TransferBatch {
+                    source,
+                    destinations: vec![],
+                    total: 0,
+                });
+                batches.len() - 1
+            }
+        };
+        let batch = &mut batches[position];
+        batch.destinations.push(TransferDestination {
+            destination,
+            lamports,
+        });
+        batch.total = batch.total.saturating_add(lamports);
+    }
+    batches.retain(|batch| batch.destinations.len() > 1);
+    batches
+}
+
+// Adds the blockhash stored in the nonce account (the instruction's first account) when its data
+// was supplied and it is initialized
+fn insert_current_blockhash(
+    value: &mut Value,
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+    config: &ParseConfig,
+) {
+    let nonce_account = &account_keys[instruction.accounts[0] as usize];
+    if let Some(blockhash) = config
+        .account_data
+        .get(nonce_account)
+        .and_then(|data| nonce_blockhash(data))
+    {
+        let map = value.as_object_mut().unwrap();
+        map.insert("currentBlockhash".to_string(), json!(blockhash.to_string()));
+    }
+}
+
+// Omitted when the seed can't derive an address, e.g. when it exceeds `MAX_SEED_LEN`
+fn insert_derived_address(
+    value: &mut Value,
+    base: &Pubkey,
+    seed: &str,
+    owner: &Pubkey,
+    account: &Pubkey,
+) {
+    if let Ok(derived_address) = Pubkey::create_with_seed(base, seed, owner) {
+        let map = value.as_object_mut().unwrap();
+        map.insert(
+            "derivedAddress".to_string(),
+            json!(derived_address.to_string()),
+        );
+        map.insert(
+            "derivedAddressMatches".to_string(),
+            json!(&derived_address == account),
+        );
+    }
+}
+
+fn nonce_blockhash(data: &[u8]) -> Option<Hash> {
+    match deserialize::<NonceVersions>(data).ok()?.state() {
+        NonceState::Initialized(data) => Some(data.blockhash()),
+        NonceState::Uninitialized => None,
+    }
+}
+
 fn check_num_system_accounts(accounts: &[u8], num: usize) -> Result<(), ParseInstructionError> {
     check_num_accounts(accounts, num, ParsableProgram::System)
 }
@@ -211,7 +528,12 @@ fn check_num_system_accounts(accounts: &[u8], num: usize) -> Result<(), ParseIns
 mod test {
     use {
         super::*,
-        solana_sdk::{message::Message, pubkey::Pubkey, system_instruction, sysvar},
+        solana_sdk::{
+            message::Message,
+            nonce::state::{Data as NonceData, DurableNonce},
+            rent::Rent,
+            system_instruction, sysvar,
+        },
     };
 
     #[test]
@@ -237,12 +559,14 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "createAccount".to_string(),
+                instruction_type: "createAccount".into(),
                 info: json!({
                     "source": from_pubkey.to_string(),
                     "newAccount": to_pubkey.to_string(),
                     "lamports": lamports,
+                    "sol": "0.000000055",
                     "owner": owner_pubkey.to_string(),
+                    "ownerIsSystemProgram": false,
                     "space": space,
                 }),
             }
@@ -255,6 +579,142 @@ mod test {
         let keys = message.account_keys.clone();
         message.instructions[0].accounts.pop();
         assert!(parse_system(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
+
+        let instruction = system_instruction::create_account(
+            &from_pubkey,
+            &to_pubkey,
+            lamports,
+            0,
+            &owner_pubkey,
+        );
+        let message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_system(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "createAccount".into(),
+                info: json!({
+                    "source": from_pubkey.to_string(),
+                    "newAccount": to_pubkey.to_string(),
+                    "lamports": lamports,
+                    "sol": "0.000000055",
+                    "owner": owner_pubkey.to_string(),
+                    "ownerIsSystemProgram": false,
+                    "space": 0,
+                    "dataless": true,
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_system_create_account_rent_exempt() {
+        let space = 128;
+        let rent = Rent::default();
+        let minimum_balance = rent.minimum_balance(space as usize);
+        let config = ParseConfig::default().with_rent(rent);
+        let rent_exempt = |lamports: u64| {
+            let instruction = system_instruction::create_account(
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                lamports,
+                space,
+                &Pubkey::new_unique(),
+            );
+            let message = Message::new(&[instruction], None);
+            let account_keys = AccountKeys::new(&message.account_keys, None);
+            parse_system_with_config(&message.instructions[0], &account_keys, &config)
+                .unwrap()
+                .info["rentExempt"]
+                .clone()
+        };
+        assert_eq!(rent_exempt(minimum_balance), json!(true));
+        assert_eq!(rent_exempt(minimum_balance + 1), json!(true));
+        assert_eq!(rent_exempt(minimum_balance - 1), json!(false));
+
+        // Omitted without rent parameters
+        let instruction = system_instruction::create_account(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            minimum_balance,
+            space,
+            &Pubkey::new_unique(),
+        );
+        let message = Message::new(&[instruction], None);
+        let info = parse_system(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+        )
+        .unwrap()
+        .info;
+        assert!(info.get("rentExempt").is_none());
+    }
+
+    #[test]
+    fn test_parse_system_estimated_rent_exemption() {
+        // 128 bytes of data plus the 128-byte account overhead, for two years at the default
+        // 3480 lamports per byte-year
+        let expected_rent_exemption = 1_781_760;
+        let config = ParseConfig::default().with_rent(Rent::default());
+        let account_pubkey = Pubkey::new_unique();
+        let instructions = [
+            system_instruction::create_account(
+                &Pubkey::new_unique(),
+                &account_pubkey,
+                1,
+                128,
+                &Pubkey::new_unique(),
+            ),
+            system_instruction::allocate(&account_pubkey, 128),
+        ];
+        for instruction in instructions {
+            let message = Message::new(&[instruction], None);
+            let account_keys = AccountKeys::new(&message.account_keys, None);
+            let info = parse_system_with_config(&message.instructions[0], &account_keys, &config)
+                .unwrap()
+                .info;
+            assert_eq!(
+                info["estimatedRentExemption"],
+                json!(expected_rent_exemption)
+            );
+
+            let info = parse_system(&message.instructions[0], &account_keys)
+                .unwrap()
+                .info;
+            assert!(info.get("estimatedRentExemption").is_none());
+        }
+    }
+
+    #[test]
+    fn test_parse_system_owner_is_system_program() {
+        let from_pubkey = Pubkey::new_unique();
+        let to_pubkey = Pubkey::new_unique();
+        let owner_is_system_program = |instruction| {
+            let message = Message::new(&[instruction], None);
+            parse_system(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None),
+            )
+            .unwrap()
+            .info["ownerIsSystemProgram"]
+                .clone()
+        };
+        // A system-owned account allocated with data
+        let instruction = system_instruction::create_account(
+            &from_pubkey,
+            &to_pubkey,
+            55,
+            128,
+            &system_program::id(),
+        );
+        assert_eq!(owner_is_system_program(instruction), json!(true));
+        let instruction = system_instruction::assign(&to_pubkey, &system_program::id());
+        assert_eq!(owner_is_system_program(instruction), json!(true));
+        let instruction = system_instruction::assign(&to_pubkey, &Pubkey::new_unique());
+        assert_eq!(owner_is_system_program(instruction), json!(false));
     }
 
     #[test]
@@ -270,10 +730,11 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "assign".to_string(),
+                instruction_type: "assign".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "owner": owner_pubkey.to_string(),
+                    "ownerIsSystemProgram": false,
                 }),
             }
         );
@@ -297,11 +758,12 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "transfer".to_string(),
+                instruction_type: "transfer".into(),
                 info: json!({
                     "source": from_pubkey.to_string(),
                     "destination": to_pubkey.to_string(),
                     "lamports": lamports,
+                    "sol": "0.000000055",
                 }),
             }
         );
@@ -315,6 +777,56 @@ mod test {
         assert!(parse_system(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
     }
 
+    #[test]
+    fn test_parse_system_transfer_to_incinerator() {
+        let from_pubkey = Pubkey::new_unique();
+        let instruction =
+            system_instruction::transfer(&from_pubkey, &solana_sdk::incinerator::id(), 42);
+        let message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_system(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "transfer".into(),
+                info: json!({
+                    "source": from_pubkey.to_string(),
+                    "destination": solana_sdk::incinerator::id().to_string(),
+                    "lamports": 42,
+                    "sol": "0.000000042",
+                    "destinationIsIncinerator": true,
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_system_transfer_to_jito_tip_account() {
+        let from_pubkey = Pubkey::new_unique();
+        let tip_account = crate::parse_jito::tip_payment::tip_accounts()[3];
+        let instruction = system_instruction::transfer(&from_pubkey, &tip_account, 42);
+        let message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_system(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "transfer".into(),
+                info: json!({
+                    "source": from_pubkey.to_string(),
+                    "destination": tip_account.to_string(),
+                    "lamports": 42,
+                    "sol": "0.000000042",
+                    "destinationIsJitoTipAccount": true,
+                }),
+            }
+        );
+    }
+
     #[test]
     fn test_parse_system_create_account_with_seed_ix() {
         let lamports = 55;
@@ -341,11 +853,12 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "createAccountWithSeed".to_string(),
+                instruction_type: "createAccountWithSeed".into(),
                 info: json!({
                     "source": from_pubkey.to_string(),
                     "newAccount": to_pubkey.to_string(),
                     "lamports": lamports,
+                    "sol": "0.000000055",
                     "base": base_pubkey.to_string(),
                     "seed": seed,
                     "owner": owner_pubkey.to_string(),
@@ -378,7 +891,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "allocate".to_string(),
+                instruction_type: "allocate".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "space": space,
@@ -413,13 +926,17 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "allocateWithSeed".to_string(),
+                instruction_type: "allocateWithSeed".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "base": base_pubkey.to_string(),
                     "seed": seed,
                     "owner": owner_pubkey.to_string(),
                     "space": space,
+                    "derivedAddress": Pubkey::create_with_seed(&base_pubkey, seed, &owner_pubkey)
+                        .unwrap()
+                        .to_string(),
+                    "derivedAddressMatches": false,
                 }),
             }
         );
@@ -453,12 +970,16 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "assignWithSeed".to_string(),
+                instruction_type: "assignWithSeed".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "base": base_pubkey.to_string(),
                     "seed": seed,
                     "owner": owner_pubkey.to_string(),
+                    "derivedAddress": Pubkey::create_with_seed(&base_pubkey, seed, &owner_pubkey)
+                        .unwrap()
+                        .to_string(),
+                    "derivedAddressMatches": false,
                 }),
             }
         );
@@ -472,6 +993,64 @@ mod test {
         assert!(parse_system(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
     }
 
+    #[test]
+    fn test_parse_system_with_seed_derived_address() {
+        let base_pubkey = Pubkey::new_unique();
+        let owner_pubkey = Pubkey::new_unique();
+        let seed = "test_seed";
+        let derived_pubkey = Pubkey::create_with_seed(&base_pubkey, seed, &owner_pubkey).unwrap();
+
+        let instruction = system_instruction::allocate_with_seed(
+            &derived_pubkey,
+            &base_pubkey,
+            seed,
+            128,
+            &owner_pubkey,
+        );
+        let message = Message::new(&[instruction], None);
+        let info = parse_system(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+        )
+        .unwrap()
+        .info;
+        assert_eq!(info["derivedAddress"], json!(derived_pubkey.to_string()));
+        assert_eq!(info["derivedAddressMatches"], json!(true));
+
+        let instruction = system_instruction::assign_with_seed(
+            &derived_pubkey,
+            &base_pubkey,
+            seed,
+            &owner_pubkey,
+        );
+        let message = Message::new(&[instruction], None);
+        let info = parse_system(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+        )
+        .unwrap()
+        .info;
+        assert_eq!(info["derivedAddressMatches"], json!(true));
+
+        // Seeds longer than `MAX_SEED_LEN` can't derive an address
+        let long_seed = "a".repeat(solana_sdk::pubkey::MAX_SEED_LEN + 1);
+        let instruction = system_instruction::assign_with_seed(
+            &derived_pubkey,
+            &base_pubkey,
+            &long_seed,
+            &owner_pubkey,
+        );
+        let message = Message::new(&[instruction], None);
+        let info = parse_system(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+        )
+        .unwrap()
+        .info;
+        assert!(info.get("derivedAddress").is_none());
+        assert!(info.get("derivedAddressMatches").is_none());
+    }
+
     #[test]
     fn test_parse_system_transfer_with_seed_ix() {
         let lamports = 55;
@@ -496,14 +1075,16 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "transferWithSeed".to_string(),
+                instruction_type: "transferWithSeed".into(),
                 info: json!({
                     "source": from_pubkey.to_string(),
                     "sourceBase": from_base_pubkey.to_string(),
                     "sourceSeed": seed,
                     "sourceOwner": from_owner_pubkey.to_string(),
                     "lamports": lamports,
-                    "destination": to_pubkey.to_string()
+                    "sol": "0.000000055",
+                    "destination": to_pubkey.to_string(),
+                    "selfTransfer": false,
                 }),
             }
         );
@@ -515,6 +1096,43 @@ mod test {
         let keys = message.account_keys.clone();
         message.instructions[0].accounts.pop();
         assert!(parse_system(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
+
+        // Destination is the seed-derived source
+        let derived_pubkey =
+            Pubkey::create_with_seed(&from_base_pubkey, seed, &from_owner_pubkey).unwrap();
+        let instruction = system_instruction::transfer_with_seed(
+            &derived_pubkey,
+            &from_base_pubkey,
+            seed.to_string(),
+            &from_owner_pubkey,
+            &derived_pubkey,
+            lamports,
+        );
+        let message = Message::new(&[instruction], None);
+        let parsed = parse_system(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+        )
+        .unwrap();
+        assert_eq!(parsed.info["selfTransfer"], json!(true));
+
+        // No flag when the seed cannot derive an address
+        let long_seed = "x".repeat(33);
+        let instruction = system_instruction::transfer_with_seed(
+            &from_pubkey,
+            &from_base_pubkey,
+            long_seed,
+            &from_owner_pubkey,
+            &to_pubkey,
+            lamports,
+        );
+        let message = Message::new(&[instruction], None);
+        let parsed = parse_system(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+        )
+        .unwrap();
+        assert!(parsed.info.get("selfTransfer").is_none());
     }
 
     #[test]
@@ -532,7 +1150,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "advanceNonce".to_string(),
+                instruction_type: "advanceNonce".into(),
                 info: json!({
                     "nonceAccount": nonce_pubkey.to_string(),
                     "recentBlockhashesSysvar": sysvar::recent_blockhashes::ID.to_string(),
@@ -550,6 +1168,62 @@ mod test {
         assert!(parse_system(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
     }
 
+    #[test]
+    fn test_parse_system_nonce_current_blockhash() {
+        let nonce_pubkey = Pubkey::new_unique();
+        let authorized_pubkey = Pubkey::new_unique();
+        let durable_nonce = DurableNonce::from_blockhash(&Hash::new_unique());
+        let nonce_state = NonceVersions::new(NonceState::Initialized(NonceData::new(
+            authorized_pubkey,
+            durable_nonce,
+            5_000,
+        )));
+
+        let message = Message::new(
+            &[
+                system_instruction::advance_nonce_account(&nonce_pubkey, &authorized_pubkey),
+                system_instruction::withdraw_nonce_account(
+                    &nonce_pubkey,
+                    &authorized_pubkey,
+                    &Pubkey::new_unique(),
+                    42,
+                ),
+            ],
+            None,
+        );
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let mut config = ParseConfig::default();
+        config
+            .account_data
+            .insert(nonce_pubkey, bincode::serialize(&nonce_state).unwrap());
+        for instruction in &message.instructions {
+            assert_eq!(
+                parse_system_with_config(instruction, &account_keys, &config)
+                    .unwrap()
+                    .info["currentBlockhash"],
+                json!(durable_nonce.as_hash().to_string())
+            );
+            assert!(parse_system(instruction, &account_keys)
+                .unwrap()
+                .info
+                .get("currentBlockhash")
+                .is_none());
+        }
+
+        // Uninitialized nonce accounts have no blockhash
+        config.account_data.insert(
+            nonce_pubkey,
+            bincode::serialize(&NonceVersions::new(NonceState::Uninitialized)).unwrap(),
+        );
+        assert!(
+            parse_system_with_config(&message.instructions[0], &account_keys, &config)
+                .unwrap()
+                .info
+                .get("currentBlockhash")
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_parse_system_withdraw_nonce_account_ix() {
         let nonce_pubkey = Pubkey::new_unique();
@@ -571,14 +1245,15 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "withdrawFromNonce".to_string(),
+                instruction_type: "withdrawFromNonce".into(),
                 info: json!({
                     "nonceAccount": nonce_pubkey.to_string(),
                     "destination": to_pubkey.to_string(),
                     "recentBlockhashesSysvar": sysvar::recent_blockhashes::ID.to_string(),
                     "rentSysvar": sysvar::rent::ID.to_string(),
                     "nonceAuthority": authorized_pubkey.to_string(),
-                    "lamports": lamports
+                    "lamports": lamports,
+                    "sol": "0.000000055",
                 }),
             }
         );
@@ -613,7 +1288,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeNonce".to_string(),
+                instruction_type: "initializeNonce".into(),
                 info: json!({
                     "nonceAccount": nonce_pubkey.to_string(),
                     "recentBlockhashesSysvar": sysvar::recent_blockhashes::ID.to_string(),
@@ -651,11 +1326,12 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "authorizeNonce".to_string(),
+                instruction_type: "authorizeNonce".into(),
                 info: json!({
                     "nonceAccount": nonce_pubkey.to_string(),
                     "newAuthorized": new_authority_pubkey.to_string(),
                     "nonceAuthority": authorized_pubkey.to_string(),
+                    "authorityUnchanged": false,
                 }),
             }
         );
@@ -667,5 +1343,71 @@ mod test {
         let keys = message.account_keys.clone();
         message.instructions[0].accounts.pop();
         assert!(parse_system(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
+
+        // Rotating to the current authority is a no-op
+        let instruction = system_instruction::authorize_nonce_account(
+            &nonce_pubkey,
+            &authorized_pubkey,
+            &authorized_pubkey,
+        );
+        let message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_system(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "authorizeNonce".into(),
+                info: json!({
+                    "nonceAccount": nonce_pubkey.to_string(),
+                    "newAuthorized": authorized_pubkey.to_string(),
+                    "nonceAuthority": authorized_pubkey.to_string(),
+                    "authorityUnchanged": true,
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn test_aggregate_transfers() {
+        let payer = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let recipients: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let mut instructions: Vec<_> = recipients
+            .iter()
+            .zip([100, 200, 300])
+            .map(|(recipient, lamports)| system_instruction::transfer(&payer, recipient, lamports))
+            .collect();
+        // A lone transfer from another source is not a batch
+        instructions.push(system_instruction::transfer(&other, &recipients[0], 400));
+        instructions.push(system_instruction::allocate(&payer, 128));
+        let message = Message::new(&instructions, Some(&payer));
+        assert_eq!(
+            aggregate_transfers(&message),
+            vec![TransferBatch {
+                source: payer.to_string(),
+                destinations: recipients
+                    .iter()
+                    .zip([100, 200, 300])
+                    .map(|(recipient, lamports)| TransferDestination {
+                        destination: recipient.to_string(),
+                        lamports,
+                    })
+                    .collect(),
+                total: 600,
+            }]
+        );
+
+        let message = Message::new(&instructions[3..], Some(&payer));
+        assert!(aggregate_transfers(&message).is_empty());
+
+        // Unsanitized program indexes are skipped
+        let mut message = Message::new(&instructions, Some(&payer));
+        message.instructions[2].program_id_index = message.account_keys.len() as u8;
+        let batches = aggregate_transfers(&message);
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].destinations.len(), 2);
+        assert_eq!(batches[0].total, 300);
     }
 }
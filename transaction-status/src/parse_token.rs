@@ -1,6 +1,10 @@
 use {
-    crate::parse_instruction::{
-        check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            check_num_accounts, ParsableProgram, ParseConfig, ParseInstructionError,
+            ParsedInstructionEnum,
+        },
     },
     extension::{
         default_account_state::*, memo_transfer::*, mint_close_authority::*, reallocate::*,
@@ -8,27 +12,67 @@ use {
     },
     serde_json::{json, Map, Value},
     solana_account_decoder::parse_token::{
-        pubkey_from_spl_token, token_amount_to_ui_amount, UiAccountState,
+        pubkey_from_spl_token, spl_token_native_mint, token_amount_to_ui_amount, UiAccountState,
     },
     solana_sdk::{
         instruction::{AccountMeta, CompiledInstruction, Instruction},
         message::AccountKeys,
+        pubkey::Pubkey as SdkPubkey,
     },
     spl_token_2022::{
-        extension::ExtensionType,
+        extension::{ExtensionType, StateWithExtensions},
         instruction::{AuthorityType, TokenInstruction},
         solana_program::{
             instruction::Instruction as SplTokenInstruction, program_option::COption,
             pubkey::Pubkey,
         },
+        state::Mint,
     },
 };
 
 mod extension;
 
+lazy_static! {
+    static ref NATIVE_MINT_IDS: [SdkPubkey; 2] = [
+        spl_token_native_mint(),
+        SdkPubkey::new_from_array(spl_token_2022::native_mint::id().to_bytes()),
+    ];
+}
+
+/// Parses an SPL Token or Token-2022 instruction. The top-level `checked` flag, see
+/// [`is_checked_token_instruction`], is only added by the
+/// [`parse`](crate::parse_instruction::parse) dispatcher.
 pub fn parse_token(
     instruction: &CompiledInstruction,
     account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    parse_token_with_config(instruction, account_keys, &ParseConfig::default())
+}
+
+pub fn parse_token_with_config(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+    config: &ParseConfig,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    let mut parsed = parse_token_instruction(instruction, account_keys, config)?;
+    // Whichever key names it, e.g. `mint` or `nativeMint`
+    let references_native_mint = instruction.accounts.iter().any(|index| {
+        account_keys
+            .get(*index as usize)
+            .map_or(false, is_wrapped_sol_mint)
+    });
+    if references_native_mint {
+        if let Some(info) = parsed.info.as_object_mut() {
+            info.insert("mintIsWrappedSol".to_string(), json!(true));
+        }
+    }
+    Ok(parsed)
+}
+
+fn parse_token_instruction(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+    config: &ParseConfig,
 ) -> Result<ParsedInstructionEnum, ParseInstructionError> {
     let token_instruction = TokenInstruction::unpack(&instruction.data)
         .map_err(|_| ParseInstructionError::InstructionNotParsable(ParsableProgram::SplToken))?;
@@ -62,7 +106,7 @@ pub fn parse_token(
                 );
             }
             Ok(ParsedInstructionEnum {
-                instruction_type: "initializeMint".to_string(),
+                instruction_type: instruction_types::token::INITIALIZE_MINT.into(),
                 info: value,
             })
         }
@@ -85,14 +129,14 @@ pub fn parse_token(
                 );
             }
             Ok(ParsedInstructionEnum {
-                instruction_type: "initializeMint2".to_string(),
+                instruction_type: instruction_types::token::INITIALIZE_MINT2.into(),
                 info: value,
             })
         }
         TokenInstruction::InitializeAccount => {
             check_num_token_accounts(&instruction.accounts, 4)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "initializeAccount".to_string(),
+                instruction_type: instruction_types::token::INITIALIZE_ACCOUNT.into(),
                 info: json!({
                     "account": account_keys[instruction.accounts[0] as usize].to_string(),
                     "mint": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -104,7 +148,7 @@ pub fn parse_token(
         TokenInstruction::InitializeAccount2 { owner } => {
             check_num_token_accounts(&instruction.accounts, 3)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "initializeAccount2".to_string(),
+                instruction_type: instruction_types::token::INITIALIZE_ACCOUNT2.into(),
                 info: json!({
                     "account": account_keys[instruction.accounts[0] as usize].to_string(),
                     "mint": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -116,7 +160,7 @@ pub fn parse_token(
         TokenInstruction::InitializeAccount3 { owner } => {
             check_num_token_accounts(&instruction.accounts, 2)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "initializeAccount3".to_string(),
+                instruction_type: instruction_types::token::INITIALIZE_ACCOUNT3.into(),
                 info: json!({
                     "account": account_keys[instruction.accounts[0] as usize].to_string(),
                     "mint": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -131,7 +175,7 @@ pub fn parse_token(
                 signers.push(account_keys[*i as usize].to_string());
             }
             Ok(ParsedInstructionEnum {
-                instruction_type: "initializeMultisig".to_string(),
+                instruction_type: instruction_types::token::INITIALIZE_MULTISIG.into(),
                 info: json!({
                     "multisig": account_keys[instruction.accounts[0] as usize].to_string(),
                     "rentSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -147,7 +191,7 @@ pub fn parse_token(
                 signers.push(account_keys[*i as usize].to_string());
             }
             Ok(ParsedInstructionEnum {
-                instruction_type: "initializeMultisig2".to_string(),
+                instruction_type: instruction_types::token::INITIALIZE_MULTISIG2.into(),
                 info: json!({
                     "multisig": account_keys[instruction.accounts[0] as usize].to_string(),
                     "signers": signers,
@@ -173,7 +217,7 @@ pub fn parse_token(
                 "multisigAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "transfer".to_string(),
+                instruction_type: instruction_types::token::TRANSFER.into(),
                 info: value,
             })
         }
@@ -194,7 +238,7 @@ pub fn parse_token(
                 "multisigOwner",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "approve".to_string(),
+                instruction_type: instruction_types::token::APPROVE.into(),
                 info: value,
             })
         }
@@ -213,7 +257,7 @@ pub fn parse_token(
                 "multisigOwner",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "revoke".to_string(),
+                instruction_type: instruction_types::token::REVOKE.into(),
                 info: value,
             })
         }
@@ -245,7 +289,7 @@ pub fn parse_token(
                 "multisigAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "setAuthority".to_string(),
+                instruction_type: instruction_types::token::SET_AUTHORITY.into(),
                 info: value,
             })
         }
@@ -266,7 +310,7 @@ pub fn parse_token(
                 "multisigMintAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "mintTo".to_string(),
+                instruction_type: instruction_types::token::MINT_TO.into(),
                 info: value,
             })
         }
@@ -287,14 +331,25 @@ pub fn parse_token(
                 "multisigAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "burn".to_string(),
+                instruction_type: instruction_types::token::BURN.into(),
                 info: value,
             })
         }
         TokenInstruction::CloseAccount => {
             check_num_token_accounts(&instruction.accounts, 3)?;
+            // Token-2022 mints with a close authority are closed by the same instruction, which
+            // only the closed account's data tells apart
+            let closed = &account_keys[instruction.accounts[0] as usize];
+            let closes_mint = config.account_data.get(closed).map_or(false, |data| {
+                StateWithExtensions::<Mint>::unpack(data).is_ok()
+            });
+            let (closed_name, authority, multisig_authority) = if closes_mint {
+                ("mint", "closeAuthority", "multisigCloseAuthority")
+            } else {
+                ("account", "owner", "multisigOwner")
+            };
             let mut value = json!({
-                "account": account_keys[instruction.accounts[0] as usize].to_string(),
+                closed_name: closed.to_string(),
                 "destination": account_keys[instruction.accounts[1] as usize].to_string(),
             });
             let map = value.as_object_mut().unwrap();
@@ -303,19 +358,22 @@ pub fn parse_token(
                 2,
                 account_keys,
                 &instruction.accounts,
-                "owner",
-                "multisigOwner",
+                authority,
+                multisig_authority,
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "closeAccount".to_string(),
+                instruction_type: instruction_types::token::CLOSE_ACCOUNT.into(),
                 info: value,
             })
         }
         TokenInstruction::FreezeAccount => {
             check_num_token_accounts(&instruction.accounts, 3)?;
+            // The account's state once the instruction lands, so a freeze and a thaw can be told
+            // apart without matching on the instruction type
             let mut value = json!({
                 "account": account_keys[instruction.accounts[0] as usize].to_string(),
                 "mint": account_keys[instruction.accounts[1] as usize].to_string(),
+                "frozen": true,
             });
             let map = value.as_object_mut().unwrap();
             parse_signers(
@@ -327,7 +385,7 @@ pub fn parse_token(
                 "multisigFreezeAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "freezeAccount".to_string(),
+                instruction_type: instruction_types::token::FREEZE_ACCOUNT.into(),
                 info: value,
             })
         }
@@ -336,6 +394,7 @@ pub fn parse_token(
             let mut value = json!({
                 "account": account_keys[instruction.accounts[0] as usize].to_string(),
                 "mint": account_keys[instruction.accounts[1] as usize].to_string(),
+                "frozen": false,
             });
             let map = value.as_object_mut().unwrap();
             parse_signers(
@@ -347,7 +406,7 @@ pub fn parse_token(
                 "multisigFreezeAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "thawAccount".to_string(),
+                instruction_type: instruction_types::token::THAW_ACCOUNT.into(),
                 info: value,
             })
         }
@@ -369,7 +428,7 @@ pub fn parse_token(
                 "multisigAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "transferChecked".to_string(),
+                instruction_type: instruction_types::token::TRANSFER_CHECKED.into(),
                 info: value,
             })
         }
@@ -391,7 +450,7 @@ pub fn parse_token(
                 "multisigOwner",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "approveChecked".to_string(),
+                instruction_type: instruction_types::token::APPROVE_CHECKED.into(),
                 info: value,
             })
         }
@@ -412,7 +471,7 @@ pub fn parse_token(
                 "multisigMintAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "mintToChecked".to_string(),
+                instruction_type: instruction_types::token::MINT_TO_CHECKED.into(),
                 info: value,
             })
         }
@@ -433,14 +492,14 @@ pub fn parse_token(
                 "multisigAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "burnChecked".to_string(),
+                instruction_type: instruction_types::token::BURN_CHECKED.into(),
                 info: value,
             })
         }
         TokenInstruction::SyncNative => {
             check_num_token_accounts(&instruction.accounts, 1)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "syncNative".to_string(),
+                instruction_type: instruction_types::token::SYNC_NATIVE.into(),
                 info: json!({
                     "account": account_keys[instruction.accounts[0] as usize].to_string(),
                 }),
@@ -462,14 +521,14 @@ pub fn parse_token(
                 );
             }
             Ok(ParsedInstructionEnum {
-                instruction_type: "getAccountDataSize".to_string(),
+                instruction_type: instruction_types::token::GET_ACCOUNT_DATA_SIZE.into(),
                 info: value,
             })
         }
         TokenInstruction::InitializeImmutableOwner => {
             check_num_token_accounts(&instruction.accounts, 1)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "initializeImmutableOwner".to_string(),
+                instruction_type: instruction_types::token::INITIALIZE_IMMUTABLE_OWNER.into(),
                 info: json!({
                     "account": account_keys[instruction.accounts[0] as usize].to_string(),
                 }),
@@ -478,7 +537,7 @@ pub fn parse_token(
         TokenInstruction::AmountToUiAmount { amount } => {
             check_num_token_accounts(&instruction.accounts, 1)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "amountToUiAmount".to_string(),
+                instruction_type: instruction_types::token::AMOUNT_TO_UI_AMOUNT.into(),
                 info: json!({
                     "mint": account_keys[instruction.accounts[0] as usize].to_string(),
                     "amount": amount,
@@ -487,12 +546,21 @@ pub fn parse_token(
         }
         TokenInstruction::UiAmountToAmount { ui_amount } => {
             check_num_token_accounts(&instruction.accounts, 1)?;
+            let mint = &account_keys[instruction.accounts[0] as usize];
+            let mut value = json!({
+                "mint": mint.to_string(),
+                "uiAmount": ui_amount,
+            });
+            let amount = config.mint_decimals.get(mint).and_then(|decimals| {
+                spl_token_2022::try_ui_amount_into_amount(ui_amount.to_string(), *decimals).ok()
+            });
+            if let Some(amount) = amount {
+                let map = value.as_object_mut().unwrap();
+                map.insert("amount".to_string(), json!(amount.to_string()));
+            }
             Ok(ParsedInstructionEnum {
-                instruction_type: "uiAmountToAmount".to_string(),
-                info: json!({
-                    "mint": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "uiAmount": ui_amount,
-                }),
+                instruction_type: instruction_types::token::UI_AMOUNT_TO_AMOUNT.into(),
+                info: value,
             })
         }
         TokenInstruction::InitializeMintCloseAuthority { close_authority } => {
@@ -542,7 +610,7 @@ pub fn parse_token(
         TokenInstruction::CreateNativeMint => {
             check_num_token_accounts(&instruction.accounts, 3)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "createNativeMint".to_string(),
+                instruction_type: instruction_types::token::CREATE_NATIVE_MINT.into(),
                 info: json!({
                     "payer": account_keys[instruction.accounts[0] as usize].to_string(),
                     "nativeMint": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -611,7 +679,7 @@ impl From<ExtensionType> for UiExtensionType {
     }
 }
 
-fn parse_signers(
+pub(crate) fn parse_signers(
     map: &mut Map<String, Value>,
     last_nonsigner_index: usize,
     account_keys: &AccountKeys,
@@ -637,6 +705,24 @@ fn parse_signers(
     }
 }
 
+/// Whether a token instruction restates the mint decimals for the program to verify, as the
+/// `*Checked` variants do
+pub fn is_checked_token_instruction(instruction_type: &str) -> bool {
+    matches!(
+        instruction_type,
+        instruction_types::token::TRANSFER_CHECKED
+            | instruction_types::token::APPROVE_CHECKED
+            | instruction_types::token::MINT_TO_CHECKED
+            | instruction_types::token::BURN_CHECKED
+            | instruction_types::token::TRANSFER_CHECKED_WITH_FEE
+    )
+}
+
+/// Whether `mint` is the native mint of either token program, whose tokens are wrapped SOL
+pub fn is_wrapped_sol_mint(mint: &SdkPubkey) -> bool {
+    NATIVE_MINT_IDS.contains(mint)
+}
+
 fn check_num_token_accounts(accounts: &[u8], num: usize) -> Result<(), ParseInstructionError> {
     check_num_accounts(accounts, num, ParsableProgram::SplToken)
 }
@@ -725,7 +811,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeMint".to_string(),
+                instruction_type: "initializeMint".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "decimals": 2,
@@ -753,7 +839,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeMint".to_string(),
+                instruction_type: "initializeMint".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "decimals": 2,
@@ -781,7 +867,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeMint2".to_string(),
+                instruction_type: "initializeMint2".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "decimals": 2,
@@ -810,7 +896,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeAccount".to_string(),
+                instruction_type: "initializeAccount".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "mint": mint_pubkey.to_string(),
@@ -837,7 +923,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeAccount2".to_string(),
+                instruction_type: "initializeAccount2".into(),
                 info: json!({
                    "account": account_pubkey.to_string(),
                    "mint": mint_pubkey.to_string(),
@@ -864,7 +950,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeAccount3".to_string(),
+                instruction_type: "initializeAccount3".into(),
                 info: json!({
                    "account": account_pubkey.to_string(),
                    "mint": mint_pubkey.to_string(),
@@ -898,7 +984,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeMultisig".to_string(),
+                instruction_type: "initializeMultisig".into(),
                 info: json!({
                     "multisig": multisig_pubkey.to_string(),
                     "m": 2,
@@ -933,7 +1019,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeMultisig2".to_string(),
+                instruction_type: "initializeMultisig2".into(),
                 info: json!({
                     "multisig": multisig_pubkey.to_string(),
                     "m": 2,
@@ -967,7 +1053,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "transfer".to_string(),
+                instruction_type: "transfer".into(),
                 info: json!({
                     "source": account_pubkey.to_string(),
                     "destination": recipient.to_string(),
@@ -999,7 +1085,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "transfer".to_string(),
+                instruction_type: "transfer".into(),
                 info: json!({
                     "source": account_pubkey.to_string(),
                     "destination": recipient.to_string(),
@@ -1032,7 +1118,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "approve".to_string(),
+                instruction_type: "approve".into(),
                 info: json!({
                     "source": account_pubkey.to_string(),
                     "delegate": recipient.to_string(),
@@ -1063,7 +1149,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "approve".to_string(),
+                instruction_type: "approve".into(),
                 info: json!({
                     "source": account_pubkey.to_string(),
                     "delegate": recipient.to_string(),
@@ -1094,7 +1180,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "revoke".to_string(),
+                instruction_type: "revoke".into(),
                 info: json!({
                     "source": account_pubkey.to_string(),
                     "owner": owner.to_string(),
@@ -1122,7 +1208,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "setAuthority".to_string(),
+                instruction_type: "setAuthority".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "newAuthority": new_freeze_authority.to_string(),
@@ -1151,7 +1237,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "setAuthority".to_string(),
+                instruction_type: "setAuthority".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "newAuthority": new_authority,
@@ -1180,7 +1266,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "mintTo".to_string(),
+                instruction_type: "mintTo".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "account": account_pubkey.to_string(),
@@ -1209,7 +1295,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "burn".to_string(),
+                instruction_type: "burn".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "mint": mint_pubkey.to_string(),
@@ -1237,7 +1323,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "closeAccount".to_string(),
+                instruction_type: "closeAccount".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "destination": recipient.to_string(),
@@ -1246,7 +1332,7 @@ mod test {
             }
         );
 
-        // Test FreezeAccount
+        // Test FreezeAccount, incl multisig
         let freeze_account_ix = freeze_account(
             program_id,
             &convert_pubkey(account_pubkey),
@@ -1264,16 +1350,51 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "freezeAccount".to_string(),
+                instruction_type: "freezeAccount".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "mint": mint_pubkey.to_string(),
+                    "frozen": true,
                     "freezeAuthority": freeze_authority.to_string(),
                 })
             }
         );
 
-        // Test ThawAccount
+        let freeze_account_ix = freeze_account(
+            program_id,
+            &convert_pubkey(account_pubkey),
+            &convert_pubkey(mint_pubkey),
+            &convert_pubkey(multisig_pubkey),
+            &[
+                &convert_pubkey(multisig_signer0),
+                &convert_pubkey(multisig_signer1),
+            ],
+        )
+        .unwrap();
+        let message = Message::new(&[freeze_account_ix], None);
+        let compiled_instruction = convert_compiled_instruction(&message.instructions[0]);
+        assert_eq!(
+            parse_token(
+                &compiled_instruction,
+                &AccountKeys::new(&convert_account_keys(&message), None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "freezeAccount".into(),
+                info: json!({
+                    "account": account_pubkey.to_string(),
+                    "mint": mint_pubkey.to_string(),
+                    "frozen": true,
+                    "multisigFreezeAuthority": multisig_pubkey.to_string(),
+                    "signers": vec![
+                        multisig_signer0.to_string(),
+                        multisig_signer1.to_string(),
+                    ],
+                })
+            }
+        );
+
+        // Test ThawAccount, incl multisig
         let thaw_account_ix = thaw_account(
             program_id,
             &convert_pubkey(account_pubkey),
@@ -1291,15 +1412,50 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "thawAccount".to_string(),
+                instruction_type: "thawAccount".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "mint": mint_pubkey.to_string(),
+                    "frozen": false,
                     "freezeAuthority": freeze_authority.to_string(),
                 })
             }
         );
 
+        let thaw_account_ix = thaw_account(
+            program_id,
+            &convert_pubkey(account_pubkey),
+            &convert_pubkey(mint_pubkey),
+            &convert_pubkey(multisig_pubkey),
+            &[
+                &convert_pubkey(multisig_signer0),
+                &convert_pubkey(multisig_signer1),
+            ],
+        )
+        .unwrap();
+        let message = Message::new(&[thaw_account_ix], None);
+        let compiled_instruction = convert_compiled_instruction(&message.instructions[0]);
+        assert_eq!(
+            parse_token(
+                &compiled_instruction,
+                &AccountKeys::new(&convert_account_keys(&message), None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "thawAccount".into(),
+                info: json!({
+                    "account": account_pubkey.to_string(),
+                    "mint": mint_pubkey.to_string(),
+                    "frozen": false,
+                    "multisigFreezeAuthority": multisig_pubkey.to_string(),
+                    "signers": vec![
+                        multisig_signer0.to_string(),
+                        multisig_signer1.to_string(),
+                    ],
+                })
+            }
+        );
+
         // Test TransferChecked, incl multisig
         let transfer_ix = transfer_checked(
             program_id,
@@ -1321,7 +1477,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "transferChecked".to_string(),
+                instruction_type: "transferChecked".into(),
                 info: json!({
                     "source": account_pubkey.to_string(),
                     "destination": recipient.to_string(),
@@ -1360,7 +1516,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "transferChecked".to_string(),
+                instruction_type: "transferChecked".into(),
                 info: json!({
                     "source": account_pubkey.to_string(),
                     "destination": recipient.to_string(),
@@ -1401,7 +1557,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "approveChecked".to_string(),
+                instruction_type: "approveChecked".into(),
                 info: json!({
                     "source": account_pubkey.to_string(),
                     "mint": mint_pubkey.to_string(),
@@ -1440,7 +1596,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "approveChecked".to_string(),
+                instruction_type: "approveChecked".into(),
                 info: json!({
                     "source": account_pubkey.to_string(),
                     "mint": mint_pubkey.to_string(),
@@ -1480,7 +1636,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "mintToChecked".to_string(),
+                instruction_type: "mintToChecked".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "account": account_pubkey.to_string(),
@@ -1515,7 +1671,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "burnChecked".to_string(),
+                instruction_type: "burnChecked".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "mint": mint_pubkey.to_string(),
@@ -1541,7 +1697,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "syncNative".to_string(),
+                instruction_type: "syncNative".into(),
                 info: json!({
                    "account": account_pubkey.to_string(),
                 })
@@ -1560,7 +1716,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeImmutableOwner".to_string(),
+                instruction_type: "initializeImmutableOwner".into(),
                 info: json!({
                    "account": account_pubkey.to_string(),
                 })
@@ -1583,7 +1739,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "getAccountDataSize".to_string(),
+                instruction_type: "getAccountDataSize".into(),
                 info: json!({
                    "mint": mint_pubkey.to_string(),
                 })
@@ -1605,7 +1761,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "getAccountDataSize".to_string(),
+                instruction_type: "getAccountDataSize".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "extensionTypes": [
@@ -1628,7 +1784,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "amountToUiAmount".to_string(),
+                instruction_type: "amountToUiAmount".into(),
                 info: json!({
                    "mint": mint_pubkey.to_string(),
                    "amount": 4242,
@@ -1648,7 +1804,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "uiAmountToAmount".to_string(),
+                instruction_type: "uiAmountToAmount".into(),
                 info: json!({
                    "mint": mint_pubkey.to_string(),
                    "uiAmount": "42.42",
@@ -1669,6 +1825,135 @@ mod test {
         test_parse_token(&spl_token_2022::id());
     }
 
+    #[test]
+    fn test_parse_token_ui_amount_to_amount_with_decimals() {
+        let mint_pubkey = Pubkey::new_unique();
+        let instruction =
+            ui_amount_to_amount(&spl_token::id(), &convert_pubkey(mint_pubkey), "1.5").unwrap();
+        let message = Message::new(&[instruction], None);
+        let compiled_instruction = convert_compiled_instruction(&message.instructions[0]);
+        let account_keys = convert_account_keys(&message);
+        let account_keys = AccountKeys::new(&account_keys, None);
+
+        let mut config = ParseConfig::default();
+        config.mint_decimals.insert(mint_pubkey, 3);
+        assert_eq!(
+            parse_token_with_config(&compiled_instruction, &account_keys, &config).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "uiAmountToAmount".into(),
+                info: json!({
+                   "mint": mint_pubkey.to_string(),
+                   "uiAmount": "1.5",
+                   "amount": "1500",
+                })
+            }
+        );
+
+        // Decimals of another mint don't apply
+        config.mint_decimals.clear();
+        config.mint_decimals.insert(Pubkey::new_unique(), 3);
+        assert!(
+            parse_token_with_config(&compiled_instruction, &account_keys, &config)
+                .unwrap()
+                .info
+                .get("amount")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_parse_token_checked_mint() {
+        let program_id = spl_token::id();
+        let account = convert_pubkey(Pubkey::new_unique());
+        let mint = convert_pubkey(Pubkey::new_unique());
+        let other = convert_pubkey(Pubkey::new_unique());
+        let owner = convert_pubkey(Pubkey::new_unique());
+        let parse_mint = |instruction| {
+            let message = Message::new(&[instruction], None);
+            let compiled_instruction = convert_compiled_instruction(&message.instructions[0]);
+            parse_token(
+                &compiled_instruction,
+                &AccountKeys::new(&convert_account_keys(&message), None),
+            )
+            .unwrap()
+            .info
+            .get("mint")
+            .cloned()
+        };
+        let expected_mint = Some(json!(mint.to_string()));
+
+        let checked = [
+            transfer_checked(&program_id, &account, &mint, &other, &owner, &[], 1, 2),
+            approve_checked(&program_id, &account, &mint, &other, &owner, &[], 1, 2),
+            mint_to_checked(&program_id, &mint, &account, &owner, &[], 1, 2),
+            burn_checked(&program_id, &account, &mint, &owner, &[], 1, 2),
+        ];
+        for instruction in checked {
+            assert_eq!(parse_mint(instruction.unwrap()), expected_mint);
+        }
+
+        // Unchecked transfers and approvals never see the mint
+        #[allow(deprecated)]
+        let unchecked = [
+            transfer(&program_id, &account, &other, &owner, &[], 1),
+            approve(&program_id, &account, &other, &owner, &[], 1),
+        ];
+        for instruction in unchecked {
+            assert_eq!(parse_mint(instruction.unwrap()), None);
+        }
+        // ...whereas mintTo and burn always take it as an account
+        let unchecked_with_mint = [
+            mint_to(&program_id, &mint, &account, &owner, &[], 1),
+            burn(&program_id, &account, &mint, &owner, &[], 1),
+        ];
+        for instruction in unchecked_with_mint {
+            assert_eq!(parse_mint(instruction.unwrap()), expected_mint);
+        }
+    }
+
+    #[test]
+    fn test_parse_token_checked_ui_amount_string() {
+        // Vectors observed from the RPC jsonParsed `uiAmountString`
+        let vectors = [
+            (100, 9, "0.0000001"),
+            (0, 9, "0"),
+            (1, 9, "0.000000001"),
+            (1_000_000_000, 9, "1"),
+            (1_500_000_000, 9, "1.5"),
+            (1_234_567_890, 3, "1234567.89"),
+            (42, 0, "42"),
+            (u64::MAX, 0, "18446744073709551615"),
+            (u64::MAX, 19, "1.8446744073709551615"),
+        ];
+        for (amount, decimals, ui_amount_string) in vectors {
+            let transfer_ix = transfer_checked(
+                &spl_token::id(),
+                &convert_pubkey(Pubkey::new_unique()),
+                &convert_pubkey(Pubkey::new_unique()),
+                &convert_pubkey(Pubkey::new_unique()),
+                &convert_pubkey(Pubkey::new_unique()),
+                &[],
+                amount,
+                decimals,
+            )
+            .unwrap();
+            let message = Message::new(&[transfer_ix], None);
+            let compiled_instruction = convert_compiled_instruction(&message.instructions[0]);
+            let parsed = parse_token(
+                &compiled_instruction,
+                &AccountKeys::new(&convert_account_keys(&message), None),
+            )
+            .unwrap();
+            assert_eq!(
+                parsed.info["tokenAmount"]["uiAmountString"],
+                json!(ui_amount_string),
+                "amount={}, decimals={}",
+                amount,
+                decimals
+            );
+        }
+    }
+
     #[test]
     fn test_create_native_mint() {
         let payer = Pubkey::new_unique();
@@ -1683,16 +1968,61 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "createNativeMint".to_string(),
+                instruction_type: "createNativeMint".into(),
                 info: json!({
                    "payer": payer.to_string(),
                    "nativeMint": spl_token_2022::native_mint::id().to_string(),
                    "systemProgram": solana_sdk::system_program::id().to_string(),
+                   "mintIsWrappedSol": true,
                 })
             }
         );
     }
 
+    #[test]
+    fn test_parse_token_wrapped_sol_mint() {
+        let account_pubkey = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        for (program_id, native_mint) in [
+            (spl_token::id(), spl_token::native_mint::id()),
+            (spl_token_2022::id(), spl_token_2022::native_mint::id()),
+        ] {
+            let program_id = SplTokenPubkey::new_from_array(program_id.to_bytes());
+            let native_mint = SplTokenPubkey::new_from_array(native_mint.to_bytes());
+            let initialize_account_ix = initialize_account(
+                &program_id,
+                &convert_pubkey(account_pubkey),
+                &native_mint,
+                &convert_pubkey(owner),
+            )
+            .unwrap();
+            let message = Message::new(&[initialize_account_ix], None);
+            let compiled_instruction = convert_compiled_instruction(&message.instructions[0]);
+            let parsed = parse_token(
+                &compiled_instruction,
+                &AccountKeys::new(&convert_account_keys(&message), None),
+            )
+            .unwrap();
+            assert_eq!(parsed.info["mintIsWrappedSol"], json!(true));
+        }
+
+        let initialize_account_ix = initialize_account(
+            &spl_token_2022::id(),
+            &convert_pubkey(account_pubkey),
+            &convert_pubkey(Pubkey::new_unique()),
+            &convert_pubkey(owner),
+        )
+        .unwrap();
+        let message = Message::new(&[initialize_account_ix], None);
+        let compiled_instruction = convert_compiled_instruction(&message.instructions[0]);
+        let parsed = parse_token(
+            &compiled_instruction,
+            &AccountKeys::new(&convert_account_keys(&message), None),
+        )
+        .unwrap();
+        assert!(parsed.info.get("mintIsWrappedSol").is_none());
+    }
+
     fn test_token_ix_not_enough_keys(program_id: &SplTokenPubkey) {
         let mut keys: Vec<Pubkey> = vec![];
         for _ in 0..10 {
@@ -1979,7 +2309,7 @@ mod test {
             compiled_instruction.accounts[0..compiled_instruction.accounts.len() - 1].to_vec();
         assert!(parse_token(&compiled_instruction, &AccountKeys::new(&keys, None)).is_err());
 
-        // Test FreezeAccount
+        // Test FreezeAccount, incl multisig
         let freeze_account_ix = freeze_account(
             program_id,
             &convert_pubkey(keys[1]),
@@ -1995,7 +2325,7 @@ mod test {
             compiled_instruction.accounts[0..compiled_instruction.accounts.len() - 1].to_vec();
         assert!(parse_token(&compiled_instruction, &AccountKeys::new(&keys, None)).is_err());
 
-        // Test ThawAccount
+        // Test ThawAccount, incl multisig
         let thaw_account_ix = thaw_account(
             program_id,
             &convert_pubkey(keys[1]),
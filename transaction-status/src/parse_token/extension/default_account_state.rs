@@ -14,12 +14,11 @@ pub(in crate::parse_token) fn parse_default_account_state_instruction(
         .map_err(|_| {
         ParseInstructionError::InstructionNotParsable(ParsableProgram::SplToken)
     })?;
-    let instruction_type = "DefaultAccountState";
     match default_account_state_instruction {
         DefaultAccountStateInstruction::Initialize => {
             check_num_token_accounts(account_indexes, 1)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: format!("initialize{}", instruction_type),
+                instruction_type: instruction_types::token::INITIALIZE_DEFAULT_ACCOUNT_STATE.into(),
                 info: json!({
                     "mint": account_keys[account_indexes[0] as usize].to_string(),
                     "accountState": UiAccountState::from(account_state),
@@ -42,7 +41,7 @@ pub(in crate::parse_token) fn parse_default_account_state_instruction(
                 "multisigFreezeAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: format!("update{}", instruction_type),
+                instruction_type: instruction_types::token::UPDATE_DEFAULT_ACCOUNT_STATE.into(),
                 info: value,
             })
         }
@@ -82,7 +81,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeDefaultAccountState".to_string(),
+                instruction_type: "initializeDefaultAccountState".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "accountState": "frozen",
@@ -109,7 +108,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "updateDefaultAccountState".to_string(),
+                instruction_type: "updateDefaultAccountState".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "accountState": "initialized",
@@ -142,7 +141,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "updateDefaultAccountState".to_string(),
+                instruction_type: "updateDefaultAccountState".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "accountState": "initialized",
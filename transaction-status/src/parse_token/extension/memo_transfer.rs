@@ -12,11 +12,15 @@ pub(in crate::parse_token) fn parse_memo_transfer_instruction(
     account_keys: &AccountKeys,
 ) -> Result<ParsedInstructionEnum, ParseInstructionError> {
     check_num_token_accounts(account_indexes, 2)?;
-    let instruction_type_str = match decode_instruction_type(instruction_data)
+    let instruction_type = match decode_instruction_type(instruction_data)
         .map_err(|_| ParseInstructionError::InstructionNotParsable(ParsableProgram::SplToken))?
     {
-        RequiredMemoTransfersInstruction::Enable => "enable",
-        RequiredMemoTransfersInstruction::Disable => "disable",
+        RequiredMemoTransfersInstruction::Enable => {
+            instruction_types::token::ENABLE_REQUIRED_MEMO_TRANSFERS
+        }
+        RequiredMemoTransfersInstruction::Disable => {
+            instruction_types::token::DISABLE_REQUIRED_MEMO_TRANSFERS
+        }
     };
     let mut value = json!({
         "account": account_keys[account_indexes[0] as usize].to_string(),
@@ -31,7 +35,7 @@ pub(in crate::parse_token) fn parse_memo_transfer_instruction(
         "multisigOwner",
     );
     Ok(ParsedInstructionEnum {
-        instruction_type: format!("{}RequiredMemoTransfers", instruction_type_str),
+        instruction_type: instruction_type.into(),
         info: value,
     })
 }
@@ -72,7 +76,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "enableRequiredMemoTransfers".to_string(),
+                instruction_type: "enableRequiredMemoTransfers".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "owner": owner_pubkey.to_string(),
@@ -103,7 +107,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "enableRequiredMemoTransfers".to_string(),
+                instruction_type: "enableRequiredMemoTransfers".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "multisigOwner": multisig_pubkey.to_string(),
@@ -116,14 +120,14 @@ mod test {
         );
 
         // Disable, single owner
-        let enable_memo_transfers_ix = disable_required_transfer_memos(
+        let disable_memo_transfers_ix = disable_required_transfer_memos(
             &spl_token_2022::id(),
             &convert_pubkey(account_pubkey),
             &convert_pubkey(owner_pubkey),
             &[],
         )
         .unwrap();
-        let message = Message::new(&[enable_memo_transfers_ix], None);
+        let message = Message::new(&[disable_memo_transfers_ix], None);
         let compiled_instruction = convert_compiled_instruction(&message.instructions[0]);
         assert_eq!(
             parse_token(
@@ -132,7 +136,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "disableRequiredMemoTransfers".to_string(),
+                instruction_type: "disableRequiredMemoTransfers".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "owner": owner_pubkey.to_string(),
@@ -140,11 +144,11 @@ mod test {
             }
         );
 
-        // Enable, multisig owner
+        // Disable, multisig owner
         let multisig_pubkey = Pubkey::new_unique();
         let multisig_signer0 = Pubkey::new_unique();
         let multisig_signer1 = Pubkey::new_unique();
-        let enable_memo_transfers_ix = disable_required_transfer_memos(
+        let disable_memo_transfers_ix = disable_required_transfer_memos(
             &spl_token_2022::id(),
             &convert_pubkey(account_pubkey),
             &convert_pubkey(multisig_pubkey),
@@ -154,7 +158,7 @@ mod test {
             ],
         )
         .unwrap();
-        let message = Message::new(&[enable_memo_transfers_ix], None);
+        let message = Message::new(&[disable_memo_transfers_ix], None);
         let compiled_instruction = convert_compiled_instruction(&message.instructions[0]);
         assert_eq!(
             parse_token(
@@ -163,7 +167,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "disableRequiredMemoTransfers".to_string(),
+                instruction_type: "disableRequiredMemoTransfers".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "multisigOwner": multisig_pubkey.to_string(),
@@ -174,5 +178,14 @@ mod test {
                 })
             }
         );
+
+        // Missing owner
+        let mut compiled_instruction = compiled_instruction;
+        compiled_instruction.accounts.truncate(1);
+        assert!(parse_token(
+            &compiled_instruction,
+            &AccountKeys::new(&convert_account_keys(&message), None)
+        )
+        .is_err());
     }
 }
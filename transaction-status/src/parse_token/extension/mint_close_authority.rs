@@ -10,7 +10,7 @@ pub(in crate::parse_token) fn parse_initialize_mint_close_authority_instruction(
 ) -> Result<ParsedInstructionEnum, ParseInstructionError> {
     check_num_token_accounts(account_indexes, 1)?;
     Ok(ParsedInstructionEnum {
-        instruction_type: "initializeMintCloseAuthority".to_string(),
+        instruction_type: instruction_types::token::INITIALIZE_MINT_CLOSE_AUTHORITY.into(),
         info: json!({
             "mint": account_keys[account_indexes[0] as usize].to_string(),
             "newAuthority": map_coption_pubkey(close_authority),
@@ -22,10 +22,16 @@ pub(in crate::parse_token) fn parse_initialize_mint_close_authority_instruction(
 mod test {
     use {
         super::*,
-        crate::parse_token::test::*,
+        crate::{parse_instruction::ParseConfig, parse_token::test::*},
         serde_json::Value,
         solana_sdk::pubkey::Pubkey,
-        spl_token_2022::{instruction::*, solana_program::message::Message},
+        spl_token_2022::{
+            extension::{mint_close_authority::MintCloseAuthority, StateWithExtensionsMut},
+            instruction::*,
+            pod::OptionalNonZeroPubkey,
+            solana_program::message::Message,
+            state::Mint,
+        },
     };
 
     #[test]
@@ -47,7 +53,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeMintCloseAuthority".to_string(),
+                instruction_type: "initializeMintCloseAuthority".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "newAuthority": close_authority.to_string(),
@@ -70,7 +76,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeMintCloseAuthority".to_string(),
+                instruction_type: "initializeMintCloseAuthority".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "newAuthority": Value::Null,
@@ -78,4 +84,94 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn test_parse_close_mint() {
+        let mint_pubkey = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let close_authority = Pubkey::new_unique();
+        let multisig_signer0 = Pubkey::new_unique();
+        let multisig_signer1 = Pubkey::new_unique();
+
+        let mut mint_data =
+            vec![0; ExtensionType::get_account_len::<Mint>(&[ExtensionType::MintCloseAuthority])];
+        let mut mint =
+            StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut mint_data).unwrap();
+        mint.init_extension::<MintCloseAuthority>()
+            .unwrap()
+            .close_authority =
+            OptionalNonZeroPubkey::try_from(Some(convert_pubkey(close_authority))).unwrap();
+        mint.base.is_initialized = true;
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+        let config = ParseConfig::default().with_account_data(mint_pubkey, mint_data);
+
+        let close_mint_ix = close_account(
+            &spl_token_2022::id(),
+            &convert_pubkey(mint_pubkey),
+            &convert_pubkey(destination),
+            &convert_pubkey(close_authority),
+            &[],
+        )
+        .unwrap();
+        let message = Message::new(&[close_mint_ix], None);
+        let compiled_instruction = convert_compiled_instruction(&message.instructions[0]);
+        let account_keys = convert_account_keys(&message);
+        let account_keys = AccountKeys::new(&account_keys, None);
+        assert_eq!(
+            parse_token_with_config(&compiled_instruction, &account_keys, &config).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "closeAccount".into(),
+                info: json!({
+                    "mint": mint_pubkey.to_string(),
+                    "destination": destination.to_string(),
+                    "closeAuthority": close_authority.to_string(),
+                })
+            }
+        );
+
+        // Without the mint's data, the closed account is assumed to be a token account
+        assert_eq!(
+            parse_token(&compiled_instruction, &account_keys)
+                .unwrap()
+                .info,
+            json!({
+                "account": mint_pubkey.to_string(),
+                "destination": destination.to_string(),
+                "owner": close_authority.to_string(),
+            })
+        );
+
+        let close_mint_ix = close_account(
+            &spl_token_2022::id(),
+            &convert_pubkey(mint_pubkey),
+            &convert_pubkey(destination),
+            &convert_pubkey(close_authority),
+            &[
+                &convert_pubkey(multisig_signer0),
+                &convert_pubkey(multisig_signer1),
+            ],
+        )
+        .unwrap();
+        let message = Message::new(&[close_mint_ix], None);
+        let compiled_instruction = convert_compiled_instruction(&message.instructions[0]);
+        assert_eq!(
+            parse_token_with_config(
+                &compiled_instruction,
+                &AccountKeys::new(&convert_account_keys(&message), None),
+                &config
+            )
+            .unwrap()
+            .info,
+            json!({
+                "mint": mint_pubkey.to_string(),
+                "destination": destination.to_string(),
+                "multisigCloseAuthority": close_authority.to_string(),
+                "signers": vec![
+                    multisig_signer0.to_string(),
+                    multisig_signer1.to_string(),
+                ],
+            })
+        );
+    }
 }
@@ -22,7 +22,7 @@ pub(in crate::parse_token) fn parse_reallocate_instruction(
         "multisigOwner",
     );
     Ok(ParsedInstructionEnum {
-        instruction_type: "reallocate".to_string(),
+        instruction_type: instruction_types::token::REALLOCATE.into(),
         info: value,
     })
 }
@@ -66,7 +66,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "reallocate".to_string(),
+                instruction_type: "reallocate".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "payer": payer_pubkey.to_string(),
@@ -102,7 +102,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "reallocate".to_string(),
+                instruction_type: "reallocate".into(),
                 info: json!({
                     "account": account_pubkey.to_string(),
                     "payer": payer_pubkey.to_string(),
@@ -32,7 +32,7 @@ pub(in crate::parse_token) fn parse_transfer_fee_instruction(
                 );
             }
             Ok(ParsedInstructionEnum {
-                instruction_type: "initializeTransferFeeConfig".to_string(),
+                instruction_type: instruction_types::token::INITIALIZE_TRANSFER_FEE_CONFIG.into(),
                 info: value,
             })
         }
@@ -59,7 +59,7 @@ pub(in crate::parse_token) fn parse_transfer_fee_instruction(
                 "multisigAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "transferCheckedWithFee".to_string(),
+                instruction_type: instruction_types::token::TRANSFER_CHECKED_WITH_FEE.into(),
                 info: value,
             })
         }
@@ -79,7 +79,8 @@ pub(in crate::parse_token) fn parse_transfer_fee_instruction(
                 "multisigWithdrawWithheldAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "withdrawWithheldTokensFromMint".to_string(),
+                instruction_type: instruction_types::token::WITHDRAW_WITHHELD_TOKENS_FROM_MINT
+                    .into(),
                 info: value,
             })
         }
@@ -107,7 +108,8 @@ pub(in crate::parse_token) fn parse_transfer_fee_instruction(
                 "multisigWithdrawWithheldAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "withdrawWithheldTokensFromAccounts".to_string(),
+                instruction_type: instruction_types::token::WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS
+                    .into(),
                 info: value,
             })
         }
@@ -123,7 +125,7 @@ pub(in crate::parse_token) fn parse_transfer_fee_instruction(
             }
             map.insert("sourceAccounts".to_string(), json!(source_accounts));
             Ok(ParsedInstructionEnum {
-                instruction_type: "harvestWithheldTokensToMint".to_string(),
+                instruction_type: instruction_types::token::HARVEST_WITHHELD_TOKENS_TO_MINT.into(),
                 info: value,
             })
         }
@@ -147,7 +149,7 @@ pub(in crate::parse_token) fn parse_transfer_fee_instruction(
                 "multisigtransferFeeConfigAuthority",
             );
             Ok(ParsedInstructionEnum {
-                instruction_type: "setTransferFee".to_string(),
+                instruction_type: instruction_types::token::SET_TRANSFER_FEE.into(),
                 info: value,
             })
         }
@@ -192,7 +194,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeTransferFeeConfig".to_string(),
+                instruction_type: "initializeTransferFeeConfig".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "transferFeeConfigAuthority": transfer_fee_config_authority.to_string(),
@@ -221,7 +223,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initializeTransferFeeConfig".to_string(),
+                instruction_type: "initializeTransferFeeConfig".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "transferFeeBasisPoints": transfer_fee_basis_points,
@@ -258,7 +260,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "transferCheckedWithFee".to_string(),
+                instruction_type: "transferCheckedWithFee".into(),
                 info: json!({
                     "source": account_pubkey.to_string(),
                     "mint": mint_pubkey.to_string(),
@@ -308,7 +310,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "transferCheckedWithFee".to_string(),
+                instruction_type: "transferCheckedWithFee".into(),
                 info: json!({
                     "source": account_pubkey.to_string(),
                     "mint": mint_pubkey.to_string(),
@@ -352,7 +354,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "withdrawWithheldTokensFromMint".to_string(),
+                instruction_type: "withdrawWithheldTokensFromMint".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "feeRecipient": recipient.to_string(),
@@ -382,7 +384,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "withdrawWithheldTokensFromMint".to_string(),
+                instruction_type: "withdrawWithheldTokensFromMint".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "feeRecipient": recipient.to_string(),
@@ -416,7 +418,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "withdrawWithheldTokensFromAccounts".to_string(),
+                instruction_type: "withdrawWithheldTokensFromAccounts".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "feeRecipient": recipient.to_string(),
@@ -451,7 +453,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "withdrawWithheldTokensFromAccounts".to_string(),
+                instruction_type: "withdrawWithheldTokensFromAccounts".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "feeRecipient": recipient.to_string(),
@@ -484,7 +486,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "harvestWithheldTokensToMint".to_string(),
+                instruction_type: "harvestWithheldTokensToMint".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "sourceAccounts": vec![
@@ -514,7 +516,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "setTransferFee".to_string(),
+                instruction_type: "setTransferFee".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "transferFeeBasisPoints": transfer_fee_basis_points,
@@ -546,7 +548,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "setTransferFee".to_string(),
+                instruction_type: "setTransferFee".into(),
                 info: json!({
                     "mint": mint_pubkey.to_string(),
                     "transferFeeBasisPoints": transfer_fee_basis_points,
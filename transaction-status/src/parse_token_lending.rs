@@ -0,0 +1,206 @@
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+        },
+    },
+    borsh::BorshDeserialize,
+    serde_json::json,
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys},
+};
+
+// Amounts in the packed `LendingInstruction` layout are little-endian `u64`s, so borsh reads
+// them as-is
+solana_sdk::declare_id!("LendZqTs7gn5CTSJU1jWKhKuVpjJGom45nnwPb2AMTi");
+
+const DEPOSIT_RESERVE_LIQUIDITY: u8 = 4;
+const REDEEM_RESERVE_COLLATERAL: u8 = 5;
+const BORROW_OBLIGATION_LIQUIDITY: u8 = 10;
+const REPAY_OBLIGATION_LIQUIDITY: u8 = 11;
+const LIQUIDATE_OBLIGATION: u8 = 12;
+
+pub fn parse_token_lending(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    match instruction.accounts.iter().max() {
+        Some(index) if (*index as usize) < account_keys.len() => {}
+        _ => {
+            // Runtime should prevent this from ever happening
+            return Err(ParseInstructionError::InstructionKeyMismatch(
+                ParsableProgram::SplTokenLending,
+            ));
+        }
+    }
+    let not_parsable =
+        || ParseInstructionError::InstructionNotParsable(ParsableProgram::SplTokenLending);
+    let (tag, data) = instruction.data.split_first().ok_or_else(not_parsable)?;
+    let key = |i: usize| account_keys[instruction.accounts[i] as usize].to_string();
+    // Every supported instruction takes a single amount
+    let amount = || u64::try_from_slice(data).map_err(|_| not_parsable());
+    match *tag {
+        DEPOSIT_RESERVE_LIQUIDITY => {
+            let liquidity_amount = amount()?;
+            check_num_token_lending_accounts(&instruction.accounts, 10)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_lending::DEPOSIT_RESERVE_LIQUIDITY
+                    .into(),
+                info: json!({
+                    "sourceLiquidity": key(0),
+                    "destinationCollateral": key(1),
+                    "reserve": key(2),
+                    "reserveLiquiditySupply": key(3),
+                    "reserveCollateralMint": key(4),
+                    "lendingMarket": key(5),
+                    "lendingMarketAuthority": key(6),
+                    "userTransferAuthority": key(7),
+                    "clockSysvar": key(8),
+                    "tokenProgram": key(9),
+                    "liquidityAmount": liquidity_amount,
+                }),
+            })
+        }
+        REDEEM_RESERVE_COLLATERAL => {
+            let collateral_amount = amount()?;
+            check_num_token_lending_accounts(&instruction.accounts, 10)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_lending::REDEEM_RESERVE_COLLATERAL
+                    .into(),
+                info: json!({
+                    "sourceCollateral": key(0),
+                    "destinationLiquidity": key(1),
+                    "reserve": key(2),
+                    "reserveCollateralMint": key(3),
+                    "reserveLiquiditySupply": key(4),
+                    "lendingMarket": key(5),
+                    "lendingMarketAuthority": key(6),
+                    "userTransferAuthority": key(7),
+                    "clockSysvar": key(8),
+                    "tokenProgram": key(9),
+                    "collateralAmount": collateral_amount,
+                }),
+            })
+        }
+        BORROW_OBLIGATION_LIQUIDITY => {
+            let liquidity_amount = amount()?;
+            check_num_token_lending_accounts(&instruction.accounts, 10)?;
+            let mut value = json!({
+                "sourceLiquidity": key(0),
+                "destinationLiquidity": key(1),
+                "borrowReserve": key(2),
+                "borrowReserveLiquidityFeeReceiver": key(3),
+                "obligation": key(4),
+                "lendingMarket": key(5),
+                "lendingMarketAuthority": key(6),
+                "obligationOwner": key(7),
+                "clockSysvar": key(8),
+                "tokenProgram": key(9),
+                "liquidityAmount": liquidity_amount,
+            });
+            if instruction.accounts.len() >= 11 {
+                let map = value.as_object_mut().unwrap();
+                map.insert("hostFeeReceiver".to_string(), json!(key(10)));
+            }
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_lending::BORROW_OBLIGATION_LIQUIDITY
+                    .into(),
+                info: value,
+            })
+        }
+        REPAY_OBLIGATION_LIQUIDITY => {
+            let liquidity_amount = amount()?;
+            check_num_token_lending_accounts(&instruction.accounts, 8)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_lending::REPAY_OBLIGATION_LIQUIDITY
+                    .into(),
+                info: json!({
+                    "sourceLiquidity": key(0),
+                    "destinationLiquidity": key(1),
+                    "repayReserve": key(2),
+                    "obligation": key(3),
+                    "lendingMarket": key(4),
+                    "userTransferAuthority": key(5),
+                    "clockSysvar": key(6),
+                    "tokenProgram": key(7),
+                    "liquidityAmount": liquidity_amount,
+                }),
+            })
+        }
+        LIQUIDATE_OBLIGATION => {
+            let liquidity_amount = amount()?;
+            check_num_token_lending_accounts(&instruction.accounts, 12)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_lending::LIQUIDATE_OBLIGATION.into(),
+                info: json!({
+                    "sourceLiquidity": key(0),
+                    "destinationCollateral": key(1),
+                    "repayReserve": key(2),
+                    "repayReserveLiquiditySupply": key(3),
+                    "withdrawReserve": key(4),
+                    "withdrawReserveCollateralSupply": key(5),
+                    "obligation": key(6),
+                    "lendingMarket": key(7),
+                    "lendingMarketAuthority": key(8),
+                    "userTransferAuthority": key(9),
+                    "clockSysvar": key(10),
+                    "tokenProgram": key(11),
+                    "liquidityAmount": liquidity_amount,
+                }),
+            })
+        }
+        _ => Err(not_parsable()),
+    }
+}
+
+fn check_num_token_lending_accounts(
+    accounts: &[u8],
+    num: usize,
+) -> Result<(), ParseInstructionError> {
+    check_num_accounts(accounts, num, ParsableProgram::SplTokenLending)
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_sdk::pubkey::Pubkey};
+
+    #[test]
+    fn test_parse_token_lending_deposit_reserve_liquidity() {
+        let keys: Vec<Pubkey> = (0..10).map(|_| Pubkey::new_unique()).collect();
+        let mut data = vec![DEPOSIT_RESERVE_LIQUIDITY];
+        data.extend_from_slice(&5_000u64.to_le_bytes());
+        let mut instruction = CompiledInstruction::new_from_raw_parts(0, data, (0..10).collect());
+        assert_eq!(
+            parse_token_lending(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "depositReserveLiquidity".into(),
+                info: json!({
+                    "sourceLiquidity": keys[0].to_string(),
+                    "destinationCollateral": keys[1].to_string(),
+                    "reserve": keys[2].to_string(),
+                    "reserveLiquiditySupply": keys[3].to_string(),
+                    "reserveCollateralMint": keys[4].to_string(),
+                    "lendingMarket": keys[5].to_string(),
+                    "lendingMarketAuthority": keys[6].to_string(),
+                    "userTransferAuthority": keys[7].to_string(),
+                    "clockSysvar": keys[8].to_string(),
+                    "tokenProgram": keys[9].to_string(),
+                    "liquidityAmount": 5_000,
+                }),
+            }
+        );
+        assert!(parse_token_lending(&instruction, &AccountKeys::new(&keys[0..9], None)).is_err());
+        instruction.accounts.pop();
+        assert!(parse_token_lending(&instruction, &AccountKeys::new(&keys, None)).is_err());
+
+        // Truncated amount
+        instruction.accounts.push(9);
+        instruction.data.truncate(5);
+        assert!(matches!(
+            parse_token_lending(&instruction, &AccountKeys::new(&keys, None)),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::SplTokenLending
+            ))
+        ));
+    }
+}
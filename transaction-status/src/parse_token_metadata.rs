@@ -0,0 +1,268 @@
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+        },
+    },
+    borsh::BorshDeserialize,
+    serde_json::{json, Map, Value},
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys},
+};
+
+solana_sdk::declare_id!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+const UPDATE_METADATA_ACCOUNT_V2: u8 = 15;
+const CREATE_MASTER_EDITION_V3: u8 = 17;
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+const BURN: u8 = 41;
+const MINT: u8 = 43;
+const TRANSFER: u8 = 49;
+
+pub fn parse_token_metadata(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    match instruction.accounts.iter().max() {
+        Some(index) if (*index as usize) < account_keys.len() => {}
+        _ => {
+            // Runtime should prevent this from ever happening
+            return Err(ParseInstructionError::InstructionKeyMismatch(
+                ParsableProgram::TokenMetadata,
+            ));
+        }
+    }
+    let not_parsable =
+        || ParseInstructionError::InstructionNotParsable(ParsableProgram::TokenMetadata);
+    let (tag, mut data) = instruction.data.split_first().ok_or_else(not_parsable)?;
+    let key = |i: usize| account_keys[instruction.accounts[i] as usize].to_string();
+    // Optional accounts of the newer instructions are passed as the program id when absent
+    let insert_optional = |map: &mut Map<String, Value>, name: &str, i: usize| {
+        let account = &account_keys[instruction.accounts[i] as usize];
+        if *account != id() {
+            map.insert(name.to_string(), json!(account.to_string()));
+        }
+    };
+    match *tag {
+        CREATE_METADATA_ACCOUNT_V3 => {
+            // Only the leading fields of `DataV2` are decoded
+            let (name, symbol, uri, seller_fee_basis_points) =
+                <(String, String, String, u16)>::deserialize(&mut data)
+                    .map_err(|_| not_parsable())?;
+            check_num_token_metadata_accounts(&instruction.accounts, 6)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_metadata::CREATE_METADATA_ACCOUNT_V3
+                    .into(),
+                info: json!({
+                    "metadata": key(0),
+                    "mint": key(1),
+                    "mintAuthority": key(2),
+                    "payer": key(3),
+                    "updateAuthority": key(4),
+                    "systemProgram": key(5),
+                    "name": name.trim_end_matches('\0'),
+                    "symbol": symbol.trim_end_matches('\0'),
+                    "uri": uri.trim_end_matches('\0'),
+                    "sellerFeeBasisPoints": seller_fee_basis_points,
+                }),
+            })
+        }
+        UPDATE_METADATA_ACCOUNT_V2 => {
+            check_num_token_metadata_accounts(&instruction.accounts, 2)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_metadata::UPDATE_METADATA_ACCOUNT_V2
+                    .into(),
+                info: json!({
+                    "metadata": key(0),
+                    "updateAuthority": key(1),
+                }),
+            })
+        }
+        CREATE_MASTER_EDITION_V3 => {
+            let max_supply = Option::<u64>::deserialize(&mut data).map_err(|_| not_parsable())?;
+            check_num_token_metadata_accounts(&instruction.accounts, 8)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_metadata::CREATE_MASTER_EDITION_V3
+                    .into(),
+                info: json!({
+                    "edition": key(0),
+                    "mint": key(1),
+                    "updateAuthority": key(2),
+                    "mintAuthority": key(3),
+                    "payer": key(4),
+                    "metadata": key(5),
+                    "tokenProgram": key(6),
+                    "systemProgram": key(7),
+                    "maxSupply": max_supply,
+                }),
+            })
+        }
+        MINT => {
+            let amount = parse_v1_amount(&mut data).ok_or_else(not_parsable)?;
+            check_num_token_metadata_accounts(&instruction.accounts, 13)?;
+            let mut value = json!({
+                "token": key(0),
+                "metadata": key(2),
+                "mint": key(5),
+                "authority": key(6),
+                "payer": key(8),
+                "amount": amount,
+            });
+            let map = value.as_object_mut().unwrap();
+            insert_optional(map, "tokenOwner", 1);
+            insert_optional(map, "masterEdition", 3);
+            insert_optional(map, "tokenRecord", 4);
+            insert_optional(map, "delegateRecord", 7);
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_metadata::MINT.into(),
+                info: value,
+            })
+        }
+        BURN => {
+            let amount = parse_v1_amount(&mut data).ok_or_else(not_parsable)?;
+            check_num_token_metadata_accounts(&instruction.accounts, 14)?;
+            let mut value = json!({
+                "authority": key(0),
+                "metadata": key(2),
+                "mint": key(4),
+                "token": key(5),
+                "amount": amount,
+            });
+            let map = value.as_object_mut().unwrap();
+            insert_optional(map, "collectionMetadata", 1);
+            insert_optional(map, "edition", 3);
+            insert_optional(map, "tokenRecord", 10);
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_metadata::BURN.into(),
+                info: value,
+            })
+        }
+        TRANSFER => {
+            let amount = parse_v1_amount(&mut data).ok_or_else(not_parsable)?;
+            check_num_token_metadata_accounts(&instruction.accounts, 15)?;
+            let mut value = json!({
+                "token": key(0),
+                "tokenOwner": key(1),
+                "destination": key(2),
+                "destinationOwner": key(3),
+                "mint": key(4),
+                "metadata": key(5),
+                "authority": key(9),
+                "payer": key(10),
+                "amount": amount,
+            });
+            let map = value.as_object_mut().unwrap();
+            insert_optional(map, "edition", 6);
+            insert_optional(map, "ownerTokenRecord", 7);
+            insert_optional(map, "destinationTokenRecord", 8);
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_metadata::TRANSFER.into(),
+                info: value,
+            })
+        }
+        _ => Err(not_parsable()),
+    }
+}
+
+// Mint, burn and transfer args are versioned enums whose only variant, V1, leads with the amount
+fn parse_v1_amount(data: &mut &[u8]) -> Option<u64> {
+    match u8::deserialize(data).ok()? {
+        0 => u64::deserialize(data).ok(),
+        _ => None,
+    }
+}
+
+fn check_num_token_metadata_accounts(
+    accounts: &[u8],
+    num: usize,
+) -> Result<(), ParseInstructionError> {
+    check_num_accounts(accounts, num, ParsableProgram::TokenMetadata)
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, borsh::BorshSerialize, solana_sdk::pubkey::Pubkey};
+
+    #[test]
+    fn test_parse_token_metadata_create_metadata_account_v3() {
+        let keys: Vec<Pubkey> = (0..7).map(|_| Pubkey::new_unique()).collect();
+        let mut data = vec![CREATE_METADATA_ACCOUNT_V3];
+        data.extend(
+            (
+                "Solana Monkey".to_string(),
+                "SMB".to_string(),
+                "https://example.com/smb.json".to_string(),
+                500u16,
+            )
+                .try_to_vec()
+                .unwrap(),
+        );
+        // No creators, collection or uses; mutable; no collection details
+        data.extend_from_slice(&[0, 0, 0, 1, 0]);
+        let mut instruction = CompiledInstruction::new_from_raw_parts(0, data, (0..7).collect());
+        assert_eq!(
+            parse_token_metadata(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "createMetadataAccountV3".into(),
+                info: json!({
+                    "metadata": keys[0].to_string(),
+                    "mint": keys[1].to_string(),
+                    "mintAuthority": keys[2].to_string(),
+                    "payer": keys[3].to_string(),
+                    "updateAuthority": keys[4].to_string(),
+                    "systemProgram": keys[5].to_string(),
+                    "name": "Solana Monkey",
+                    "symbol": "SMB",
+                    "uri": "https://example.com/smb.json",
+                    "sellerFeeBasisPoints": 500,
+                }),
+            }
+        );
+
+        // Rent sysvar is optional
+        instruction.accounts.pop();
+        assert!(parse_token_metadata(&instruction, &AccountKeys::new(&keys, None)).is_ok());
+        instruction.accounts.pop();
+        assert!(parse_token_metadata(&instruction, &AccountKeys::new(&keys, None)).is_err());
+        assert!(parse_token_metadata(&instruction, &AccountKeys::new(&keys[0..5], None)).is_err());
+
+        // Truncated args
+        instruction.accounts = (0..6).collect();
+        instruction.data.truncate(10);
+        assert!(matches!(
+            parse_token_metadata(&instruction, &AccountKeys::new(&keys, None)),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::TokenMetadata
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_parse_token_metadata_mint() {
+        let mut keys: Vec<Pubkey> = (0..13).map(|_| Pubkey::new_unique()).collect();
+        // No token record or delegate record
+        keys[4] = id();
+        keys[7] = id();
+        let instruction = CompiledInstruction::new_from_raw_parts(
+            0,
+            vec![MINT, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            (0..13).collect(),
+        );
+        assert_eq!(
+            parse_token_metadata(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "mint".into(),
+                info: json!({
+                    "token": keys[0].to_string(),
+                    "tokenOwner": keys[1].to_string(),
+                    "metadata": keys[2].to_string(),
+                    "masterEdition": keys[3].to_string(),
+                    "mint": keys[5].to_string(),
+                    "authority": keys[6].to_string(),
+                    "payer": keys[8].to_string(),
+                    "amount": 1,
+                }),
+            }
+        );
+    }
+}
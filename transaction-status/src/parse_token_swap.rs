@@ -0,0 +1,219 @@
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+        },
+    },
+    borsh::BorshDeserialize,
+    serde_json::json,
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys},
+};
+
+// `SwapInstruction` is packed rather than borsh-encoded, but its amounts are little-endian `u64`s,
+// which borsh decodes identically
+solana_sdk::declare_id!("SwaPpA9LAaLfeLi3a68M4DjnLqgtticKg6CnyNwgAC8");
+
+const INITIALIZE: u8 = 0;
+const SWAP: u8 = 1;
+const DEPOSIT_ALL_TOKEN_TYPES: u8 = 2;
+const WITHDRAW_ALL_TOKEN_TYPES: u8 = 3;
+
+pub fn parse_token_swap(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    match instruction.accounts.iter().max() {
+        Some(index) if (*index as usize) < account_keys.len() => {}
+        _ => {
+            // Runtime should prevent this from ever happening
+            return Err(ParseInstructionError::InstructionKeyMismatch(
+                ParsableProgram::SplTokenSwap,
+            ));
+        }
+    }
+    let not_parsable =
+        || ParseInstructionError::InstructionNotParsable(ParsableProgram::SplTokenSwap);
+    let (tag, mut data) = instruction.data.split_first().ok_or_else(not_parsable)?;
+    let key = |i: usize| account_keys[instruction.accounts[i] as usize].to_string();
+    match *tag {
+        INITIALIZE => {
+            // `Fees`, then the curve type; the curve parameters that follow are not decoded
+            let fees = <[u64; 8]>::deserialize(&mut data).map_err(|_| not_parsable())?;
+            let curve_type = match u8::deserialize(&mut data).map_err(|_| not_parsable())? {
+                0 => "constantProduct",
+                1 => "constantPrice",
+                2 => "stable",
+                3 => "offset",
+                _ => return Err(not_parsable()),
+            };
+            check_num_token_swap_accounts(&instruction.accounts, 8)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_swap::INITIALIZE.into(),
+                info: json!({
+                    "swap": key(0),
+                    "swapAuthority": key(1),
+                    "tokenA": key(2),
+                    "tokenB": key(3),
+                    "poolMint": key(4),
+                    "poolFeeAccount": key(5),
+                    "destination": key(6),
+                    "tokenProgram": key(7),
+                    "fees": {
+                        "tradeFeeNumerator": fees[0],
+                        "tradeFeeDenominator": fees[1],
+                        "ownerTradeFeeNumerator": fees[2],
+                        "ownerTradeFeeDenominator": fees[3],
+                        "ownerWithdrawFeeNumerator": fees[4],
+                        "ownerWithdrawFeeDenominator": fees[5],
+                        "hostFeeNumerator": fees[6],
+                        "hostFeeDenominator": fees[7],
+                    },
+                    "curveType": curve_type,
+                }),
+            })
+        }
+        SWAP => {
+            let (amount_in, minimum_amount_out) =
+                <(u64, u64)>::deserialize(&mut data).map_err(|_| not_parsable())?;
+            check_num_token_swap_accounts(&instruction.accounts, 10)?;
+            let mut value = json!({
+                "swap": key(0),
+                "swapAuthority": key(1),
+                "userTransferAuthority": key(2),
+                "source": key(3),
+                "swapSource": key(4),
+                "swapDestination": key(5),
+                "destination": key(6),
+                "poolMint": key(7),
+                "poolFeeAccount": key(8),
+                "tokenProgram": key(9),
+                "amountIn": amount_in,
+                "minimumAmountOut": minimum_amount_out,
+            });
+            if instruction.accounts.len() >= 11 {
+                let map = value.as_object_mut().unwrap();
+                map.insert("hostFeeAccount".to_string(), json!(key(10)));
+            }
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_swap::SWAP.into(),
+                info: value,
+            })
+        }
+        DEPOSIT_ALL_TOKEN_TYPES => {
+            let (pool_token_amount, maximum_token_a_amount, maximum_token_b_amount) =
+                <(u64, u64, u64)>::deserialize(&mut data).map_err(|_| not_parsable())?;
+            check_num_token_swap_accounts(&instruction.accounts, 10)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_swap::DEPOSIT_ALL_TOKEN_TYPES.into(),
+                info: json!({
+                    "swap": key(0),
+                    "swapAuthority": key(1),
+                    "userTransferAuthority": key(2),
+                    "sourceA": key(3),
+                    "sourceB": key(4),
+                    "swapTokenA": key(5),
+                    "swapTokenB": key(6),
+                    "poolMint": key(7),
+                    "destination": key(8),
+                    "tokenProgram": key(9),
+                    "poolTokenAmount": pool_token_amount,
+                    "maximumTokenAAmount": maximum_token_a_amount,
+                    "maximumTokenBAmount": maximum_token_b_amount,
+                }),
+            })
+        }
+        WITHDRAW_ALL_TOKEN_TYPES => {
+            let (pool_token_amount, minimum_token_a_amount, minimum_token_b_amount) =
+                <(u64, u64, u64)>::deserialize(&mut data).map_err(|_| not_parsable())?;
+            check_num_token_swap_accounts(&instruction.accounts, 11)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_swap::WITHDRAW_ALL_TOKEN_TYPES.into(),
+                info: json!({
+                    "swap": key(0),
+                    "swapAuthority": key(1),
+                    "userTransferAuthority": key(2),
+                    "poolMint": key(3),
+                    "source": key(4),
+                    "swapTokenA": key(5),
+                    "swapTokenB": key(6),
+                    "destinationA": key(7),
+                    "destinationB": key(8),
+                    "poolFeeAccount": key(9),
+                    "tokenProgram": key(10),
+                    "poolTokenAmount": pool_token_amount,
+                    "minimumTokenAAmount": minimum_token_a_amount,
+                    "minimumTokenBAmount": minimum_token_b_amount,
+                }),
+            })
+        }
+        _ => Err(not_parsable()),
+    }
+}
+
+fn check_num_token_swap_accounts(accounts: &[u8], num: usize) -> Result<(), ParseInstructionError> {
+    check_num_accounts(accounts, num, ParsableProgram::SplTokenSwap)
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_sdk::pubkey::Pubkey};
+
+    #[test]
+    fn test_parse_token_swap_swap() {
+        let keys: Vec<Pubkey> = (0..11).map(|_| Pubkey::new_unique()).collect();
+        let mut data = vec![SWAP];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&990u64.to_le_bytes());
+        let mut instruction = CompiledInstruction::new_from_raw_parts(0, data, (0..10).collect());
+        let expected_info = json!({
+            "swap": keys[0].to_string(),
+            "swapAuthority": keys[1].to_string(),
+            "userTransferAuthority": keys[2].to_string(),
+            "source": keys[3].to_string(),
+            "swapSource": keys[4].to_string(),
+            "swapDestination": keys[5].to_string(),
+            "destination": keys[6].to_string(),
+            "poolMint": keys[7].to_string(),
+            "poolFeeAccount": keys[8].to_string(),
+            "tokenProgram": keys[9].to_string(),
+            "amountIn": 1_000,
+            "minimumAmountOut": 990,
+        });
+        assert_eq!(
+            parse_token_swap(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "swap".into(),
+                info: expected_info.clone(),
+            }
+        );
+
+        // With a host fee account
+        instruction.accounts.push(10);
+        let mut expected_info = expected_info;
+        expected_info
+            .as_object_mut()
+            .unwrap()
+            .insert("hostFeeAccount".to_string(), json!(keys[10].to_string()));
+        assert_eq!(
+            parse_token_swap(&instruction, &AccountKeys::new(&keys, None))
+                .unwrap()
+                .info,
+            expected_info
+        );
+
+        assert!(parse_token_swap(&instruction, &AccountKeys::new(&keys[0..10], None)).is_err());
+        instruction.accounts.truncate(9);
+        assert!(parse_token_swap(&instruction, &AccountKeys::new(&keys, None)).is_err());
+
+        // Truncated amounts
+        instruction.accounts = (0..10).collect();
+        instruction.data.truncate(9);
+        assert!(matches!(
+            parse_token_swap(&instruction, &AccountKeys::new(&keys, None)),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::SplTokenSwap
+            ))
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+        },
+        parse_token::parse_signers,
+    },
+    serde_json::json,
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys},
+};
+
+// The program burns tokens of a deprecated mint and pays out the same amount of a new mint from
+// an escrow held by a PDA
+solana_sdk::declare_id!("TkupDoNseygccBCjSsrSpMccjwHfTYwcrjpnDSrFDhC");
+
+const EXCHANGE: u8 = 0;
+
+pub fn parse_token_upgrade(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    match instruction.accounts.iter().max() {
+        Some(index) if (*index as usize) < account_keys.len() => {}
+        _ => {
+            // Runtime should prevent this from ever happening
+            return Err(ParseInstructionError::InstructionKeyMismatch(
+                ParsableProgram::SplTokenUpgrade,
+            ));
+        }
+    }
+    let key = |i: usize| account_keys[instruction.accounts[i] as usize].to_string();
+    match instruction.data.as_slice() {
+        [EXCHANGE] => {
+            check_num_token_upgrade_accounts(&instruction.accounts, 9)?;
+            let mut value = json!({
+                "originalAccount": key(0),
+                "originalMint": key(1),
+                "newEscrow": key(2),
+                "newAccount": key(3),
+                "newMint": key(4),
+                "escrowAuthority": key(5),
+                "originalTokenProgram": key(6),
+                "newTokenProgram": key(7),
+            });
+            let map = value.as_object_mut().unwrap();
+            parse_signers(
+                map,
+                8,
+                account_keys,
+                &instruction.accounts,
+                "originalAccountAuthority",
+                "originalAccountMultisigAuthority",
+            );
+            Ok(ParsedInstructionEnum {
+                instruction_type: instruction_types::token_upgrade::EXCHANGE.into(),
+                info: value,
+            })
+        }
+        _ => Err(ParseInstructionError::InstructionNotParsable(
+            ParsableProgram::SplTokenUpgrade,
+        )),
+    }
+}
+
+fn check_num_token_upgrade_accounts(
+    accounts: &[u8],
+    num: usize,
+) -> Result<(), ParseInstructionError> {
+    check_num_accounts(accounts, num, ParsableProgram::SplTokenUpgrade)
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_sdk::pubkey::Pubkey};
+
+    #[test]
+    fn test_parse_token_upgrade_exchange() {
+        let keys: Vec<Pubkey> = (0..11).map(|_| Pubkey::new_unique()).collect();
+        let mut instruction =
+            CompiledInstruction::new_from_raw_parts(0, vec![EXCHANGE], (0..9).collect());
+        let expected_info = json!({
+            "originalAccount": keys[0].to_string(),
+            "originalMint": keys[1].to_string(),
+            "newEscrow": keys[2].to_string(),
+            "newAccount": keys[3].to_string(),
+            "newMint": keys[4].to_string(),
+            "escrowAuthority": keys[5].to_string(),
+            "originalTokenProgram": keys[6].to_string(),
+            "newTokenProgram": keys[7].to_string(),
+        });
+        let mut single_owner_info = expected_info.clone();
+        single_owner_info.as_object_mut().unwrap().insert(
+            "originalAccountAuthority".to_string(),
+            json!(keys[8].to_string()),
+        );
+        assert_eq!(
+            parse_token_upgrade(&instruction, &AccountKeys::new(&keys, None)).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "exchange".into(),
+                info: single_owner_info,
+            }
+        );
+
+        // Multisig authority
+        instruction.accounts.extend([9, 10]);
+        let mut multisig_info = expected_info;
+        let map = multisig_info.as_object_mut().unwrap();
+        map.insert(
+            "originalAccountMultisigAuthority".to_string(),
+            json!(keys[8].to_string()),
+        );
+        map.insert(
+            "signers".to_string(),
+            json!([keys[9].to_string(), keys[10].to_string()]),
+        );
+        assert_eq!(
+            parse_token_upgrade(&instruction, &AccountKeys::new(&keys, None))
+                .unwrap()
+                .info,
+            multisig_info
+        );
+
+        assert!(parse_token_upgrade(&instruction, &AccountKeys::new(&keys[0..10], None)).is_err());
+        instruction.accounts.truncate(8);
+        assert!(parse_token_upgrade(&instruction, &AccountKeys::new(&keys, None)).is_err());
+
+        // Unknown instruction tag
+        instruction.accounts = (0..9).collect();
+        instruction.data = vec![1];
+        assert!(matches!(
+            parse_token_upgrade(&instruction, &AccountKeys::new(&keys, None)),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::SplTokenUpgrade
+            ))
+        ));
+    }
+}
@@ -1,11 +1,22 @@
 use {
-    crate::parse_instruction::{
-        check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+    crate::{
+        instruction_types,
+        parse_encode::ParsedFields,
+        parse_instruction::{
+            check_num_accounts, flag_incinerator_destination, lamports_to_sol_string,
+            InstructionParser, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
+        },
     },
     bincode::deserialize,
-    serde_json::json,
-    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys},
-    solana_vote_program::vote_instruction::VoteInstruction,
+    serde_json::{json, Value},
+    solana_sdk::{instruction::CompiledInstruction, message::AccountKeys, pubkey::Pubkey},
+    solana_vote_program::{
+        vote_instruction::VoteInstruction,
+        vote_state::{
+            Lockout, Vote, VoteAuthorize, VoteAuthorizeCheckedWithSeedArgs,
+            VoteAuthorizeWithSeedArgs, VoteInit, VoteState, VoteStateUpdate,
+        },
+    },
 };
 
 pub fn parse_vote(
@@ -25,37 +36,52 @@ pub fn parse_vote(
     }
     match vote_instruction {
         VoteInstruction::InitializeAccount(vote_init) => {
-            check_num_vote_accounts(&instruction.accounts, 4)?;
+            check_num_vote_accounts(&instruction.accounts, 3)?;
+            let mut value = json!({
+                "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                "rentSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
+                "authorizedVoter": vote_init.authorized_voter.to_string(),
+                "authorizedWithdrawer": vote_init.authorized_withdrawer.to_string(),
+                "commission": vote_init.commission,
+            });
+            let map = value.as_object_mut().unwrap();
+            // Legacy instructions omitted the clock sysvar: [vote, rent, node]
+            let node_index = if instruction.accounts.len() >= 4 {
+                map.insert(
+                    "clockSysvar".to_string(),
+                    json!(account_keys[instruction.accounts[2] as usize].to_string()),
+                );
+                3
+            } else {
+                2
+            };
+            map.insert(
+                "node".to_string(),
+                json!(account_keys[instruction.accounts[node_index] as usize].to_string()),
+            );
             Ok(ParsedInstructionEnum {
-                instruction_type: "initialize".to_string(),
-                info: json!({
-                    "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "rentSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "clockSysvar": account_keys[instruction.accounts[2] as usize].to_string(),
-                    "node": account_keys[instruction.accounts[3] as usize].to_string(),
-                    "authorizedVoter": vote_init.authorized_voter.to_string(),
-                    "authorizedWithdrawer": vote_init.authorized_withdrawer.to_string(),
-                    "commission": vote_init.commission,
-                }),
+                instruction_type: instruction_types::vote::INITIALIZE.into(),
+                info: value,
             })
         }
         VoteInstruction::Authorize(new_authorized, authority_type) => {
             check_num_vote_accounts(&instruction.accounts, 3)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "authorize".to_string(),
+                instruction_type: instruction_types::vote::AUTHORIZE.into(),
                 info: json!({
                     "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "clockSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
                     "authority": account_keys[instruction.accounts[2] as usize].to_string(),
                     "newAuthority": new_authorized.to_string(),
-                    "authorityType": authority_type,
+                    "authorityType": vote_authorize_name(authority_type),
+                    "authorityTypeCode": vote_authorize_code(authority_type),
                 }),
             })
         }
         VoteInstruction::AuthorizeWithSeed(args) => {
             check_num_vote_accounts(&instruction.accounts, 3)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "authorizeWithSeed".to_string(),
+                instruction_type: instruction_types::vote::AUTHORIZE_WITH_SEED.into(),
                 info: json!({
                     "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "clockSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -63,14 +89,15 @@ pub fn parse_vote(
                     "authorityOwner": args.current_authority_derived_key_owner.to_string(),
                     "authoritySeed": args.current_authority_derived_key_seed,
                     "newAuthority": args.new_authority.to_string(),
-                    "authorityType": args.authorization_type,
+                    "authorityType": vote_authorize_name(args.authorization_type),
+                    "authorityTypeCode": vote_authorize_code(args.authorization_type),
                 }),
             })
         }
         VoteInstruction::AuthorizeCheckedWithSeed(args) => {
             check_num_vote_accounts(&instruction.accounts, 4)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "authorizeCheckedWithSeed".to_string(),
+                instruction_type: instruction_types::vote::AUTHORIZE_CHECKED_WITH_SEED.into(),
                 info: json!({
                     "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "clockSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -78,7 +105,8 @@ pub fn parse_vote(
                     "authorityOwner": args.current_authority_derived_key_owner.to_string(),
                     "authoritySeed": args.current_authority_derived_key_seed,
                     "newAuthority": account_keys[instruction.accounts[3] as usize].to_string(),
-                    "authorityType": args.authorization_type,
+                    "authorityType": vote_authorize_name(args.authorization_type),
+                    "authorityTypeCode": vote_authorize_code(args.authorization_type),
                 }),
             })
         }
@@ -88,9 +116,10 @@ pub fn parse_vote(
                 "slots": vote.slots,
                 "hash": vote.hash.to_string(),
                 "timestamp": vote.timestamp,
+                "hasTimestamp": vote.timestamp.is_some(),
             });
             Ok(ParsedInstructionEnum {
-                instruction_type: "vote".to_string(),
+                instruction_type: instruction_types::vote::VOTE.into(),
                 info: json!({
                     "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "slotHashesSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -102,14 +131,9 @@ pub fn parse_vote(
         }
         VoteInstruction::UpdateVoteState(vote_state_update) => {
             check_num_vote_accounts(&instruction.accounts, 4)?;
-            let vote_state_update = json!({
-                "lockouts": vote_state_update.lockouts,
-                "root": vote_state_update.root,
-                "hash": vote_state_update.hash.to_string(),
-                "timestamp": vote_state_update.timestamp,
-            });
+            let vote_state_update = vote_state_update_json(&vote_state_update);
             Ok(ParsedInstructionEnum {
-                instruction_type: "updatevotestate".to_string(),
+                instruction_type: instruction_types::vote::UPDATE_VOTE_STATE.into(),
                 info: json!({
                     "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "slotHashesSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -121,40 +145,41 @@ pub fn parse_vote(
         }
         VoteInstruction::UpdateVoteStateSwitch(vote_state_update, hash) => {
             check_num_vote_accounts(&instruction.accounts, 4)?;
-            let vote_state_update = json!({
-                "lockouts": vote_state_update.lockouts,
-                "root": vote_state_update.root,
-                "hash": vote_state_update.hash.to_string(),
-                "timestamp": vote_state_update.timestamp,
-            });
+            let vote_state_update = vote_state_update_json(&vote_state_update);
             Ok(ParsedInstructionEnum {
-                instruction_type: "updatevotestateswitch".to_string(),
+                instruction_type: instruction_types::vote::UPDATE_VOTE_STATE_SWITCH.into(),
                 info: json!({
                     "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "slotHashesSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
                     "clockSysvar": account_keys[instruction.accounts[2] as usize].to_string(),
                     "voteAuthority": account_keys[instruction.accounts[3] as usize].to_string(),
                     "voteStateUpdate": vote_state_update,
+                    // `hash` duplicates `proofHash` until consumers have migrated
                     "hash": hash.to_string(),
+                    "proofHash": hash.to_string(),
                 }),
             })
         }
         VoteInstruction::Withdraw(lamports) => {
             check_num_vote_accounts(&instruction.accounts, 3)?;
+            let destination = &account_keys[instruction.accounts[1] as usize];
+            let mut value = json!({
+                "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                "destination": destination.to_string(),
+                "withdrawAuthority": account_keys[instruction.accounts[2] as usize].to_string(),
+                "lamports": lamports,
+                "sol": lamports_to_sol_string(lamports),
+            });
+            flag_incinerator_destination(&mut value, destination);
             Ok(ParsedInstructionEnum {
-                instruction_type: "withdraw".to_string(),
-                info: json!({
-                    "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
-                    "destination": account_keys[instruction.accounts[1] as usize].to_string(),
-                    "withdrawAuthority": account_keys[instruction.accounts[2] as usize].to_string(),
-                    "lamports": lamports,
-                }),
+                instruction_type: instruction_types::vote::WITHDRAW.into(),
+                info: value,
             })
         }
         VoteInstruction::UpdateValidatorIdentity => {
             check_num_vote_accounts(&instruction.accounts, 3)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "updateValidatorIdentity".to_string(),
+                instruction_type: instruction_types::vote::UPDATE_VALIDATOR_IDENTITY.into(),
                 info: json!({
                     "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "newValidatorIdentity": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -165,7 +190,7 @@ pub fn parse_vote(
         VoteInstruction::UpdateCommission(commission) => {
             check_num_vote_accounts(&instruction.accounts, 2)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "updateCommission".to_string(),
+                instruction_type: instruction_types::vote::UPDATE_COMMISSION.into(),
                 info: json!({
                     "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "withdrawAuthority": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -179,35 +204,266 @@ pub fn parse_vote(
                 "slots": vote.slots,
                 "hash": vote.hash.to_string(),
                 "timestamp": vote.timestamp,
+                "hasTimestamp": vote.timestamp.is_some(),
             });
             Ok(ParsedInstructionEnum {
-                instruction_type: "voteSwitch".to_string(),
+                instruction_type: instruction_types::vote::VOTE_SWITCH.into(),
                 info: json!({
                     "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "slotHashesSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
                     "clockSysvar": account_keys[instruction.accounts[2] as usize].to_string(),
                     "voteAuthority": account_keys[instruction.accounts[3] as usize].to_string(),
                     "vote": vote,
+                    // `hash` duplicates `proofHash` until consumers have migrated
                     "hash": hash.to_string(),
+                    "proofHash": hash.to_string(),
                 }),
             })
         }
         VoteInstruction::AuthorizeChecked(authority_type) => {
             check_num_vote_accounts(&instruction.accounts, 4)?;
             Ok(ParsedInstructionEnum {
-                instruction_type: "authorizeChecked".to_string(),
+                instruction_type: instruction_types::vote::AUTHORIZE_CHECKED.into(),
                 info: json!({
                     "voteAccount": account_keys[instruction.accounts[0] as usize].to_string(),
                     "clockSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
                     "authority": account_keys[instruction.accounts[2] as usize].to_string(),
                     "newAuthority": account_keys[instruction.accounts[3] as usize].to_string(),
-                    "authorityType": authority_type,
+                    "authorityType": vote_authorize_name(authority_type),
+                    "authorityTypeCode": vote_authorize_code(authority_type),
                 }),
             })
         }
     }
 }
 
+/// [`InstructionParser`] for the vote program
+pub struct VoteParser;
+
+impl InstructionParser for VoteParser {
+    fn program_id(&self) -> Pubkey {
+        solana_vote_program::id()
+    }
+
+    fn program_name(&self) -> String {
+        "vote".to_string()
+    }
+
+    fn parse(
+        &self,
+        instruction: &CompiledInstruction,
+        account_keys: &AccountKeys,
+    ) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+        parse_vote(instruction, account_keys)
+    }
+}
+
+/// Inverse of [`parse_vote`]: the instruction and account indexes described by the parsed
+/// `info`, see [`to_compiled_instruction`](crate::parse_encode::to_compiled_instruction)
+pub(crate) fn encode_vote(
+    instruction_type: &str,
+    fields: &ParsedFields,
+) -> Result<(VoteInstruction, Vec<u8>), ParseInstructionError> {
+    let not_parsable = || ParseInstructionError::InstructionNotParsable(ParsableProgram::Vote);
+    let accounts = |names: &[&str]| -> Result<Vec<u8>, ParseInstructionError> {
+        names.iter().map(|name| fields.account(name)).collect()
+    };
+    let authority_type = || match fields.string("authorityType")?.as_str() {
+        "Voter" => Ok(VoteAuthorize::Voter),
+        "Withdrawer" => Ok(VoteAuthorize::Withdrawer),
+        _ => Err(not_parsable()),
+    };
+    let vote = || -> Result<Vote, ParseInstructionError> {
+        let vote = fields.nested(fields.value("vote")?)?;
+        Ok(Vote {
+            slots: vote
+                .array("slots")?
+                .iter()
+                .map(|slot| vote.integer(slot))
+                .collect::<Result<_, _>>()?,
+            hash: vote.hash("hash")?,
+            timestamp: vote.optional_integer("timestamp")?,
+        })
+    };
+    let vote_state_update = || -> Result<VoteStateUpdate, ParseInstructionError> {
+        let update = fields.nested(fields.value("voteStateUpdate")?)?;
+        let lockouts = update
+            .array("lockouts")?
+            .iter()
+            .map(|lockout| {
+                let lockout = update.nested(lockout)?;
+                Ok(Lockout {
+                    slot: lockout.u64("slot")?,
+                    confirmation_count: lockout.integer(lockout.value("confirmationCount")?)?,
+                })
+            })
+            .collect::<Result<_, ParseInstructionError>>()?;
+        Ok(VoteStateUpdate {
+            lockouts,
+            root: update.optional_integer("root")?,
+            hash: update.hash("hash")?,
+            timestamp: update.optional_integer("timestamp")?,
+        })
+    };
+    let vote_accounts = [
+        "voteAccount",
+        "slotHashesSysvar",
+        "clockSysvar",
+        "voteAuthority",
+    ];
+    match instruction_type {
+        instruction_types::vote::INITIALIZE => {
+            let vote_init = VoteInit {
+                node_pubkey: fields.pubkey("node")?,
+                authorized_voter: fields.pubkey("authorizedVoter")?,
+                authorized_withdrawer: fields.pubkey("authorizedWithdrawer")?,
+                commission: fields.u8("commission")?,
+            };
+            // Legacy instructions omitted the clock sysvar
+            let accounts = if fields.contains("clockSysvar") {
+                accounts(&["voteAccount", "rentSysvar", "clockSysvar", "node"])?
+            } else {
+                accounts(&["voteAccount", "rentSysvar", "node"])?
+            };
+            Ok((VoteInstruction::InitializeAccount(vote_init), accounts))
+        }
+        instruction_types::vote::AUTHORIZE => Ok((
+            VoteInstruction::Authorize(fields.pubkey("newAuthority")?, authority_type()?),
+            accounts(&["voteAccount", "clockSysvar", "authority"])?,
+        )),
+        instruction_types::vote::AUTHORIZE_WITH_SEED => Ok((
+            VoteInstruction::AuthorizeWithSeed(VoteAuthorizeWithSeedArgs {
+                authorization_type: authority_type()?,
+                current_authority_derived_key_owner: fields.pubkey("authorityOwner")?,
+                current_authority_derived_key_seed: fields.string("authoritySeed")?,
+                new_authority: fields.pubkey("newAuthority")?,
+            }),
+            accounts(&["voteAccount", "clockSysvar", "authorityBaseKey"])?,
+        )),
+        instruction_types::vote::AUTHORIZE_CHECKED_WITH_SEED => Ok((
+            VoteInstruction::AuthorizeCheckedWithSeed(VoteAuthorizeCheckedWithSeedArgs {
+                authorization_type: authority_type()?,
+                current_authority_derived_key_owner: fields.pubkey("authorityOwner")?,
+                current_authority_derived_key_seed: fields.string("authoritySeed")?,
+            }),
+            accounts(&[
+                "voteAccount",
+                "clockSysvar",
+                "authorityBaseKey",
+                "newAuthority",
+            ])?,
+        )),
+        instruction_types::vote::VOTE => {
+            Ok((VoteInstruction::Vote(vote()?), accounts(&vote_accounts)?))
+        }
+        instruction_types::vote::UPDATE_VOTE_STATE => Ok((
+            VoteInstruction::UpdateVoteState(vote_state_update()?),
+            accounts(&vote_accounts)?,
+        )),
+        instruction_types::vote::UPDATE_VOTE_STATE_SWITCH => Ok((
+            VoteInstruction::UpdateVoteStateSwitch(vote_state_update()?, fields.hash("proofHash")?),
+            accounts(&vote_accounts)?,
+        )),
+        instruction_types::vote::WITHDRAW => Ok((
+            VoteInstruction::Withdraw(fields.u64("lamports")?),
+            accounts(&["voteAccount", "destination", "withdrawAuthority"])?,
+        )),
+        instruction_types::vote::UPDATE_VALIDATOR_IDENTITY => Ok((
+            VoteInstruction::UpdateValidatorIdentity,
+            accounts(&["voteAccount", "newValidatorIdentity", "withdrawAuthority"])?,
+        )),
+        instruction_types::vote::UPDATE_COMMISSION => Ok((
+            VoteInstruction::UpdateCommission(fields.u8("commission")?),
+            accounts(&["voteAccount", "withdrawAuthority"])?,
+        )),
+        instruction_types::vote::VOTE_SWITCH => Ok((
+            VoteInstruction::VoteSwitch(vote()?, fields.hash("proofHash")?),
+            accounts(&vote_accounts)?,
+        )),
+        instruction_types::vote::AUTHORIZE_CHECKED => Ok((
+            VoteInstruction::AuthorizeChecked(authority_type()?),
+            accounts(&["voteAccount", "clockSysvar", "authority", "newAuthority"])?,
+        )),
+        _ => Err(not_parsable()),
+    }
+}
+
+/// Decodes the data of a vote account, in either the current or the 0.23.5 state layout
+pub fn decode_vote_state(data: &[u8]) -> Result<Value, ParseInstructionError> {
+    let vote_state = VoteState::deserialize(data)
+        .map_err(|_| ParseInstructionError::InstructionNotParsable(ParsableProgram::Vote))?;
+    let votes: Vec<Value> = vote_state
+        .votes
+        .iter()
+        .map(|lockout| {
+            json!({
+                "slot": lockout.slot,
+                "confirmationCount": lockout.confirmation_count,
+            })
+        })
+        .collect();
+    let epoch_credits: Vec<Value> = vote_state
+        .epoch_credits
+        .iter()
+        .map(|(epoch, credits, previous_credits)| {
+            json!({
+                "epoch": epoch,
+                "credits": credits,
+                "previousCredits": previous_credits,
+            })
+        })
+        .collect();
+    Ok(json!({
+        "nodePubkey": vote_state.node_pubkey.to_string(),
+        "authorizedWithdrawer": vote_state.authorized_withdrawer.to_string(),
+        "commission": vote_state.commission,
+        "votes": votes,
+        "rootSlot": vote_state.root_slot,
+        "epochCredits": epoch_credits,
+        "lastTimestamp": {
+            "slot": vote_state.last_timestamp.slot,
+            "timestamp": vote_state.last_timestamp.timestamp,
+        },
+    }))
+}
+
+fn vote_state_update_json(vote_state_update: &VoteStateUpdate) -> Value {
+    let lockouts: Vec<Value> = vote_state_update
+        .lockouts
+        .iter()
+        .map(|lockout| {
+            json!({
+                "slot": lockout.slot,
+                "confirmationCount": lockout.confirmation_count,
+            })
+        })
+        .collect();
+    json!({
+        "lockouts": lockouts,
+        "root": vote_state_update.root,
+        "hash": vote_state_update.hash.to_string(),
+        "timestamp": vote_state_update.timestamp,
+        "hasTimestamp": vote_state_update.timestamp.is_some(),
+    })
+}
+
+// Spelled out rather than relying on the derived `Serialize`, so SDK changes to `VoteAuthorize`
+// cannot silently change parsed output
+fn vote_authorize_name(authority_type: VoteAuthorize) -> &'static str {
+    match authority_type {
+        VoteAuthorize::Voter => "Voter",
+        VoteAuthorize::Withdrawer => "Withdrawer",
+    }
+}
+
+// Pinned alongside the name for consumers that key on numbers, matching the bincode tag
+fn vote_authorize_code(authority_type: VoteAuthorize) -> u8 {
+    match authority_type {
+        VoteAuthorize::Voter => 0,
+        VoteAuthorize::Withdrawer => 1,
+    }
+}
+
 fn check_num_vote_accounts(accounts: &[u8], num: usize) -> Result<(), ParseInstructionError> {
     check_num_accounts(accounts, num, ParsableProgram::Vote)
 }
@@ -216,13 +472,102 @@ fn check_num_vote_accounts(accounts: &[u8], num: usize) -> Result<(), ParseInstr
 mod test {
     use {
         super::*,
-        solana_sdk::{hash::Hash, message::Message, pubkey::Pubkey, sysvar},
+        solana_sdk::{
+            clock::Clock,
+            hash::Hash,
+            instruction::{AccountMeta, Instruction},
+            message::Message,
+            pubkey::Pubkey,
+            sysvar,
+        },
         solana_vote_program::{
             vote_instruction,
-            vote_state::{Vote, VoteAuthorize, VoteInit},
+            vote_state::{BlockTimestamp, Lockout, Vote, VoteInit, VoteStateVersions},
         },
     };
 
+    #[test]
+    fn test_decode_vote_state() {
+        let node_pubkey = Pubkey::new_unique();
+        let authorized_withdrawer = Pubkey::new_unique();
+        let mut vote_state = VoteState::new(
+            &VoteInit {
+                node_pubkey,
+                authorized_voter: Pubkey::new_unique(),
+                authorized_withdrawer,
+                commission: 10,
+            },
+            &Clock::default(),
+        );
+        vote_state.votes.push_back(Lockout::new(42));
+        vote_state.root_slot = Some(41);
+        vote_state.epoch_credits = vec![(3, 120, 100)];
+        vote_state.last_timestamp = BlockTimestamp {
+            slot: 43,
+            timestamp: 1_650_000_000,
+        };
+        let expected = json!({
+            "nodePubkey": node_pubkey.to_string(),
+            "authorizedWithdrawer": authorized_withdrawer.to_string(),
+            "commission": 10,
+            "votes": [{"slot": 42, "confirmationCount": 1}],
+            "rootSlot": 41,
+            "epochCredits": [{"epoch": 3, "credits": 120, "previousCredits": 100}],
+            "lastTimestamp": {"slot": 43, "timestamp": 1_650_000_000},
+        });
+
+        // Vote accounts are allocated larger than the serialized state
+        let mut data = vec![0; VoteState::size_of()];
+        VoteState::serialize(&VoteStateVersions::new_current(vote_state), &mut data).unwrap();
+        assert_eq!(decode_vote_state(&data).unwrap(), expected);
+
+        // The 0.23.5 layout, with its 32-entry prior voters buffer left zeroed
+        let mut data = vec![];
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(node_pubkey.as_ref());
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&[0; 32 * 56]);
+        data.extend_from_slice(&31u64.to_le_bytes());
+        data.extend_from_slice(authorized_withdrawer.as_ref());
+        data.push(10);
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(&41u64.to_le_bytes());
+        data.extend_from_slice(&1u64.to_le_bytes());
+        for value in [3u64, 120, 100, 43] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(&1_650_000_000i64.to_le_bytes());
+        assert_eq!(decode_vote_state(&data).unwrap(), expected);
+
+        assert!(matches!(
+            decode_vote_state(&[2, 0, 0, 0]),
+            Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::Vote
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_vote_authorize_name() {
+        assert_eq!(vote_authorize_name(VoteAuthorize::Voter), "Voter");
+        assert_eq!(vote_authorize_name(VoteAuthorize::Withdrawer), "Withdrawer");
+    }
+
+    #[test]
+    fn test_vote_authorize_code() {
+        assert_eq!(vote_authorize_code(VoteAuthorize::Voter), 0);
+        assert_eq!(vote_authorize_code(VoteAuthorize::Withdrawer), 1);
+        // Matches the bincode tag
+        assert_eq!(
+            bincode::serialize(&VoteAuthorize::Withdrawer).unwrap(),
+            vec![1, 0, 0, 0]
+        );
+    }
+
     #[test]
     fn test_parse_vote_initialize_ix() {
         let lamports = 55;
@@ -253,7 +598,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "initialize".to_string(),
+                instruction_type: "initialize".into(),
                 info: json!({
                     "voteAccount": vote_pubkey.to_string(),
                     "rentSysvar": sysvar::rent::ID.to_string(),
@@ -275,6 +620,44 @@ mod test {
         assert!(parse_vote(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
     }
 
+    #[test]
+    fn test_parse_vote_initialize_legacy_ix() {
+        let vote_pubkey = Pubkey::new_unique();
+        let vote_init = VoteInit {
+            node_pubkey: Pubkey::new_unique(),
+            authorized_voter: Pubkey::new_unique(),
+            authorized_withdrawer: Pubkey::new_unique(),
+            commission: 10,
+        };
+
+        let instructions =
+            vote_instruction::create_account(&Pubkey::new_unique(), &vote_pubkey, &vote_init, 55);
+        let mut message = Message::new(&instructions, None);
+        // Drop the clock sysvar to reproduce the original 3-account layout
+        message.instructions[1].accounts.remove(2);
+        assert_eq!(
+            parse_vote(
+                &message.instructions[1],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "initialize".into(),
+                info: json!({
+                    "voteAccount": vote_pubkey.to_string(),
+                    "rentSysvar": sysvar::rent::ID.to_string(),
+                    "node": vote_init.node_pubkey.to_string(),
+                    "authorizedVoter": vote_init.authorized_voter.to_string(),
+                    "authorizedWithdrawer": vote_init.authorized_withdrawer.to_string(),
+                    "commission": vote_init.commission,
+                }),
+            }
+        );
+        let keys = message.account_keys.clone();
+        message.instructions[1].accounts.pop();
+        assert!(parse_vote(&message.instructions[1], &AccountKeys::new(&keys, None)).is_err());
+    }
+
     #[test]
     fn test_parse_vote_authorize_ix() {
         let vote_pubkey = Pubkey::new_unique();
@@ -295,13 +678,14 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "authorize".to_string(),
+                instruction_type: "authorize".into(),
                 info: json!({
                     "voteAccount": vote_pubkey.to_string(),
                     "clockSysvar": sysvar::clock::ID.to_string(),
                     "authority": authorized_pubkey.to_string(),
                     "newAuthority": new_authorized_pubkey.to_string(),
                     "authorityType": authority_type,
+                    "authorityTypeCode": 0,
                 }),
             }
         );
@@ -339,7 +723,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "authorizeWithSeed".to_string(),
+                instruction_type: "authorizeWithSeed".into(),
                 info: json!({
                     "voteAccount": vote_pubkey.to_string(),
                     "clockSysvar": sysvar::clock::ID.to_string(),
@@ -348,6 +732,7 @@ mod test {
                     "authoritySeed": current_authority_seed,
                     "newAuthority": new_authorized_pubkey.to_string(),
                     "authorityType": authority_type,
+                    "authorityTypeCode": 0,
                 }),
             }
         );
@@ -382,7 +767,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "authorizeCheckedWithSeed".to_string(),
+                instruction_type: "authorizeCheckedWithSeed".into(),
                 info: json!({
                     "voteAccount": vote_pubkey.to_string(),
                     "clockSysvar": sysvar::clock::ID.to_string(),
@@ -391,6 +776,7 @@ mod test {
                     "authoritySeed": current_authority_seed,
                     "newAuthority": new_authorized_pubkey.to_string(),
                     "authorityType": authority_type,
+                    "authorityTypeCode": 0,
                 }),
             }
         );
@@ -401,6 +787,29 @@ mod test {
         .is_err());
     }
 
+    #[test]
+    fn test_parse_vote_has_timestamp() {
+        let vote_pubkey = Pubkey::new_unique();
+        let authorized_voter_pubkey = Pubkey::new_unique();
+        for (timestamp, has_timestamp) in [(Some(1_234_567_890), true), (None, false)] {
+            let vote = Vote {
+                slots: vec![1, 2, 4],
+                hash: Hash::new_unique(),
+                timestamp,
+            };
+            let instruction = vote_instruction::vote(&vote_pubkey, &authorized_voter_pubkey, vote);
+            let message = Message::new(&[instruction], None);
+            let info = parse_vote(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None),
+            )
+            .unwrap()
+            .info;
+            assert_eq!(info["vote"]["hasTimestamp"], json!(has_timestamp));
+            assert_eq!(info["vote"]["timestamp"], json!(timestamp));
+        }
+    }
+
     #[test]
     fn test_parse_vote_ix() {
         let hash = Hash::new_from_array([1; 32]);
@@ -421,7 +830,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "vote".to_string(),
+                instruction_type: "vote".into(),
                 info: json!({
                     "voteAccount": vote_pubkey.to_string(),
                     "slotHashesSysvar": sysvar::slot_hashes::ID.to_string(),
@@ -431,6 +840,7 @@ mod test {
                         "slots": [1, 2, 4],
                         "hash": hash.to_string(),
                         "timestamp": 1_234_567_890,
+                        "hasTimestamp": true,
                     },
                 }),
             }
@@ -465,12 +875,13 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "withdraw".to_string(),
+                instruction_type: "withdraw".into(),
                 info: json!({
                     "voteAccount": vote_pubkey.to_string(),
                     "destination": to_pubkey.to_string(),
                     "withdrawAuthority": authorized_withdrawer_pubkey.to_string(),
                     "lamports": lamports,
+                    "sol": "0.000000055",
                 }),
             }
         );
@@ -502,7 +913,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "updateValidatorIdentity".to_string(),
+                instruction_type: "updateValidatorIdentity".into(),
                 info: json!({
                     "voteAccount": vote_pubkey.to_string(),
                     "newValidatorIdentity": node_pubkey.to_string(),
@@ -538,7 +949,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "updateCommission".to_string(),
+                instruction_type: "updateCommission".into(),
                 info: json!({
                     "voteAccount": vote_pubkey.to_string(),
                     "withdrawAuthority": authorized_withdrawer_pubkey.to_string(),
@@ -578,7 +989,7 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "voteSwitch".to_string(),
+                instruction_type: "voteSwitch".into(),
                 info: json!({
                     "voteAccount": vote_pubkey.to_string(),
                     "slotHashesSysvar": sysvar::slot_hashes::ID.to_string(),
@@ -588,11 +999,20 @@ mod test {
                         "slots": [1, 2, 4],
                         "hash": hash.to_string(),
                         "timestamp": 1_234_567_890,
+                        "hasTimestamp": true,
                     },
                     "hash": proof_hash.to_string(),
+                    "proofHash": proof_hash.to_string(),
                 }),
             }
         );
+        let info = parse_vote(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+        )
+        .unwrap()
+        .info;
+        assert_ne!(info["vote"]["hash"], info["proofHash"]);
         assert!(parse_vote(
             &message.instructions[0],
             &AccountKeys::new(&message.account_keys[0..3], None)
@@ -603,6 +1023,86 @@ mod test {
         assert!(parse_vote(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
     }
 
+    #[test]
+    fn test_parse_vote_state_update_ix() {
+        let hash = Hash::new_from_array([1; 32]);
+        let mut vote_state_update = VoteStateUpdate::from(vec![(1, 3), (2, 2), (4, 1)]);
+        vote_state_update.root = Some(0);
+        vote_state_update.hash = hash;
+        vote_state_update.timestamp = Some(1_234_567_890);
+
+        let vote_pubkey = Pubkey::new_unique();
+        let authorized_voter_pubkey = Pubkey::new_unique();
+        let proof_hash = Hash::new_from_array([2; 32]);
+        let account_metas = vec![
+            AccountMeta::new(vote_pubkey, false),
+            AccountMeta::new_readonly(sysvar::slot_hashes::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(authorized_voter_pubkey, true),
+        ];
+        let expected_vote_state_update = json!({
+            "lockouts": [
+                {"slot": 1, "confirmationCount": 3},
+                {"slot": 2, "confirmationCount": 2},
+                {"slot": 4, "confirmationCount": 1},
+            ],
+            "root": 0,
+            "hash": hash.to_string(),
+            "timestamp": 1_234_567_890,
+            "hasTimestamp": true,
+        });
+
+        let instruction = Instruction::new_with_bincode(
+            solana_vote_program::id(),
+            &VoteInstruction::UpdateVoteState(vote_state_update.clone()),
+            account_metas.clone(),
+        );
+        let message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_vote(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "updatevotestate".into(),
+                info: json!({
+                    "voteAccount": vote_pubkey.to_string(),
+                    "slotHashesSysvar": sysvar::slot_hashes::ID.to_string(),
+                    "clockSysvar": sysvar::clock::ID.to_string(),
+                    "voteAuthority": authorized_voter_pubkey.to_string(),
+                    "voteStateUpdate": expected_vote_state_update,
+                }),
+            }
+        );
+
+        let instruction = Instruction::new_with_bincode(
+            solana_vote_program::id(),
+            &VoteInstruction::UpdateVoteStateSwitch(vote_state_update, proof_hash),
+            account_metas,
+        );
+        let message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_vote(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "updatevotestateswitch".into(),
+                info: json!({
+                    "voteAccount": vote_pubkey.to_string(),
+                    "slotHashesSysvar": sysvar::slot_hashes::ID.to_string(),
+                    "clockSysvar": sysvar::clock::ID.to_string(),
+                    "voteAuthority": authorized_voter_pubkey.to_string(),
+                    "voteStateUpdate": expected_vote_state_update,
+                    "hash": proof_hash.to_string(),
+                    "proofHash": proof_hash.to_string(),
+                }),
+            }
+        );
+    }
+
     #[test]
     fn test_parse_vote_authorized_checked_ix() {
         let vote_pubkey = Pubkey::new_unique();
@@ -623,13 +1123,14 @@ mod test {
             )
             .unwrap(),
             ParsedInstructionEnum {
-                instruction_type: "authorizeChecked".to_string(),
+                instruction_type: "authorizeChecked".into(),
                 info: json!({
                     "voteAccount": vote_pubkey.to_string(),
                     "clockSysvar": sysvar::clock::ID.to_string(),
                     "authority": authorized_pubkey.to_string(),
                     "newAuthority": new_authorized_pubkey.to_string(),
                     "authorityType": authority_type,
+                    "authorityTypeCode": 0,
                 }),
             }
         );
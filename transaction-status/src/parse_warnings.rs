@@ -0,0 +1,292 @@
+use {
+    crate::{
+        instruction_types,
+        parse_instruction::{
+            parse_with_config, ParseConfig, ParseInstructionError, ParsedInstruction,
+            ParsedInstructionEnum,
+        },
+    },
+    borsh::BorshDeserialize,
+    serde_json::Value,
+    solana_sdk::{
+        compute_budget::{self, ComputeBudgetInstruction},
+        instruction::CompiledInstruction,
+        message::{AccountKeys, Message},
+        pubkey::Pubkey,
+        stake, system_program,
+    },
+    std::collections::HashSet,
+    thiserror::Error,
+};
+
+/// Non-fatal findings about an instruction that parsed successfully
+#[derive(Error, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ParseWarning {
+    #[error("{first} and {second} must be distinct, but both are {account}")]
+    CollidingAccounts {
+        first: String,
+        second: String,
+        account: String,
+    },
+
+    #[error("accounts not referenced by the parsed instruction: {accounts:?}")]
+    ExtraAccounts { accounts: Vec<String> },
+}
+
+/// A successfully parsed instruction together with any non-fatal findings
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseOutcome {
+    pub parsed: ParsedInstruction,
+    pub warnings: Vec<ParseWarning>,
+}
+
+/// Opt-in variant of [`parse_with_config`] that also runs the non-fatal checks in this module
+pub fn parse_with_warnings(
+    program_id: &Pubkey,
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+    config: &ParseConfig,
+) -> Result<ParseOutcome, ParseInstructionError> {
+    let parsed = parse_with_config(program_id, instruction, account_keys, config)?;
+    let mut warnings = vec![];
+    // Memos and other bare values don't name their accounts
+    if let Ok(parsed_enum) = serde_json::from_value::<ParsedInstructionEnum>(parsed.parsed.clone())
+    {
+        warnings.extend(find_colliding_accounts(program_id, &parsed_enum));
+        let accounts = find_extra_accounts(instruction, account_keys, &parsed_enum.info);
+        if !accounts.is_empty() {
+            warnings.push(ParseWarning::ExtraAccounts { accounts });
+        }
+    }
+    Ok(ParseOutcome { parsed, warnings })
+}
+
+/// Accounts passed to `instruction` that appear nowhere in the parsed `info`
+fn find_extra_accounts(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+    info: &Value,
+) -> Vec<String> {
+    fn collect_strings<'a>(value: &'a Value, strings: &mut HashSet<&'a str>) {
+        match value {
+            Value::String(s) => {
+                strings.insert(s);
+            }
+            Value::Array(values) => values.iter().for_each(|v| collect_strings(v, strings)),
+            Value::Object(map) => map.values().for_each(|v| collect_strings(v, strings)),
+            _ => {}
+        }
+    }
+    let mut referenced = HashSet::new();
+    collect_strings(info, &mut referenced);
+    let mut seen = HashSet::new();
+    instruction
+        .accounts
+        .iter()
+        .filter(|index| seen.insert(**index))
+        .filter_map(|index| account_keys.get(*index as usize))
+        .map(|pubkey| pubkey.to_string())
+        .filter(|pubkey| !referenced.contains(pubkey.as_str()))
+        .collect()
+}
+
+/// Reports named accounts that the program requires to be distinct but which refer to the
+/// same address, e.g. a stake split into its own source account
+pub fn find_colliding_accounts(
+    program_id: &Pubkey,
+    parsed: &ParsedInstructionEnum,
+) -> Vec<ParseWarning> {
+    let distinct_fields: &[(&str, &str)] = if program_id == &stake::program::id() {
+        match parsed.instruction_type.as_ref() {
+            instruction_types::stake::SPLIT => &[("stakeAccount", "newSplitAccount")],
+            instruction_types::stake::MERGE => &[("destination", "source")],
+            _ => &[],
+        }
+    } else if program_id == &system_program::id() {
+        match parsed.instruction_type.as_ref() {
+            instruction_types::system::CREATE_ACCOUNT
+            | instruction_types::system::CREATE_ACCOUNT_WITH_SEED => &[("source", "newAccount")],
+            _ => &[],
+        }
+    } else {
+        &[]
+    };
+    distinct_fields
+        .iter()
+        .filter_map(|(first, second)| {
+            let account = parsed.info.get(first)?.as_str()?;
+            (parsed.info.get(second)?.as_str()? == account).then(|| {
+                ParseWarning::CollidingAccounts {
+                    first: first.to_string(),
+                    second: second.to_string(),
+                    account: account.to_string(),
+                }
+            })
+        })
+        .collect()
+}
+
+/// Indices of compute-budget instructions in `message` that set a limit, heap frame or price
+/// an earlier instruction already set. The runtime rejects such transactions with
+/// `DuplicateInstruction`. The deprecated `requestUnits` sets both the limit and the price.
+pub fn find_duplicate_compute_budget(message: &Message) -> Vec<usize> {
+    let (mut unit_limit_set, mut heap_frame_set, mut unit_price_set) = (false, false, false);
+    message
+        .instructions
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| {
+            message
+                .account_keys
+                .get(instruction.program_id_index as usize)
+                .map_or(false, compute_budget::check_id)
+        })
+        .filter_map(|(index, instruction)| {
+            let duplicate = match ComputeBudgetInstruction::try_from_slice(&instruction.data) {
+                Ok(ComputeBudgetInstruction::RequestUnitsDeprecated { .. }) => {
+                    let duplicate = unit_limit_set || unit_price_set;
+                    unit_limit_set = true;
+                    unit_price_set = true;
+                    duplicate
+                }
+                Ok(ComputeBudgetInstruction::RequestHeapFrame(_)) => {
+                    std::mem::replace(&mut heap_frame_set, true)
+                }
+                Ok(ComputeBudgetInstruction::SetComputeUnitLimit(_)) => {
+                    std::mem::replace(&mut unit_limit_set, true)
+                }
+                Ok(ComputeBudgetInstruction::SetComputeUnitPrice(_)) => {
+                    std::mem::replace(&mut unit_price_set, true)
+                }
+                Err(_) => false,
+            };
+            duplicate.then(|| index)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{parse_stake::parse_stake, parse_system::parse_system},
+        solana_sdk::{
+            message::{AccountKeys, Message},
+            system_instruction,
+        },
+    };
+
+    #[test]
+    fn test_find_duplicate_compute_budget() {
+        let payer = Pubkey::new_unique();
+        let instructions = [
+            ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+            system_instruction::transfer(&payer, &Pubkey::new_unique(), 42),
+            ComputeBudgetInstruction::set_compute_unit_price(1),
+        ];
+        let message = Message::new(&instructions, Some(&payer));
+        assert!(find_duplicate_compute_budget(&message).is_empty());
+
+        let instructions = [
+            ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+            system_instruction::transfer(&payer, &Pubkey::new_unique(), 42),
+            ComputeBudgetInstruction::set_compute_unit_limit(400_000),
+            ComputeBudgetInstruction::set_compute_unit_price(1),
+        ];
+        let mut message = Message::new(&instructions, Some(&payer));
+        assert_eq!(find_duplicate_compute_budget(&message), vec![2]);
+
+        // Unsanitized program indexes are skipped
+        let mut instruction = message.instructions[2].clone();
+        instruction.program_id_index = message.account_keys.len() as u8;
+        message.instructions.push(instruction);
+        assert_eq!(find_duplicate_compute_budget(&message), vec![2]);
+    }
+
+    #[test]
+    fn test_find_colliding_accounts() {
+        let stake_pubkey = Pubkey::new_unique();
+        let authorized_pubkey = Pubkey::new_unique();
+        let split_stake_pubkey = Pubkey::new_unique();
+        let instructions =
+            stake::instruction::split(&stake_pubkey, &authorized_pubkey, 42, &split_stake_pubkey);
+        let mut message = Message::new(&instructions, None);
+        let split_instruction = message.instructions.last().unwrap().clone();
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let parsed = parse_stake(&split_instruction, &account_keys).unwrap();
+        assert!(find_colliding_accounts(&stake::program::id(), &parsed).is_empty());
+
+        // Split into its own source account
+        let split_instruction = message.instructions.last_mut().unwrap();
+        split_instruction.accounts[1] = split_instruction.accounts[0];
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let parsed = parse_stake(message.instructions.last().unwrap(), &account_keys).unwrap();
+        assert_eq!(
+            find_colliding_accounts(&stake::program::id(), &parsed),
+            vec![ParseWarning::CollidingAccounts {
+                first: "stakeAccount".to_string(),
+                second: "newSplitAccount".to_string(),
+                account: stake_pubkey.to_string(),
+            }]
+        );
+
+        // Transfers to self are permitted on-chain
+        let instruction = system_instruction::transfer(&stake_pubkey, &stake_pubkey, 42);
+        let message = Message::new(&[instruction], None);
+        let parsed = parse_system(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys, None),
+        )
+        .unwrap();
+        assert!(find_colliding_accounts(&system_program::id(), &parsed).is_empty());
+    }
+
+    #[test]
+    fn test_parse_with_warnings() {
+        let from_pubkey = Pubkey::new_unique();
+        let to_pubkey = Pubkey::new_unique();
+        let extra_pubkey = Pubkey::new_unique();
+        let instruction = system_instruction::transfer(&from_pubkey, &to_pubkey, 42);
+        let mut message = Message::new(&[instruction], None);
+        let config = ParseConfig::default();
+
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let outcome = parse_with_warnings(
+            &system_program::id(),
+            &message.instructions[0],
+            &account_keys,
+            &config,
+        )
+        .unwrap();
+        assert!(outcome.warnings.is_empty());
+
+        message.account_keys.push(extra_pubkey);
+        message.instructions[0]
+            .accounts
+            .push(message.account_keys.len() as u8 - 1);
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        let outcome = parse_with_warnings(
+            &system_program::id(),
+            &message.instructions[0],
+            &account_keys,
+            &config,
+        )
+        .unwrap();
+        assert_eq!(
+            outcome.parsed,
+            crate::parse_instruction::parse(
+                &system_program::id(),
+                &message.instructions[0],
+                &account_keys
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            outcome.warnings,
+            vec![ParseWarning::ExtraAccounts {
+                accounts: vec![extra_pubkey.to_string()],
+            }]
+        );
+    }
+}